@@ -1,3 +1,8 @@
+pub mod current;
+pub mod gists;
 pub mod issues;
 pub mod orgs;
 pub mod pulls;
+pub mod repos;
+pub mod search;
+pub mod users;
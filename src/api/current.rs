@@ -0,0 +1,252 @@
+//! The API for the authenticated user.
+
+use crate::{models, Octocrab, Page, Result};
+
+/// Handler for the parts of GitHub's API that act on the authenticated user.
+///
+/// Created with [`Octocrab::current`].
+///
+/// [`Octocrab::current`]: ../struct.Octocrab.html#method.current
+pub struct CurrentAuthHandler<'octo> {
+    crab: &'octo Octocrab,
+}
+
+impl<'octo> CurrentAuthHandler<'octo> {
+    pub(crate) fn new(crab: &'octo Octocrab) -> Self {
+        Self { crab }
+    }
+
+    fn star_route(owner: impl AsRef<str>, repo: impl AsRef<str>) -> String {
+        format!(
+            "/user/starred/{owner}/{repo}",
+            owner = crate::encoding::segment(owner.as_ref()),
+            repo = crate::encoding::segment(repo.as_ref()),
+        )
+    }
+}
+
+/// # Stars
+impl<'octo> CurrentAuthHandler<'octo> {
+    /// Lists the repositories the authenticated user has starred.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params;
+    ///
+    /// let page = octocrab::instance()
+    ///     .current()
+    ///     .starred()
+    ///     // Optional Parameters
+    ///     .sort(params::repos::StarredSort::Updated)
+    ///     .direction(params::Direction::Ascending)
+    ///     .per_page(100)
+    ///     .page(2u32)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn starred(&self) -> ListStarredReposBuilder<'octo, '_> {
+        ListStarredReposBuilder::new(self)
+    }
+
+    /// Stars the repository `owner/repo`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance().current().star("rust-lang", "rust").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn star(&self, owner: impl AsRef<str>, repo: impl AsRef<str>) -> Result<()> {
+        let url = self.crab.absolute_url(Self::star_route(owner, repo))?;
+        let response = self.crab._put(url, None::<&()>).await?;
+
+        Octocrab::map_no_content(response).await
+    }
+
+    /// Unstars the repository `owner/repo`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance().current().unstar("rust-lang", "rust").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn unstar(&self, owner: impl AsRef<str>, repo: impl AsRef<str>) -> Result<()> {
+        let url = self.crab.absolute_url(Self::star_route(owner, repo))?;
+        let response = self.crab._delete(url, None::<&()>).await?;
+
+        Octocrab::map_no_content(response).await
+    }
+
+    /// Checks whether the authenticated user has starred the repository
+    /// `owner/repo`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// assert!(octocrab::instance().current().is_starred("rust-lang", "rust").await?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn is_starred(&self, owner: impl AsRef<str>, repo: impl AsRef<str>) -> Result<bool> {
+        let url = self.crab.absolute_url(Self::star_route(owner, repo))?;
+        let response = self.crab._get(url, None::<&()>).await?;
+
+        Octocrab::map_boolean_status(response).await
+    }
+}
+
+/// A builder pattern struct for listing the repositories the authenticated
+/// user has starred.
+///
+/// created by [`CurrentAuthHandler::starred`]
+///
+/// [`CurrentAuthHandler::starred`]: ./struct.CurrentAuthHandler.html#method.starred
+#[derive(serde::Serialize)]
+pub struct ListStarredReposBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r CurrentAuthHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort: Option<crate::params::repos::StarredSort>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    direction: Option<crate::params::Direction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListStarredReposBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r CurrentAuthHandler<'octo>) -> Self {
+        Self {
+            handler,
+            sort: None,
+            direction: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// What to sort results by. Default: when the repository was starred.
+    pub fn sort(mut self, sort: impl Into<crate::params::repos::StarredSort>) -> Self {
+        self.sort = Some(sort.into());
+        self
+    }
+
+    /// The direction of the sort. Default: descending.
+    pub fn direction(mut self, direction: impl Into<crate::params::Direction>) -> Self {
+        self.direction = Some(direction.into());
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl std::convert::TryInto<i64>) -> Self {
+        self.per_page = Some(crate::page::clamp_per_page(per_page));
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<Page<models::Repository>> {
+        self.handler.crab.get("/user/starred", Some(&self)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        matchers::{method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[tokio::test]
+    async fn starred() {
+        let server = MockServer::start().await;
+        let repository: serde_json::Value =
+            serde_json::from_str(include_str!("../../tests/resources/repository.json")).unwrap();
+        Mock::given(method("GET"))
+            .and(path("/user/starred"))
+            .and(query_param("sort", "updated"))
+            .and(query_param("direction", "asc"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([repository])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let page = crate::setup(&server)
+            .current()
+            .starred()
+            .sort(crate::params::repos::StarredSort::Updated)
+            .direction(crate::params::Direction::Ascending)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.items[0].name, "Hello-World");
+    }
+
+    #[tokio::test]
+    async fn is_starred() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/user/starred/octocat/starred"))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/user/starred/octocat/unstarred"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "message": "Not Found",
+                "documentation_url": "https://docs.github.com/rest/activity/starring#check-if-a-repository-is-starred-by-the-authenticated-user"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/user/starred/octocat/forbidden"))
+            .respond_with(ResponseTemplate::new(401).set_body_json(serde_json::json!({
+                "message": "Requires authentication",
+                "documentation_url": "https://docs.github.com/rest/activity/starring#check-if-a-repository-is-starred-by-the-authenticated-user"
+            })))
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let current = octocrab.current();
+
+        assert!(current.is_starred("octocat", "starred").await.unwrap());
+        assert!(!current.is_starred("octocat", "unstarred").await.unwrap());
+        assert_eq!(
+            current
+                .is_starred("octocat", "forbidden")
+                .await
+                .unwrap_err()
+                .status_code(),
+            Some(reqwest::StatusCode::UNAUTHORIZED)
+        );
+    }
+
+    #[tokio::test]
+    async fn star_and_unstar() {
+        let server = MockServer::start().await;
+        for verb in &["PUT", "DELETE"] {
+            Mock::given(method(*verb))
+                .and(path("/user/starred/rust-lang/rust"))
+                .respond_with(ResponseTemplate::new(204))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let octocrab = crate::setup(&server);
+
+        octocrab.current().star("rust-lang", "rust").await.unwrap();
+        octocrab
+            .current()
+            .unstar("rust-lang", "rust")
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,266 @@
+//! The gist API.
+
+use crate::{models, Created, Octocrab, Page, Result};
+
+/// Handler for GitHub's gist API.
+///
+/// Created with [`Octocrab::gists`].
+///
+/// [`Octocrab::gists`]: ../struct.Octocrab.html#method.gists
+pub struct GistsHandler<'octo> {
+    crab: &'octo Octocrab,
+}
+
+impl<'octo> GistsHandler<'octo> {
+    pub(crate) fn new(crab: &'octo Octocrab) -> Self {
+        Self { crab }
+    }
+
+    /// Gets the gist with the given `id`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let gist = octocrab::instance().gists().get("aa5a315d61ae9438b18d").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, id: impl AsRef<str>) -> Result<models::Gist> {
+        self.crab.get(Self::route(&id, ""), None::<&()>).await
+    }
+
+    /// Forks the gist with the given `id`, returning the new gist and its
+    /// `Location`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let fork = octocrab::instance().gists().fork("aa5a315d61ae9438b18d").await?;
+    /// println!("{:?}", fork.location);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fork(&self, id: impl AsRef<str>) -> Result<Created<models::Gist>> {
+        self.crab
+            .post(Self::route(&id, "/forks"), None::<&()>)
+            .await
+    }
+
+    fn route(id: &impl AsRef<str>, rest: &str) -> String {
+        format!(
+            "/gists/{id}{rest}",
+            id = crate::encoding::segment(id.as_ref()),
+            rest = rest,
+        )
+    }
+}
+
+/// # Stars
+impl<'octo> GistsHandler<'octo> {
+    /// Stars the gist with the given `id`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance().gists().star("aa5a315d61ae9438b18d").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn star(&self, id: impl AsRef<str>) -> Result<()> {
+        let url = self.crab.absolute_url(Self::route(&id, "/star"))?;
+        let response = self.crab._put(url, None::<&()>).await?;
+
+        Octocrab::map_no_content(response).await
+    }
+
+    /// Unstars the gist with the given `id`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance().gists().unstar("aa5a315d61ae9438b18d").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn unstar(&self, id: impl AsRef<str>) -> Result<()> {
+        let url = self.crab.absolute_url(Self::route(&id, "/star"))?;
+        let response = self.crab._delete(url, None::<&()>).await?;
+
+        Octocrab::map_no_content(response).await
+    }
+
+    /// Checks whether the authenticated user has starred the gist with the
+    /// given `id`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// assert!(octocrab::instance().gists().is_starred("aa5a315d61ae9438b18d").await?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn is_starred(&self, id: impl AsRef<str>) -> Result<bool> {
+        let url = self.crab.absolute_url(Self::route(&id, "/star"))?;
+        let response = self.crab._get(url, None::<&()>).await?;
+
+        Octocrab::map_boolean_status(response).await
+    }
+}
+
+/// # Comments
+impl<'octo> GistsHandler<'octo> {
+    /// Creates a comment on the gist with the given `id`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let comment = octocrab::instance()
+    ///     .gists()
+    ///     .create_comment("aa5a315d61ae9438b18d", "Beep Boop")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_comment(
+        &self,
+        id: impl AsRef<str>,
+        body: impl AsRef<str>,
+    ) -> Result<models::GistComment> {
+        self.crab
+            .post(
+                Self::route(&id, "/comments"),
+                Some(&serde_json::json!({ "body": body.as_ref() })),
+            )
+            .await
+    }
+
+    /// Lists the comments on the gist with the given `id`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance()
+    ///     .gists()
+    ///     .list_comments("aa5a315d61ae9438b18d")
+    ///     .per_page(100)
+    ///     .page(2u32)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_comments(&self, id: impl AsRef<str>) -> ListGistCommentsBuilder<'octo, '_> {
+        ListGistCommentsBuilder::new(self, Self::route(&id, "/comments"))
+    }
+}
+
+/// A builder pattern struct for listing the comments on a gist.
+///
+/// created by [`GistsHandler::list_comments`]
+///
+/// [`GistsHandler::list_comments`]: ./struct.GistsHandler.html#method.list_comments
+#[derive(serde::Serialize)]
+pub struct ListGistCommentsBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r GistsHandler<'octo>,
+    #[serde(skip)]
+    route: String,
+    per_page: Option<u8>,
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListGistCommentsBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r GistsHandler<'octo>, route: String) -> Self {
+        Self {
+            handler,
+            route,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl std::convert::TryInto<i64>) -> Self {
+        self.per_page = Some(crate::page::clamp_per_page(per_page));
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<Page<models::GistComment>> {
+        self.handler.crab.get(&self.route, Some(&self)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[tokio::test]
+    async fn fork() {
+        let server = MockServer::start().await;
+        let gist: serde_json::Value =
+            serde_json::from_str(include_str!("../../tests/resources/gist.json")).unwrap();
+        Mock::given(method("POST"))
+            .and(path("/gists/aa5a315d61ae9438b18d/forks"))
+            .respond_with(
+                ResponseTemplate::new(201)
+                    .insert_header(
+                        "location",
+                        "https://api.github.com/gists/2decf6c462d9b4418f2",
+                    )
+                    .set_body_json(gist),
+            )
+            .mount(&server)
+            .await;
+
+        let fork = crate::setup(&server)
+            .gists()
+            .fork("aa5a315d61ae9438b18d")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            fork.location.as_ref().map(reqwest::Url::as_str),
+            Some("https://api.github.com/gists/2decf6c462d9b4418f2")
+        );
+        assert_eq!(fork.id, "2decf6c462d9b4418f2");
+        assert!(fork.into_inner().files.contains_key("hello_world.rb"));
+    }
+
+    #[tokio::test]
+    async fn is_starred() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/gists/starred/star"))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/gists/unstarred/star"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "message": "Not Found",
+                "documentation_url": "https://docs.github.com/rest/gists/gists#check-if-a-gist-is-starred"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/gists/forbidden/star"))
+            .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+                "message": "Forbidden",
+                "documentation_url": "https://docs.github.com/rest/gists/gists#check-if-a-gist-is-starred"
+            })))
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let gists = octocrab.gists();
+
+        assert!(gists.is_starred("starred").await.unwrap());
+        assert!(!gists.is_starred("unstarred").await.unwrap());
+        assert_eq!(
+            gists
+                .is_starred("forbidden")
+                .await
+                .unwrap_err()
+                .as_github()
+                .unwrap()
+                .status_code,
+            403
+        );
+    }
+}
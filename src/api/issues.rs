@@ -3,6 +3,7 @@
 mod create;
 mod list;
 mod list_labels;
+mod milestones;
 mod update;
 
 use crate::{models, params, Octocrab, Result};
@@ -11,6 +12,7 @@ pub use self::{
     create::CreateIssueBuilder,
     list::ListIssuesBuilder,
     list_labels::{ListLabelsForIssueBuilder, ListLabelsForRepoBuilder},
+    milestones::{CreateMilestoneBuilder, ListMilestonesBuilder, MilestonesHandler},
     update::UpdateIssueBuilder,
 };
 
@@ -32,23 +34,46 @@ pub struct IssueHandler<'octo> {
 
 impl<'octo> IssueHandler<'octo> {
     pub(crate) fn new(crab: &'octo Octocrab, owner: String, repo: String) -> Self {
-        Self { crab, owner, repo }
+        Self {
+            crab,
+            owner: crate::encoding::segment(&owner),
+            repo: crate::encoding::segment(&repo),
+        }
     }
 
-    /// Gets a label from the repository.
+    /// Creates a `MilestonesHandler` for the repository's milestones.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let milestone = octocrab::instance()
+    ///     .issues("owner", "repo")
+    ///     .milestones()
+    ///     .get(1)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn milestones(&self) -> MilestonesHandler<'octo, '_> {
+        MilestonesHandler::new(self)
+    }
+
+    /// Gets the issue with the given `number`. If there's no such issue,
+    /// GitHub responds with `404 Not Found`, which can be checked for with
+    /// [`Error::is_not_found`].
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
     /// # let octocrab = octocrab::Octocrab::default();
-    /// let label = octocrab.issues("owner", "repo").get_label("help wanted").await?;
+    /// let issue = octocrab.issues("owner", "repo").get(101).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get(&self, number: u64) -> Result<models::Issue> {
+    ///
+    /// [`Error::is_not_found`]: ../enum.Error.html#method.is_not_found
+    pub async fn get(&self, number: impl Into<models::IssueNumber>) -> Result<models::Issue> {
         let route = format!(
             "/repos/{owner}/{repo}/issues/{number}",
             owner = self.owner,
             repo = self.repo,
-            number = number,
+            number = number.into(),
         );
 
         self.crab.get(route, None::<&()>).await
@@ -124,70 +149,61 @@ impl<'octo> IssueHandler<'octo> {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn update(&self, number: u64) -> update::UpdateIssueBuilder<'_, '_, '_, '_, '_, '_> {
-        update::UpdateIssueBuilder::new(self, number)
+    pub fn update(
+        &self,
+        number: impl Into<models::IssueNumber>,
+    ) -> update::UpdateIssueBuilder<'_, '_, '_, '_, '_, '_> {
+        update::UpdateIssueBuilder::new(self, number.into())
     }
 
-    /// Users with push access can lock an issue or pull request's conversation.
-    ///
-    /// *Note* Providing a reason requires the `sailor-v` preview to enabled.
-    ///
-    /// See also: https://developer.github.com/v3/issues/#lock-an-issue
+    /// Locks the conversation of an issue or pull request, so that only
+    /// users with push access can comment on it. Requires push access.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
     /// use octocrab::params;
     ///
-    /// assert!(octocrab::instance().issues("owner", "repo").lock(404, params::LockReason::OffTopic).await?);
+    /// octocrab::instance()
+    ///     .issues("owner", "repo")
+    ///     .lock(404, params::issues::LockReason::OffTopic)
+    ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
     pub async fn lock(
         &self,
-        number: u64,
-        reason: impl Into<Option<params::LockReason>>,
-    ) -> Result<bool> {
+        number: impl Into<models::IssueNumber>,
+        reason: impl Into<Option<params::issues::LockReason>>,
+    ) -> Result<()> {
         let route = format!(
             "/repos/{owner}/{repo}/issues/{number}/lock",
             owner = self.owner,
             repo = self.repo,
-            number = number,
+            number = number.into(),
         );
 
-        let response = self
-            .crab
-            ._put(
-                self.crab.absolute_url(route)?,
-                reason
-                    .into()
-                    .map(|reason| {
-                        serde_json::json!({
-                            "lock_reason": reason,
-                        })
-                    })
-                    .as_ref(),
-            )
-            .await?;
+        let mut request = self.crab.client.put(self.crab.absolute_url(route)?);
+        if let Some(reason) = reason.into() {
+            request = request.json(&serde_json::json!({ "lock_reason": reason }));
+        }
+        let response = self.crab.execute(request).await?;
 
-        Ok(response.status() == 204)
+        Octocrab::map_no_content(response).await
     }
 
-    /// Users with push access can unlock an issue or pull request's conversation.
-    ///
-    /// See also: https://developer.github.com/v3/issues/#unlock-an-issue
+    /// Unlocks the conversation of an issue or pull request. Requires push
+    /// access.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
-    /// use octocrab::params;
-    ///
-    /// assert!(octocrab::instance().issues("owner", "repo").unlock(404).await?);
+    /// octocrab::instance().issues("owner", "repo").unlock(404).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn unlock(&self, number: u64) -> Result<bool> {
+    pub async fn unlock(&self, number: impl Into<models::IssueNumber>) -> Result<()> {
         let route = format!(
             "/repos/{owner}/{repo}/issues/{number}/lock",
             owner = self.owner,
             repo = self.repo,
-            number = number,
+            number = number.into(),
         );
 
         let response = self
@@ -195,7 +211,7 @@ impl<'octo> IssueHandler<'octo> {
             ._delete(self.crab.absolute_url(route)?, None::<&()>)
             .await?;
 
-        Ok(response.status() == 204)
+        Octocrab::map_no_content(response).await
     }
 }
 
@@ -210,12 +226,16 @@ impl<'octo> IssueHandler<'octo> {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn add_assignees(&self, number: u64, assignees: &[u64]) -> Result<models::Issue> {
+    pub async fn add_assignees(
+        &self,
+        number: impl Into<models::IssueNumber>,
+        assignees: &[u64],
+    ) -> Result<models::Issue> {
         let route = format!(
             "/repos/{owner}/{repo}/issues/{issue}/assignees",
             owner = self.owner,
             repo = self.repo,
-            issue = number
+            issue = number.into()
         );
 
         self.crab
@@ -237,21 +257,14 @@ impl<'octo> IssueHandler<'octo> {
             "/repos/{owner}/{repo}/assignees/{assignee}",
             owner = self.owner,
             repo = self.repo,
-            assignee = assignee.as_ref()
+            assignee = crate::encoding::segment(assignee.as_ref())
         );
         let response = self
             .crab
             ._get(self.crab.absolute_url(route)?, None::<&()>)
             .await?;
-        let status = response.status();
-
-        if status == 204 {
-            Ok(true)
-        } else if status == 404 {
-            Ok(false)
-        } else {
-            Err(Octocrab::map_github_error(response).await.unwrap_err())
-        }
+
+        Octocrab::map_boolean_status(response).await
     }
 
     /// Lists the available assignees for issues in a repository.
@@ -268,7 +281,7 @@ impl<'octo> IssueHandler<'octo> {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn list_assignees(&self) -> ListAssigneesBuilder {
+    pub fn list_assignees(&self) -> ListAssigneesBuilder<'_, '_> {
         ListAssigneesBuilder::new(self)
     }
 }
@@ -291,8 +304,8 @@ impl<'octo, 'r> ListAssigneesBuilder<'octo, 'r> {
     }
 
     /// Results per page (max 100).
-    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
-        self.per_page = Some(per_page.into());
+    pub fn per_page(mut self, per_page: impl std::convert::TryInto<i64>) -> Self {
+        self.per_page = Some(crate::page::clamp_per_page(per_page));
         self
     }
 
@@ -326,12 +339,16 @@ impl<'octo> IssueHandler<'octo> {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn add_labels(&self, number: u64, labels: &[String]) -> Result<Vec<models::Label>> {
+    pub async fn add_labels(
+        &self,
+        number: impl Into<models::IssueNumber>,
+        labels: &[String],
+    ) -> Result<Vec<models::Label>> {
         let route = format!(
             "/repos/{owner}/{repo}/issues/{issue}/labels",
             owner = self.owner,
             repo = self.repo,
-            issue = number
+            issue = number.into()
         );
 
         self.crab
@@ -339,6 +356,76 @@ impl<'octo> IssueHandler<'octo> {
             .await
     }
 
+    /// Removes the label named `name` from an issue, returning the issue's
+    /// remaining labels. If the issue doesn't have the label, GitHub responds
+    /// with `404 Not Found`, which can be checked for with
+    /// [`Error::is_not_found`].
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let labels = match octocrab::instance()
+    ///     .issues("owner", "repo")
+    ///     .remove_label(101, "help wanted")
+    ///     .await
+    /// {
+    ///     Ok(labels) => Some(labels),
+    ///     // The issue didn't have the label.
+    ///     Err(error) if error.is_not_found() => None,
+    ///     Err(error) => return Err(error),
+    /// };
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error::is_not_found`]: ../enum.Error.html#method.is_not_found
+    pub async fn remove_label(
+        &self,
+        number: impl Into<models::IssueNumber>,
+        name: impl AsRef<str>,
+    ) -> Result<Vec<models::Label>> {
+        let route = format!(
+            "/repos/{owner}/{repo}/issues/{issue}/labels/{name}",
+            owner = self.owner,
+            repo = self.repo,
+            issue = number.into(),
+            name = crate::encoding::segment(name.as_ref()),
+        );
+
+        self.crab.delete(route, None::<&()>).await
+    }
+
+    /// Replaces all of an issue's labels with `labels`, returning the issue's
+    /// new labels. An empty slice removes all of the labels.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let labels = octocrab::instance()
+    ///     .issues("owner", "repo")
+    ///     .replace_all_labels(101, &[String::from("help wanted")])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn replace_all_labels(
+        &self,
+        number: impl Into<models::IssueNumber>,
+        labels: &[String],
+    ) -> Result<Vec<models::Label>> {
+        let route = format!(
+            "/repos/{owner}/{repo}/issues/{issue}/labels",
+            owner = self.owner,
+            repo = self.repo,
+            issue = number.into()
+        );
+
+        let request = self
+            .crab
+            .client
+            .put(self.crab.absolute_url(route)?)
+            .json(&serde_json::json!({ "labels": labels }));
+        let response = self.crab.execute(request).await?;
+
+        crate::FromResponse::from_response(Octocrab::map_github_error(response).await?).await
+    }
+
     /// Creates a label in the repository.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
@@ -388,7 +475,7 @@ impl<'octo> IssueHandler<'octo> {
             "/repos/{owner}/{repo}/labels/{name}",
             owner = self.owner,
             repo = self.repo,
-            name = name.as_ref(),
+            name = crate::encoding::segment(name.as_ref()),
         );
 
         self.crab.get(route, None::<&()>).await
@@ -409,7 +496,7 @@ impl<'octo> IssueHandler<'octo> {
             "/repos/{owner}/{repo}/labels/{name}",
             owner = self.owner,
             repo = self.repo,
-            name = name.as_ref(),
+            name = crate::encoding::segment(name.as_ref()),
         );
 
         self.crab.delete(route, None::<&()>).await
@@ -429,8 +516,11 @@ impl<'octo> IssueHandler<'octo> {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn list_labels_for_issue(&self, number: u64) -> ListLabelsForIssueBuilder {
-        ListLabelsForIssueBuilder::new(self, number)
+    pub fn list_labels_for_issue(
+        &self,
+        number: impl Into<models::IssueNumber>,
+    ) -> ListLabelsForIssueBuilder<'_, '_> {
+        ListLabelsForIssueBuilder::new(self, number.into())
     }
 
     /// List all labels from a repository.
@@ -447,7 +537,7 @@ impl<'octo> IssueHandler<'octo> {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn list_labels_for_repo(&self) -> ListLabelsForRepoBuilder {
+    pub fn list_labels_for_repo(&self) -> ListLabelsForRepoBuilder<'_, '_> {
         ListLabelsForRepoBuilder::new(self)
     }
 }
@@ -466,14 +556,14 @@ impl<'octo> IssueHandler<'octo> {
     /// ```
     pub async fn create_comment(
         &self,
-        number: u64,
+        number: impl Into<models::IssueNumber>,
         body: impl AsRef<str>,
     ) -> Result<models::Comment> {
         let route = format!(
             "/repos/{owner}/{repo}/issues/{issue}/comments",
             owner = self.owner,
             repo = self.repo,
-            issue = number
+            issue = number.into()
         );
 
         self.crab
@@ -491,12 +581,15 @@ impl<'octo> IssueHandler<'octo> {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_comment(&self, comment_id: u64) -> Result<models::Comment> {
+    pub async fn get_comment(
+        &self,
+        comment_id: impl Into<models::CommentId>,
+    ) -> Result<models::Comment> {
         let route = format!(
             "/repos/{owner}/{repo}/issues/comments/{comment_id}",
             owner = self.owner,
             repo = self.repo,
-            comment_id = comment_id
+            comment_id = comment_id.into()
         );
 
         self.crab.get(route, None::<&()>).await
@@ -509,12 +602,12 @@ impl<'octo> IssueHandler<'octo> {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn delete_comment(&self, comment_id: u64) -> Result<()> {
+    pub async fn delete_comment(&self, comment_id: impl Into<models::CommentId>) -> Result<()> {
         let route = format!(
             "/repos/{owner}/{repo}/issues/comments/{comment_id}",
             owner = self.owner,
             repo = self.repo,
-            comment_id = comment_id
+            comment_id = comment_id.into()
         );
 
         let response = self
@@ -522,11 +615,7 @@ impl<'octo> IssueHandler<'octo> {
             ._delete(self.crab.absolute_url(route)?, None::<&()>)
             .await?;
 
-        if response.status() == 204 {
-            Ok(())
-        } else {
-            Octocrab::map_github_error(response).await.map(drop)
-        }
+        Octocrab::map_no_content(response).await
     }
 
     /// Lists comments in the issue.
@@ -543,24 +632,32 @@ impl<'octo> IssueHandler<'octo> {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn list_comments(&self, issue_number: u64) -> ListCommentsBuilder<'_, '_> {
-        ListCommentsBuilder::new(self, issue_number)
+    pub fn list_comments(
+        &self,
+        issue_number: impl Into<models::IssueNumber>,
+    ) -> ListCommentsBuilder<'_, '_> {
+        ListCommentsBuilder::new(self, issue_number.into())
     }
 }
 
+/// A builder pattern struct for listing the comments of an issue.
+///
+/// created by [`IssueHandler::list_comments`]
+///
+/// [`IssueHandler::list_comments`]: ./struct.IssueHandler.html#method.list_comments
 #[derive(serde::Serialize)]
 pub struct ListCommentsBuilder<'octo, 'r> {
     #[serde(skip)]
     handler: &'r IssueHandler<'octo>,
     #[serde(skip)]
-    issue_number: u64,
+    issue_number: models::IssueNumber,
     since: Option<chrono::DateTime<chrono::Utc>>,
     per_page: Option<u8>,
     page: Option<u32>,
 }
 
 impl<'octo, 'r> ListCommentsBuilder<'octo, 'r> {
-    pub(crate) fn new(handler: &'r IssueHandler<'octo>, issue_number: u64) -> Self {
+    pub(crate) fn new(handler: &'r IssueHandler<'octo>, issue_number: models::IssueNumber) -> Self {
         Self {
             handler,
             issue_number,
@@ -571,8 +668,8 @@ impl<'octo, 'r> ListCommentsBuilder<'octo, 'r> {
     }
 
     /// Results per page (max 100).
-    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
-        self.per_page = Some(per_page.into());
+    pub fn per_page(mut self, per_page: impl std::convert::TryInto<i64>) -> Self {
+        self.per_page = Some(crate::page::clamp_per_page(per_page));
         self
     }
 
@@ -600,3 +697,236 @@ impl<'octo, 'r> ListCommentsBuilder<'octo, 'r> {
         self.handler.crab.get(route, Some(&self)).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        matchers::{body_json, method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    fn labels(names: &[&str]) -> serde_json::Value {
+        names
+            .iter()
+            .enumerate()
+            .map(|(id, name)| {
+                serde_json::json!({
+                    "id": id,
+                    "node_id": "MDU6TGFiZWwyMDgwNDU5NDY=",
+                    "url": format!("https://api.github.com/repos/octocat/Hello-World/labels/{}", name),
+                    "name": name,
+                    "description": null,
+                    "color": "f29513",
+                    "default": false
+                })
+            })
+            .collect()
+    }
+
+    fn comment() -> serde_json::Value {
+        serde_json::from_str(include_str!("../../tests/resources/issue_comment.json")).unwrap()
+    }
+
+    #[tokio::test]
+    async fn create_comment() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/repos/octocat/Hello-World/issues/1347/comments"))
+            .and(body_json(serde_json::json!({ "body": "Me too" })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(comment()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let comment = crate::setup(&server)
+            .issues("octocat", "Hello-World")
+            .create_comment(1347, "Me too")
+            .await
+            .unwrap();
+
+        assert_eq!(comment.id, 1);
+        assert_eq!(comment.body.as_deref(), Some("Me too"));
+    }
+
+    #[tokio::test]
+    async fn list_comments() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/issues/1347/comments"))
+            .and(query_param("since", "2011-04-14T16:00:49Z"))
+            .and(query_param("per_page", "100"))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([comment()])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let since = "2011-04-14T16:00:49Z"
+            .parse::<chrono::DateTime<chrono::Utc>>()
+            .unwrap();
+        let octocrab = crate::setup(&server);
+        let page = octocrab
+            .issues("octocat", "Hello-World")
+            .list_comments(1347)
+            .since(since)
+            .per_page(100)
+            .page(2u32)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.items[0].user.login, "octocat");
+    }
+
+    #[tokio::test]
+    async fn remove_label() {
+        let server = MockServer::start().await;
+        Mock::given(method("DELETE"))
+            .and(path(
+                "/repos/octocat/Hello-World/issues/1347/labels/help%20wanted",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(labels(&["bug"])))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/repos/octocat/Hello-World/issues/1347/labels/enhancement"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "message": "Label does not exist",
+                "documentation_url": "https://docs.github.com/rest/issues/labels#remove-a-label-from-an-issue"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let issues = octocrab.issues("octocat", "Hello-World");
+
+        let remaining = issues.remove_label(1347, "help wanted").await.unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].name, "bug");
+
+        let error = issues.remove_label(1347, "enhancement").await.unwrap_err();
+        assert!(error.is_not_found());
+    }
+
+    #[tokio::test]
+    async fn replace_all_labels() {
+        let server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path("/repos/octocat/Hello-World/issues/1347/labels"))
+            .and(body_json(
+                serde_json::json!({ "labels": ["bug", "wontfix"] }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(labels(&["bug", "wontfix"])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let labels = crate::setup(&server)
+            .issues("octocat", "Hello-World")
+            .replace_all_labels(1347, &["bug".to_owned(), "wontfix".to_owned()])
+            .await
+            .unwrap();
+
+        let names: Vec<_> = labels.iter().map(|label| label.name.as_str()).collect();
+        assert_eq!(names, ["bug", "wontfix"]);
+    }
+
+    #[tokio::test]
+    async fn lock() {
+        let server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path("/repos/octocat/Hello-World/issues/1347/lock"))
+            .and(body_json(
+                serde_json::json!({ "lock_reason": "too heated" }),
+            ))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/repos/octocat/Hello-World/issues/1347/lock"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/repos/octocat/Hello-World/issues/1348/lock"))
+            .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+                "message": "Must have push access to repository",
+                "documentation_url": "https://docs.github.com/rest/issues/issues#unlock-an-issue"
+            })))
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let issues = octocrab.issues("octocat", "Hello-World");
+
+        issues
+            .lock(1347, crate::params::issues::LockReason::TooHeated)
+            .await
+            .unwrap();
+        issues.unlock(1347).await.unwrap();
+        let error = issues.unlock(1348).await.unwrap_err();
+        assert_eq!(error.status_code(), Some(reqwest::StatusCode::FORBIDDEN));
+    }
+
+    #[tokio::test]
+    async fn get_missing_issue() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/issues/9999"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "message": "Not Found",
+                "documentation_url": "https://docs.github.com/rest/issues/issues#get-an-issue"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let error = crate::setup(&server)
+            .issues("octocat", "Hello-World")
+            .get(9999)
+            .await
+            .unwrap_err();
+
+        assert!(error.is_not_found());
+    }
+
+    #[tokio::test]
+    async fn routes_use_issue_number() {
+        let event: serde_json::Value = serde_json::from_str(include_str!(
+            "../../tests/resources/cross_referenced_event.json"
+        ))
+        .unwrap();
+        let mut issue = event["source"]["issue"].clone();
+        issue["id"] = 1296269.into();
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Spoon-Knife/issues/42"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(issue))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/repos/octocat/Spoon-Knife/issues/42/labels"))
+            .and(body_json(serde_json::json!({ "labels": ["bug"] })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let issues = octocrab.issues("octocat", "Spoon-Knife");
+        let issue = issues.get(42).await.unwrap();
+        assert_eq!(issue.id, 1296269);
+
+        issues
+            .add_labels(issue.number, &["bug".to_owned()])
+            .await
+            .unwrap();
+    }
+}
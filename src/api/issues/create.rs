@@ -1,13 +1,23 @@
 use crate::models;
 
+/// A builder pattern struct for creating an issue. Only the fields that have
+/// been set are sent.
+///
+/// created by [`IssueHandler::create`]
+///
+/// [`IssueHandler::create`]: ./struct.IssueHandler.html#method.create
 #[derive(serde::Serialize)]
 pub struct CreateIssueBuilder<'octo, 'r> {
     #[serde(skip)]
     handler: &'r super::IssueHandler<'octo>,
     title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     milestone: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     labels: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     assignees: Option<Vec<String>>,
 }
 
@@ -89,4 +99,16 @@ mod tests {
             })
         )
     }
+
+    #[test]
+    fn serialize_skips_unset_fields() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.issues("owner", "repo");
+        let create = handler.create("test-issue").body::<String>(None);
+
+        assert_eq!(
+            serde_json::to_value(create).unwrap(),
+            serde_json::json!({ "title": "test-issue" })
+        )
+    }
 }
@@ -10,11 +10,18 @@ pub struct ListIssuesBuilder<'octo, 'b, 'c, 'd> {
     assignee: Option<params::issues::Filter<&'c str>>,
     creator: Option<String>,
     mentioned: Option<String>,
-    labels: Option<&'d [String]>,
+    #[serde(serialize_with = "comma_separated")]
+    labels: Option<std::borrow::Cow<'d, [String]>>,
     sort: Option<crate::params::issues::Sort>,
     direction: Option<crate::params::Direction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    since: Option<chrono::DateTime<chrono::Utc>>,
     per_page: Option<u8>,
     page: Option<u32>,
+    #[serde(flatten)]
+    params: std::collections::BTreeMap<String, String>,
+    #[serde(skip)]
+    etag: Option<String>,
 }
 
 impl<'octo, 'b, 'c, 'd> ListIssuesBuilder<'octo, 'b, 'c, 'd> {
@@ -29,15 +36,21 @@ impl<'octo, 'b, 'c, 'd> ListIssuesBuilder<'octo, 'b, 'c, 'd> {
             labels: None,
             sort: None,
             direction: None,
+            since: None,
             per_page: None,
             page: None,
+            params: std::collections::BTreeMap::new(),
+            etag: None,
         }
     }
 
     /// If an integer is passed, it should refer to a milestone by its number
-    /// field. If the string `"*"` is passed, issues with any milestone are
-    /// accepted. If the string none is passed, issues without milestones
+    /// field. If [`Filter::any`] is passed, issues with any milestone are
+    /// accepted. If [`Filter::none`] is passed, issues without milestones
     /// are returned.
+    ///
+    /// [`Filter::any`]: ../params/issues/enum.Filter.html#method.any
+    /// [`Filter::none`]: ../params/issues/enum.Filter.html#method.none
     pub fn milestone(mut self, milestone: impl Into<params::issues::Filter<u64>>) -> Self {
         self.milestone = Some(milestone.into());
         self
@@ -71,13 +84,34 @@ impl<'octo, 'b, 'c, 'd> ListIssuesBuilder<'octo, 'b, 'c, 'd> {
 
     /// Filter issues by label.
     pub fn labels(mut self, labels: &'d (impl AsRef<[String]> + ?Sized)) -> Self {
-        self.labels = Some(labels.as_ref());
+        self.labels = Some(labels.as_ref().into());
+        self
+    }
+
+    /// Filter issues by label, like [`labels`](#method.labels), but taking
+    /// ownership of `labels` so that they don't need to outlive the builder.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::instance();
+    /// let issues = octocrab.issues("owner", "repo");
+    ///
+    /// for area in &["parser", "codegen"] {
+    ///     let page = issues
+    ///         .list()
+    ///         .labels_owned(vec![format!("A-{}", area), String::from("bug")])
+    ///         .send()
+    ///         .await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn labels_owned(mut self, labels: impl Into<Vec<String>>) -> Self {
+        self.labels = Some(labels.into().into());
         self
     }
 
-    /// What to sort results by. Can be either `created`, `updated`,
-    /// `popularity` (comment count) or `long-running` (age, filtering by pulls
-    /// updated in the last month).
+    /// What to sort results by. Can be either `created`, `updated`, or
+    /// `comments`.
     pub fn sort(mut self, sort: impl Into<crate::params::issues::Sort>) -> Self {
         self.sort = Some(sort.into());
         self
@@ -91,9 +125,15 @@ impl<'octo, 'b, 'c, 'd> ListIssuesBuilder<'octo, 'b, 'c, 'd> {
         self
     }
 
+    /// Only issues updated at or after this time are returned.
+    pub fn since(mut self, since: impl Into<chrono::DateTime<chrono::Utc>>) -> Self {
+        self.since = Some(since.into());
+        self
+    }
+
     /// Results per page (max 100).
-    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
-        self.per_page = Some(per_page.into());
+    pub fn per_page(mut self, per_page: impl std::convert::TryInto<i64>) -> Self {
+        self.per_page = Some(crate::page::clamp_per_page(per_page));
         self
     }
 
@@ -103,6 +143,37 @@ impl<'octo, 'b, 'c, 'd> ListIssuesBuilder<'octo, 'b, 'c, 'd> {
         self
     }
 
+    /// Resume listing from a previously saved `cursor`, this sets both the
+    /// page number and the number of results per page.
+    pub fn resume_from(mut self, cursor: crate::PaginationCursor) -> Self {
+        self.page = Some(cursor.page);
+        self.per_page = cursor.per_page.or(self.per_page);
+        self
+    }
+
+    /// Sets an arbitrary query parameter, for filters GitHub supports that
+    /// this builder doesn't have a method for yet. Setting the same `key`
+    /// twice overwrites the previous value.
+    ///
+    /// *Note* Don't use this for parameters the builder already has a
+    /// method for, or the parameter will be sent twice.
+    pub fn param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.params.insert(key.into(), value.into());
+        self
+    }
+
+    /// Only fetch the results if they have changed since the response with
+    /// this `ETag`, see [`Page::etag`]. If they haven't, the page is empty
+    /// and [`Page::not_modified`] is set, and the request doesn't count
+    /// against the rate limit.
+    ///
+    /// [`Page::etag`]: ../struct.Page.html#structfield.etag
+    /// [`Page::not_modified`]: ../struct.Page.html#structfield.not_modified
+    pub fn etag(mut self, etag: impl Into<String>) -> Self {
+        self.etag = Some(etag.into());
+        self
+    }
+
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<crate::Page<crate::models::Issue>> {
         let url = format!(
@@ -110,7 +181,25 @@ impl<'octo, 'b, 'c, 'd> ListIssuesBuilder<'octo, 'b, 'c, 'd> {
             owner = self.handler.owner,
             repo = self.handler.repo
         );
-        self.handler.crab.get(url, Some(&self)).await
+        self.handler
+            .crab
+            .get_page_if_none_match(url, Some(&self), self.etag.as_deref())
+            .await
+    }
+}
+
+/// GitHub expects the `labels` filter as a single comma separated value,
+/// rather than as a repeated query parameter.
+fn comma_separated<S>(
+    labels: &Option<std::borrow::Cow<'_, [String]>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    match labels {
+        Some(labels) => serializer.serialize_str(&labels.join(",")),
+        None => serializer.serialize_none(),
     }
 }
 
@@ -134,6 +223,11 @@ mod tests {
             .labels(&labels)
             .sort(crate::params::issues::Sort::Comments)
             .direction(crate::params::Direction::Ascending)
+            .since(
+                "2021-01-01T00:00:00Z"
+                    .parse::<chrono::DateTime<chrono::Utc>>()
+                    .unwrap(),
+            )
             .per_page(100)
             .page(1u8);
 
@@ -145,12 +239,210 @@ mod tests {
                 "assignee": "ferris",
                 "creator": "octocrab",
                 "mentioned": "octocat",
-                "labels": ["help wanted", "good first issue"],
+                "labels": "help wanted,good first issue",
                 "sort": "comments",
                 "direction": "asc",
+                "since": "2021-01-01T00:00:00Z",
                 "per_page": 100,
                 "page": 1,
             })
         )
     }
+
+    #[test]
+    fn per_page_is_clamped() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.issues("rust-lang", "rust");
+
+        assert_eq!(
+            serde_json::to_value(handler.list().per_page(250u16)).unwrap()["per_page"],
+            100
+        );
+        assert_eq!(
+            serde_json::to_value(handler.list().per_page(0)).unwrap()["per_page"],
+            1
+        );
+    }
+
+    #[test]
+    fn milestone_query_string() {
+        use crate::params::issues::Filter;
+
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.issues("rust-lang", "rust");
+        let query = |milestone: Filter<u64>| {
+            octocrab
+                .client
+                .get(
+                    octocrab
+                        .absolute_url("/repos/rust-lang/rust/issues")
+                        .unwrap(),
+                )
+                .query(&handler.list().milestone(milestone))
+                .build()
+                .unwrap()
+                .url()
+                .query()
+                .map(String::from)
+        };
+
+        assert_eq!(query(Filter::exact(5)).as_deref(), Some("milestone=5"));
+        assert_eq!(query(Filter::any()).as_deref(), Some("milestone=*"));
+        assert_eq!(query(Filter::none()).as_deref(), Some("milestone=none"));
+    }
+
+    #[test]
+    fn labels_query_string() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.issues("rust-lang", "rust");
+        let labels = vec![
+            String::from("help wanted"),
+            String::from("good first issue"),
+        ];
+        let list = handler.list().labels(&labels);
+
+        let request = octocrab
+            .client
+            .get(
+                octocrab
+                    .absolute_url("/repos/rust-lang/rust/issues")
+                    .unwrap(),
+            )
+            .query(&list)
+            .build()
+            .unwrap();
+        let url = request.url();
+
+        // The comma is percent encoded on the wire, which GitHub decodes to
+        // `labels=help+wanted,good+first+issue`.
+        assert_eq!(url.query(), Some("labels=help+wanted%2Cgood+first+issue"));
+        assert_eq!(
+            url.query_pairs().collect::<Vec<_>>(),
+            [("labels".into(), "help wanted,good first issue".into())]
+        );
+    }
+
+    #[test]
+    fn param_query_string() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.issues("rust-lang", "rust");
+        let list = handler
+            .list()
+            .state(crate::params::State::Open)
+            .param("foo", "bar")
+            .param("baz", "qux");
+
+        let request = octocrab
+            .client
+            .get(
+                octocrab
+                    .absolute_url("/repos/rust-lang/rust/issues")
+                    .unwrap(),
+            )
+            .query(&list)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            request.url().query_pairs().collect::<Vec<_>>(),
+            [
+                ("state".into(), "open".into()),
+                ("baz".into(), "qux".into()),
+                ("foo".into(), "bar".into()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn etag() {
+        use wiremock::{
+            matchers::{header, method, path},
+            Mock, MockServer, ResponseTemplate,
+        };
+
+        let event: serde_json::Value = serde_json::from_str(include_str!(
+            "../../../tests/resources/cross_referenced_event.json"
+        ))
+        .unwrap();
+        let etag = r#""644b5b0155e6404a9cc4bd9d8b1ae730""#;
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Spoon-Knife/issues"))
+            .and(header("if-none-match", etag))
+            .respond_with(ResponseTemplate::new(304).insert_header("etag", etag))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Spoon-Knife/issues"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("etag", etag)
+                    .set_body_json(serde_json::json!([event["source"]["issue"]])),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let issues = octocrab.issues("octocat", "Spoon-Knife");
+
+        let page = issues.list().send().await.unwrap();
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.etag.as_deref(), Some(etag));
+        assert!(!page.not_modified);
+
+        let page = issues.list().etag(page.etag.unwrap()).send().await.unwrap();
+        assert!(page.items.is_empty());
+        assert!(page.not_modified);
+    }
+
+    #[test]
+    fn owned_labels() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.issues("rust-lang", "rust");
+        let list = handler
+            .list()
+            .labels_owned(vec![String::from("A-parser"), String::from("bug")]);
+
+        assert_eq!(
+            serde_json::to_value(list).unwrap()["labels"],
+            "A-parser,bug"
+        );
+    }
+
+    #[test]
+    fn resume_from_saved_cursor() {
+        let next = reqwest::Url::parse(
+            "https://api.github.com/repositories/724712/issues?state=open&per_page=50&page=3",
+        )
+        .unwrap();
+        let checkpoint =
+            serde_json::to_string(&crate::PaginationCursor::from_url(&next).unwrap()).unwrap();
+        assert_eq!(checkpoint, r#"{"page":3,"per_page":50}"#);
+
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.issues("rust-lang", "rust");
+        let list = handler
+            .list()
+            .state(crate::params::State::Open)
+            .resume_from(serde_json::from_str(&checkpoint).unwrap());
+
+        assert_eq!(
+            serde_json::to_value(list).unwrap(),
+            serde_json::json!({
+                "state": "open",
+                "milestone": null,
+                "assignee": null,
+                "creator": null,
+                "mentioned": null,
+                "labels": null,
+                "sort": null,
+                "direction": null,
+                "per_page": 50,
+                "page": 3,
+            })
+        )
+    }
 }
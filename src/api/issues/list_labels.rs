@@ -5,13 +5,13 @@ pub struct ListLabelsForIssueBuilder<'octo, 'r> {
     #[serde(skip)]
     handler: &'r IssueHandler<'octo>,
     #[serde(skip)]
-    number: u64,
+    number: models::IssueNumber,
     per_page: Option<u8>,
     page: Option<u32>,
 }
 
 impl<'octo, 'r> ListLabelsForIssueBuilder<'octo, 'r> {
-    pub(crate) fn new(handler: &'r IssueHandler<'octo>, number: u64) -> Self {
+    pub(crate) fn new(handler: &'r IssueHandler<'octo>, number: models::IssueNumber) -> Self {
         Self {
             handler,
             number,
@@ -21,8 +21,8 @@ impl<'octo, 'r> ListLabelsForIssueBuilder<'octo, 'r> {
     }
 
     /// Results per page (max 100).
-    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
-        self.per_page = Some(per_page.into());
+    pub fn per_page(mut self, per_page: impl std::convert::TryInto<i64>) -> Self {
+        self.per_page = Some(crate::page::clamp_per_page(per_page));
         self
     }
 
@@ -63,8 +63,8 @@ impl<'octo, 'r> ListLabelsForRepoBuilder<'octo, 'r> {
     }
 
     /// Results per page (max 100).
-    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
-        self.per_page = Some(per_page.into());
+    pub fn per_page(mut self, per_page: impl std::convert::TryInto<i64>) -> Self {
+        self.per_page = Some(crate::page::clamp_per_page(per_page));
         self
     }
 
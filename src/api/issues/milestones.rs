@@ -0,0 +1,361 @@
+use super::*;
+
+/// A client to GitHub's milestones API.
+///
+/// Created with [`IssueHandler::milestones`].
+///
+/// [`IssueHandler::milestones`]: ./struct.IssueHandler.html#method.milestones
+pub struct MilestonesHandler<'octo, 'r> {
+    handler: &'r IssueHandler<'octo>,
+}
+
+impl<'octo, 'r> MilestonesHandler<'octo, 'r> {
+    pub(crate) fn new(handler: &'r IssueHandler<'octo>) -> Self {
+        Self { handler }
+    }
+
+    /// Gets the milestone with the given `number`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let milestone = octocrab::instance()
+    ///     .issues("owner", "repo")
+    ///     .milestones()
+    ///     .get(1)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, number: u64) -> Result<models::Milestone> {
+        let route = format!(
+            "/repos/{owner}/{repo}/milestones/{number}",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+            number = number,
+        );
+
+        self.handler.crab.get(route, None::<&()>).await
+    }
+
+    /// Gets the milestone with the given `number`, returning `None` if it
+    /// doesn't exist.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// if let Some(milestone) = octocrab::instance()
+    ///     .issues("owner", "repo")
+    ///     .milestones()
+    ///     .get_opt(1)
+    ///     .await?
+    /// {
+    ///     println!("{} open issues", milestone.open_issues.unwrap_or(0));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_opt(&self, number: u64) -> Result<Option<models::Milestone>> {
+        match self.get(number).await {
+            Ok(milestone) => Ok(Some(milestone)),
+            Err(error)
+                if error.as_github().map(|error| error.status_code)
+                    == Some(reqwest::StatusCode::NOT_FOUND) =>
+            {
+                Ok(None)
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Lists the repository's milestones.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params;
+    ///
+    /// let page = octocrab::instance()
+    ///     .issues("owner", "repo")
+    ///     .milestones()
+    ///     .list()
+    ///     // Optional Parameters
+    ///     .state(params::State::All)
+    ///     .sort(params::issues::MilestoneSort::Completeness)
+    ///     .direction(params::Direction::Descending)
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListMilestonesBuilder<'octo, 'r> {
+        ListMilestonesBuilder::new(self.handler)
+    }
+
+    /// Creates a milestone called `title`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let milestone = octocrab::instance()
+    ///     .issues("owner", "repo")
+    ///     .milestones()
+    ///     .create("v1.0")
+    ///     // Optional Parameters
+    ///     .description("Tracking milestone for version 1.0")
+    ///     .due_on(chrono::Utc::now() + chrono::Duration::weeks(4))
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create(&self, title: impl Into<String>) -> CreateMilestoneBuilder<'octo, 'r> {
+        CreateMilestoneBuilder::new(self.handler, title.into())
+    }
+
+    fn route(handler: &IssueHandler<'_>) -> String {
+        format!(
+            "/repos/{owner}/{repo}/milestones",
+            owner = handler.owner,
+            repo = handler.repo,
+        )
+    }
+}
+
+/// A builder pattern struct for listing a repository's milestones.
+///
+/// created by [`MilestonesHandler::list`]
+///
+/// [`MilestonesHandler::list`]: ./struct.MilestonesHandler.html#method.list
+#[derive(serde::Serialize)]
+pub struct ListMilestonesBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r IssueHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<crate::params::State>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort: Option<crate::params::issues::MilestoneSort>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    direction: Option<crate::params::Direction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListMilestonesBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r IssueHandler<'octo>) -> Self {
+        Self {
+            handler,
+            state: None,
+            sort: None,
+            direction: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Filter milestones by `state`. Default: `Open`.
+    pub fn state(mut self, state: impl Into<crate::params::State>) -> Self {
+        self.state = Some(state.into());
+        self
+    }
+
+    /// What to sort results by. Default: `DueOn`.
+    pub fn sort(mut self, sort: impl Into<crate::params::issues::MilestoneSort>) -> Self {
+        self.sort = Some(sort.into());
+        self
+    }
+
+    /// The direction of the sort. Default: ascending.
+    pub fn direction(mut self, direction: impl Into<crate::params::Direction>) -> Self {
+        self.direction = Some(direction.into());
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl std::convert::TryInto<i64>) -> Self {
+        self.per_page = Some(crate::page::clamp_per_page(per_page));
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<crate::Page<models::Milestone>> {
+        self.handler
+            .crab
+            .get(MilestonesHandler::route(self.handler), Some(&self))
+            .await
+    }
+}
+
+/// A builder pattern struct for creating a milestone.
+///
+/// created by [`MilestonesHandler::create`]
+///
+/// [`MilestonesHandler::create`]: ./struct.MilestonesHandler.html#method.create
+#[derive(serde::Serialize)]
+pub struct CreateMilestoneBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r IssueHandler<'octo>,
+    title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<crate::params::State>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    due_on: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl<'octo, 'r> CreateMilestoneBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r IssueHandler<'octo>, title: String) -> Self {
+        Self {
+            handler,
+            title,
+            state: None,
+            description: None,
+            due_on: None,
+        }
+    }
+
+    /// Whether the milestone is open or closed, GitHub rejects `State::All`.
+    /// Default: `Open`.
+    pub fn state(mut self, state: impl Into<crate::params::State>) -> Self {
+        self.state = Some(state.into());
+        self
+    }
+
+    /// A description of the milestone.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// When the milestone is due.
+    pub fn due_on(mut self, due_on: impl Into<chrono::DateTime<chrono::Utc>>) -> Self {
+        self.due_on = Some(due_on.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<models::Milestone> {
+        self.handler
+            .crab
+            .post(MilestonesHandler::route(self.handler), Some(&self))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        matchers::{body_json, method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[tokio::test]
+    async fn get_opt() {
+        let server = MockServer::start().await;
+        let milestone: serde_json::Value =
+            serde_json::from_str(include_str!("../../../tests/resources/milestone.json")).unwrap();
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/milestones/1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(milestone))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/milestones/2"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "message": "Not Found",
+                "documentation_url": "https://docs.github.com/rest/issues/milestones#get-a-milestone"
+            })))
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let issues = octocrab.issues("octocat", "Hello-World");
+        let milestones = issues.milestones();
+
+        assert_eq!(milestones.get_opt(1).await.unwrap().unwrap().number, 1);
+        assert!(milestones.get_opt(2).await.unwrap().is_none());
+        assert!(milestones.get(2).await.is_err());
+    }
+
+    #[test]
+    fn serialize() {
+        let octocrab = crate::Octocrab::default();
+        let issues = octocrab.issues("rust-lang", "rust");
+        let milestones = issues.milestones();
+        let list = milestones
+            .list()
+            .state(crate::params::State::Closed)
+            .sort(crate::params::issues::MilestoneSort::Completeness)
+            .direction(crate::params::Direction::Descending)
+            .per_page(100);
+        let create = milestones
+            .create("v1.0")
+            .description("Tracking milestone for version 1.0")
+            .due_on(
+                "2012-10-09T23:39:01Z"
+                    .parse::<chrono::DateTime<chrono::Utc>>()
+                    .unwrap(),
+            );
+
+        assert_eq!(
+            serde_json::to_value(list).unwrap(),
+            serde_json::json!({
+                "state": "closed",
+                "sort": "completeness",
+                "direction": "desc",
+                "per_page": 100,
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(create).unwrap(),
+            serde_json::json!({
+                "title": "v1.0",
+                "description": "Tracking milestone for version 1.0",
+                "due_on": "2012-10-09T23:39:01Z",
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(milestones.create("v1.0")).unwrap(),
+            serde_json::json!({ "title": "v1.0" })
+        );
+    }
+
+    #[tokio::test]
+    async fn list_and_create() {
+        let server = MockServer::start().await;
+        let milestone: serde_json::Value =
+            serde_json::from_str(include_str!("../../../tests/resources/milestone.json")).unwrap();
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/milestones"))
+            .and(query_param("state", "all"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(vec![milestone.clone()]))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/repos/octocat/Hello-World/milestones"))
+            .and(body_json(serde_json::json!({ "title": "v1.0" })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(milestone))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let issues = octocrab.issues("octocat", "Hello-World");
+        let milestones = issues.milestones();
+
+        let page = milestones
+            .list()
+            .state(crate::params::State::All)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.items[0].number, 1);
+
+        let created = milestones.create("v1.0").send().await.unwrap();
+        assert_eq!(created.title, page.items[0].title);
+    }
+}
@@ -1,22 +1,34 @@
 use super::*;
 use crate::models;
 
+/// A builder pattern struct for updating an issue. Only the fields that have
+/// been set are sent, so unset fields are left unchanged.
+///
+/// created by [`IssueHandler::update`]
+///
+/// [`IssueHandler::update`]: ./struct.IssueHandler.html#method.update
 #[derive(serde::Serialize)]
 pub struct UpdateIssueBuilder<'octo, 'a, 'b, 'c, 'd, 'e> {
     #[serde(skip)]
     handler: &'a IssueHandler<'octo>,
     #[serde(skip)]
-    number: u64,
+    number: models::IssueNumber,
+    #[serde(skip_serializing_if = "Option::is_none")]
     title: Option<&'b str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     body: Option<&'c str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     assignees: Option<&'d [String]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     state: Option<models::IssueState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     milestone: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     labels: Option<&'e [String]>,
 }
 
 impl<'octo, 'a, 'b, 'c, 'd, 'e> UpdateIssueBuilder<'octo, 'a, 'b, 'c, 'd, 'e> {
-    pub(crate) fn new(handler: &'a IssueHandler<'octo>, number: u64) -> Self {
+    pub(crate) fn new(handler: &'a IssueHandler<'octo>, number: models::IssueNumber) -> Self {
         Self {
             handler,
             number,
@@ -110,4 +122,18 @@ mod tests {
             })
         )
     }
+
+    #[test]
+    fn serialize_only_set_fields() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.issues("rust-lang", "rust");
+        let update = handler
+            .update(1234)
+            .state(crate::models::IssueState::Closed);
+
+        assert_eq!(
+            serde_json::to_value(update).unwrap(),
+            serde_json::json!({ "state": "closed" })
+        )
+    }
 }
@@ -1,10 +1,20 @@
 //! The Organization API.
 
+mod actions;
+mod list_invitations;
+mod list_members;
+mod list_outside_collaborators;
 mod list_repos;
+mod list_teams;
+mod update;
 
 use crate::Octocrab;
 
-pub use self::list_repos::ListReposBuilder;
+pub use self::{
+    actions::ActionsHandler, list_invitations::ListPendingInvitationsBuilder,
+    list_members::ListMembersBuilder, list_outside_collaborators::ListOutsideCollaboratorsBuilder,
+    list_repos::ListReposBuilder, list_teams::ListTeamsBuilder, update::UpdateOrgBuilder,
+};
 
 /// A client to GitHub's organization API.
 ///
@@ -18,7 +28,26 @@ pub struct OrgHandler<'octo> {
 
 impl<'octo> OrgHandler<'octo> {
     pub(crate) fn new(crab: &'octo Octocrab, owner: String) -> Self {
-        Self { crab, owner }
+        Self {
+            crab,
+            owner: crate::encoding::segment(&owner),
+        }
+    }
+
+    /// Creates an `ActionsHandler` for managing the organization's GitHub
+    /// Actions secrets.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let secrets = octocrab::instance()
+    ///     .orgs("owner")
+    ///     .actions()
+    ///     .list_secrets()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn actions(&self) -> ActionsHandler<'octo, '_> {
+        ActionsHandler::new(self)
     }
 
     /// Add or update organization membership
@@ -50,12 +79,141 @@ impl<'octo> OrgHandler<'octo> {
         let url = format!(
             "/orgs/{org}/memberships/{username}",
             org = self.owner,
-            username = username.as_ref(),
+            username = crate::encoding::segment(username.as_ref()),
+        );
+
+        let mut request = self.crab.client.put(self.crab.absolute_url(url)?);
+        if let Some(role) = role {
+            request = request.json(&serde_json::json!({ "role": role }));
+        }
+        let response = self.crab.execute(request).await?;
+
+        crate::FromResponse::from_response(Octocrab::map_github_error(response).await?).await
+    }
+
+    /// Removes a user from the organization, along with their access to the
+    /// organization's repositories.
+    ///
+    /// **Note** Only organization owners can remove members, otherwise GitHub
+    /// returns a `403 Forbidden` error.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance().orgs("owner").remove_member("ferris").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_member(&self, username: impl AsRef<str>) -> crate::Result<()> {
+        let url = format!(
+            "/orgs/{org}/members/{username}",
+            org = self.owner,
+            username = crate::encoding::segment(username.as_ref()),
+        );
+
+        let response = self
+            .crab
+            ._delete(self.crab.absolute_url(url)?, None::<&()>)
+            .await?;
+
+        Octocrab::map_no_content(response).await
+    }
+
+    /// Converts a member of the organization into an outside collaborator,
+    /// keeping their access to the repositories they can access through their
+    /// teams or memberships.
+    ///
+    /// **Note** Only organization owners can convert members, otherwise
+    /// GitHub returns a `403 Forbidden` error.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .orgs("owner")
+    ///     .convert_member_to_outside_collaborator("ferris")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn convert_member_to_outside_collaborator(
+        &self,
+        username: impl AsRef<str>,
+    ) -> crate::Result<()> {
+        let url = format!(
+            "/orgs/{org}/outside_collaborators/{username}",
+            org = self.owner,
+            username = crate::encoding::segment(username.as_ref()),
         );
 
-        let body = role.map(|role| serde_json::json!({ "role": role }));
+        let response = self
+            .crab
+            ._put(self.crab.absolute_url(url)?, None::<&()>)
+            .await?;
+
+        Octocrab::map_github_error(response).await.map(drop)
+    }
 
-        self.crab.post(url, body.as_ref()).await
+    /// Invites a user to the organization by their email address or GitHub
+    /// user id, optionally adding them to the teams with `team_ids` once they
+    /// accept. Default role: `direct_member`.
+    ///
+    /// **Note** Only organization owners can create invitations, otherwise
+    /// GitHub returns a `403 Forbidden` error.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::orgs::InvitationRole;
+    ///
+    /// let invitation = octocrab::instance()
+    ///     .orgs("owner")
+    ///     .create_invitation("ferris@example.com", InvitationRole::DirectMember, &[12])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_invitation(
+        &self,
+        invitee: impl Into<crate::params::orgs::Invitee>,
+        role: impl Into<Option<crate::params::orgs::InvitationRole>>,
+        team_ids: &[u64],
+    ) -> crate::Result<crate::models::OrgInvitation> {
+        #[derive(serde::Serialize)]
+        struct Body<'a> {
+            #[serde(flatten)]
+            invitee: crate::params::orgs::Invitee,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            role: Option<crate::params::orgs::InvitationRole>,
+            #[serde(skip_serializing_if = "<[u64]>::is_empty")]
+            team_ids: &'a [u64],
+        }
+
+        let url = format!("/orgs/{org}/invitations", org = self.owner);
+        let body = Body {
+            invitee: invitee.into(),
+            role: role.into(),
+            team_ids,
+        };
+
+        self.crab.post(url, Some(&body)).await
+    }
+
+    /// Lists the organization's pending invitations.
+    ///
+    /// **Note** Only organization owners can list invitations, otherwise
+    /// GitHub returns a `403 Forbidden` error.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance()
+    ///     .orgs("owner")
+    ///     .list_pending_invitations()
+    ///     // Optional Parameters
+    ///     .per_page(100)
+    ///     .page(2u32)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_pending_invitations(
+        &self,
+    ) -> list_invitations::ListPendingInvitationsBuilder<'_, '_> {
+        list_invitations::ListPendingInvitationsBuilder::new(self)
     }
 
     /// Check if a user is, publicly or privately, a member of the organization.
@@ -71,7 +229,7 @@ impl<'octo> OrgHandler<'octo> {
         let url = format!(
             "/orgs/{org}/members/{username}",
             org = self.owner,
-            username = username.as_ref(),
+            username = crate::encoding::segment(username.as_ref()),
         );
 
         let response = self
@@ -103,6 +261,29 @@ impl<'octo> OrgHandler<'octo> {
         self.crab.get(route, None::<&()>).await
     }
 
+    /// Update the organization's settings, only the settings you set on the
+    /// builder are changed.
+    ///
+    /// **Note** Only organization owners can update the organization's
+    /// settings, otherwise GitHub returns a `403 Forbidden` error.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::orgs::DefaultRepositoryPermission;
+    ///
+    /// let org = octocrab::instance()
+    ///     .orgs("owner")
+    ///     .update()
+    ///     .default_repository_permission(DefaultRepositoryPermission::Read)
+    ///     .members_can_create_repositories(false)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self) -> update::UpdateOrgBuilder<'_, '_> {
+        update::UpdateOrgBuilder::new(self)
+    }
+
     /// List repos for the specified organization.
     ///
     /// ```no_run
@@ -125,7 +306,289 @@ impl<'octo> OrgHandler<'octo> {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn list_repos(&self) -> list_repos::ListReposBuilder {
+    pub fn list_repos(&self) -> list_repos::ListReposBuilder<'_, '_> {
         list_repos::ListReposBuilder::new(self)
     }
+
+    /// Lists the organization's members. If the authenticated user isn't a
+    /// member of the organization, only public members are listed.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance()
+    ///     .orgs("owner")
+    ///     .list_members()
+    ///     // Optional Parameters
+    ///     .per_page(100)
+    ///     .page(2u32)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_members(&self) -> list_members::ListMembersBuilder<'_, '_> {
+        list_members::ListMembersBuilder::new(self)
+    }
+
+    /// Lists the teams in the organization that are visible to the
+    /// authenticated user.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance()
+    ///     .orgs("owner")
+    ///     .list_teams()
+    ///     // Optional Parameters
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    ///
+    /// for team in page {
+    ///     println!("{} ({})", team.name, team.permission);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_teams(&self) -> list_teams::ListTeamsBuilder<'_, '_> {
+        list_teams::ListTeamsBuilder::new(self)
+    }
+
+    /// Lists the organization's outside collaborators, i.e. users who have
+    /// access to at least one of the organization's repositories without
+    /// being a member of the organization.
+    ///
+    /// **Note** Only organization owners can list outside collaborators,
+    /// otherwise GitHub returns a `403 Forbidden` error.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::orgs::OutsideCollaboratorFilter;
+    ///
+    /// // Find outside collaborators without two-factor authentication.
+    /// let page = octocrab::instance()
+    ///     .orgs("owner")
+    ///     .list_outside_collaborators()
+    ///     // Optional Parameters
+    ///     .filter(OutsideCollaboratorFilter::TwoFactorDisabled)
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_outside_collaborators(
+        &self,
+    ) -> list_outside_collaborators::ListOutsideCollaboratorsBuilder<'_, '_> {
+        list_outside_collaborators::ListOutsideCollaboratorsBuilder::new(self)
+    }
+
+    /// Removes an outside collaborator from all of the organization's
+    /// repositories.
+    ///
+    /// **Note** Only organization owners can remove outside collaborators,
+    /// otherwise GitHub returns a `403 Forbidden` error.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .orgs("owner")
+    ///     .remove_outside_collaborator("ferris")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_outside_collaborator(
+        &self,
+        username: impl AsRef<str>,
+    ) -> crate::Result<()> {
+        let url = format!(
+            "/orgs/{org}/outside_collaborators/{username}",
+            org = self.owner,
+            username = crate::encoding::segment(username.as_ref()),
+        );
+
+        let response = self
+            .crab
+            ._delete(self.crab.absolute_url(url)?, None::<&()>)
+            .await?;
+
+        Octocrab::map_no_content(response).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        matchers::{body_json, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    fn invitation() -> serde_json::Value {
+        serde_json::json!({
+            "id": 1,
+            "login": null,
+            "node_id": "MDIyOk9yZ2FuaXphdGlvbkludml0YXRpb24x",
+            "email": "ferris@example.com",
+            "role": "direct_member",
+            "created_at": "2016-11-30T06:46:10-08:00",
+            "failed_at": null,
+            "failed_reason": null,
+            "inviter": serde_json::from_str::<serde_json::Value>(include_str!(
+                "../../tests/resources/repository.json"
+            ))
+            .unwrap()["owner"],
+            "team_count": 2,
+            "invitation_teams_url": "https://api.github.com/organizations/2/invitations/1/teams"
+        })
+    }
+
+    #[tokio::test]
+    async fn invitations() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/orgs/github/invitations"))
+            .and(body_json(serde_json::json!({
+                "email": "ferris@example.com",
+                "role": "direct_member",
+                "team_ids": [12, 26],
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(invitation()))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/orgs/github/invitations"))
+            .and(body_json(serde_json::json!({ "invitee_id": 1 })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(invitation()))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/orgs/github/invitations"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(vec![invitation()]))
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let orgs = octocrab.orgs("github");
+
+        let invitation = orgs
+            .create_invitation(
+                "ferris@example.com",
+                crate::params::orgs::InvitationRole::DirectMember,
+                &[12, 26],
+            )
+            .await
+            .unwrap();
+        assert_eq!(invitation.email.as_deref(), Some("ferris@example.com"));
+        assert!(invitation.login.is_none());
+        assert_eq!(invitation.team_count, 2);
+        orgs.create_invitation(1, None, &[]).await.unwrap();
+
+        let invitations = orgs.list_pending_invitations().send().await.unwrap().items;
+        assert_eq!(invitations[0].role, "direct_member");
+        assert_eq!(invitations[0].created_at.timestamp(), 1480517170);
+    }
+
+    #[tokio::test]
+    async fn add_or_update_membership() {
+        let server = MockServer::start().await;
+        let user = serde_json::from_str::<serde_json::Value>(include_str!(
+            "../../tests/resources/repository.json"
+        ))
+        .unwrap()["owner"]
+            .clone();
+        let membership = serde_json::json!({
+            "url": "https://api.github.com/orgs/github/memberships/ferris",
+            "state": "pending",
+            "role": "admin",
+            "organization_url": "https://api.github.com/orgs/github",
+            "organization": {
+                "login": "github",
+                "id": 1,
+                "node_id": "MDEyOk9yZ2FuaXphdGlvbjE=",
+                "url": "https://api.github.com/orgs/github",
+                "repos_url": "https://api.github.com/orgs/github/repos",
+                "events_url": "https://api.github.com/orgs/github/events",
+                "hooks_url": "https://api.github.com/orgs/github/hooks",
+                "issues_url": "https://api.github.com/orgs/github/issues",
+                "members_url": "https://api.github.com/orgs/github/members{/member}",
+                "public_members_url": "https://api.github.com/orgs/github/public_members{/member}",
+                "avatar_url": "https://github.com/images/error/octocat_happy.gif"
+            },
+            "user": user,
+        });
+        Mock::given(method("PUT"))
+            .and(path("/orgs/github/memberships/ferris"))
+            .and(body_json(serde_json::json!({ "role": "admin" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(membership))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let membership = crate::setup(&server)
+            .orgs("github")
+            .add_or_update_membership("ferris", Some(crate::params::orgs::Role::Admin))
+            .await
+            .unwrap();
+
+        assert_eq!(membership.role, "admin");
+        assert_eq!(membership.state, "pending");
+    }
+
+    #[tokio::test]
+    async fn remove_member() {
+        let server = MockServer::start().await;
+        Mock::given(method("DELETE"))
+            .and(path("/orgs/github/members/ferris"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+
+        octocrab
+            .orgs("github")
+            .remove_member("ferris")
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn list_members_and_teams() {
+        let server = MockServer::start().await;
+        let owner = serde_json::from_str::<serde_json::Value>(include_str!(
+            "../../tests/resources/repository.json"
+        ))
+        .unwrap()["owner"]
+            .clone();
+        let teams: serde_json::Value =
+            serde_json::from_str(include_str!("../../tests/resources/teams.json")).unwrap();
+        Mock::given(method("GET"))
+            .and(path("/orgs/github/members"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(vec![owner]))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/orgs/github/teams"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(teams))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let orgs = octocrab.orgs("github");
+
+        let members = orgs.list_members().send().await.unwrap();
+        assert_eq!(members.items.len(), 1);
+        assert_eq!(members.items[0].login, "octocat");
+
+        let teams = orgs.list_teams().per_page(100).send().await.unwrap();
+        assert_eq!(teams.items.len(), 1);
+        let team = &teams.items[0];
+        assert_eq!(team.id, 1);
+        assert_eq!(team.name, "Justice League");
+        assert_eq!(team.slug, "justice-league");
+        assert_eq!(team.privacy, "closed");
+        assert_eq!(team.permission, "admin");
+        assert_eq!(team.members_count, None);
+    }
 }
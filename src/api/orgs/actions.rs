@@ -0,0 +1,261 @@
+use super::*;
+
+/// A client to GitHub's organization Actions API.
+///
+/// Created with [`OrgHandler::actions`].
+///
+/// [`OrgHandler::actions`]: ./struct.OrgHandler.html#method.actions
+pub struct ActionsHandler<'octo, 'r> {
+    handler: &'r OrgHandler<'octo>,
+}
+
+impl<'octo, 'r> ActionsHandler<'octo, 'r> {
+    pub(crate) fn new(handler: &'r OrgHandler<'octo>) -> Self {
+        Self { handler }
+    }
+
+    /// Gets the organization's public key, which is needed to encrypt
+    /// secrets before creating or updating them.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let key = octocrab::instance()
+    ///     .orgs("owner")
+    ///     .actions()
+    ///     .get_public_key()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_public_key(&self) -> crate::Result<crate::models::PublicKey> {
+        let route = format!("{}/public-key", self.secrets_route());
+
+        self.handler.crab.get(route, None::<&()>).await
+    }
+
+    /// Lists the organization's secrets, without their values.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance()
+    ///     .orgs("owner")
+    ///     .actions()
+    ///     .list_secrets()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_secrets(
+        &self,
+    ) -> crate::Result<crate::Page<crate::models::OrganizationSecret>> {
+        self.handler
+            .crab
+            .get_enveloped_page(
+                self.secrets_route(),
+                None::<&()>,
+                reqwest::header::HeaderMap::new(),
+                "secrets",
+            )
+            .await
+    }
+
+    /// Creates or updates the secret `name`, which the repositories matching
+    /// `visibility` can access. `selected_repository_ids` is only used when
+    /// `visibility` is [`Selected`].
+    ///
+    /// `encrypted_value` must already be encrypted with the organization's
+    /// public key, identified by `key_id`, using a libsodium sealed box.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::orgs::SecretVisibility;
+    ///
+    /// let octocrab = octocrab::instance();
+    /// let orgs = octocrab.orgs("owner");
+    /// let actions = orgs.actions();
+    /// let key = actions.get_public_key().await?;
+    /// # let encrypted_value = String::new();
+    ///
+    /// actions
+    ///     .create_or_update_secret(
+    ///         "DEPLOY_TOKEN",
+    ///         encrypted_value,
+    ///         key.key_id,
+    ///         SecretVisibility::Selected,
+    ///         &[1296269],
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Selected`]: ../params/orgs/enum.SecretVisibility.html#variant.Selected
+    pub async fn create_or_update_secret(
+        &self,
+        name: impl AsRef<str>,
+        encrypted_value: impl Into<String>,
+        key_id: impl Into<String>,
+        visibility: crate::params::orgs::SecretVisibility,
+        selected_repository_ids: &[u64],
+    ) -> crate::Result<()> {
+        let body = SecretBody {
+            encrypted_value: encrypted_value.into(),
+            key_id: key_id.into(),
+            visibility,
+            selected_repository_ids,
+        };
+        let request = self
+            .handler
+            .crab
+            .client
+            .put(
+                self.handler
+                    .crab
+                    .absolute_url(self.secret_route(name.as_ref()))?,
+            )
+            .json(&body);
+        let response = self.handler.crab.execute(request).await?;
+
+        // GitHub responds with `201 Created` for new secrets, and with
+        // `204 No Content` for updated secrets.
+        if response.status() == 201 {
+            Ok(())
+        } else {
+            Octocrab::map_no_content(response).await
+        }
+    }
+
+    /// Replaces the repositories that can access the secret `name`, which
+    /// must have the `selected` visibility.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .orgs("owner")
+    ///     .actions()
+    ///     .set_selected_repos("DEPLOY_TOKEN", &[1296269, 1296270])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_selected_repos(
+        &self,
+        name: impl AsRef<str>,
+        selected_repository_ids: &[u64],
+    ) -> crate::Result<()> {
+        let route = format!("{}/repositories", self.secret_route(name.as_ref()));
+        let request = self
+            .handler
+            .crab
+            .client
+            .put(self.handler.crab.absolute_url(route)?)
+            .json(&serde_json::json!({
+                "selected_repository_ids": selected_repository_ids,
+            }));
+        let response = self.handler.crab.execute(request).await?;
+
+        Octocrab::map_no_content(response).await
+    }
+
+    fn secrets_route(&self) -> String {
+        format!("/orgs/{org}/actions/secrets", org = self.handler.owner)
+    }
+
+    fn secret_route(&self, name: &str) -> String {
+        format!(
+            "{}/{}",
+            self.secrets_route(),
+            crate::encoding::segment(name)
+        )
+    }
+}
+
+#[derive(serde::Serialize)]
+struct SecretBody<'a> {
+    encrypted_value: String,
+    key_id: String,
+    visibility: crate::params::orgs::SecretVisibility,
+    #[serde(skip_serializing_if = "<[u64]>::is_empty")]
+    selected_repository_ids: &'a [u64],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SecretBody;
+    use crate::params::orgs::SecretVisibility;
+    use wiremock::{
+        matchers::{body_json, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[test]
+    fn serialize() {
+        let body = |visibility, selected_repository_ids| {
+            serde_json::to_value(SecretBody {
+                encrypted_value: String::from("c2VjcmV0"),
+                key_id: String::from("012345678912345678"),
+                visibility,
+                selected_repository_ids,
+            })
+            .unwrap()
+        };
+
+        assert_eq!(
+            body(SecretVisibility::Selected, &[1296269, 1296270]),
+            serde_json::json!({
+                "encrypted_value": "c2VjcmV0",
+                "key_id": "012345678912345678",
+                "visibility": "selected",
+                "selected_repository_ids": [1296269, 1296270],
+            })
+        );
+        assert_eq!(
+            body(SecretVisibility::Private, &[]),
+            serde_json::json!({
+                "encrypted_value": "c2VjcmV0",
+                "key_id": "012345678912345678",
+                "visibility": "private",
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn secrets() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/orgs/octo-org/actions/secrets"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 1,
+                "secrets": [{
+                    "name": "DEPLOY_TOKEN",
+                    "created_at": "2019-08-10T14:59:22Z",
+                    "updated_at": "2020-01-10T14:59:22Z",
+                    "visibility": "selected",
+                    "selected_repositories_url": "https://api.github.com/orgs/octo-org/actions/secrets/DEPLOY_TOKEN/repositories"
+                }]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path(
+                "/orgs/octo-org/actions/secrets/DEPLOY_TOKEN/repositories",
+            ))
+            .and(body_json(serde_json::json!({
+                "selected_repository_ids": [1296269],
+            })))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let orgs = octocrab.orgs("octo-org");
+        let actions = orgs.actions();
+
+        let page = actions.list_secrets().await.unwrap();
+        assert_eq!(page.total_count, Some(1));
+        assert_eq!(page.items[0].name, "DEPLOY_TOKEN");
+        assert_eq!(page.items[0].visibility, "selected");
+
+        actions
+            .set_selected_repos("DEPLOY_TOKEN", &[1296269])
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,88 @@
+use super::*;
+
+/// A builder pattern struct for listing an organization's outside
+/// collaborators.
+///
+/// created by [`OrgHandler::list_outside_collaborators`]
+///
+/// [`OrgHandler::list_outside_collaborators`]: ./struct.OrgHandler.html#method.list_outside_collaborators
+#[derive(serde::Serialize)]
+pub struct ListOutsideCollaboratorsBuilder<'octo, 'b> {
+    #[serde(skip)]
+    handler: &'b OrgHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter: Option<crate::params::orgs::OutsideCollaboratorFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'b> ListOutsideCollaboratorsBuilder<'octo, 'b> {
+    pub(crate) fn new(handler: &'b OrgHandler<'octo>) -> Self {
+        Self {
+            handler,
+            filter: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Filter the outside collaborators, e.g. to those without two-factor
+    /// authentication. Default: `All`.
+    pub fn filter(
+        mut self,
+        filter: impl Into<crate::params::orgs::OutsideCollaboratorFilter>,
+    ) -> Self {
+        self.filter = Some(filter.into());
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl std::convert::TryInto<i64>) -> Self {
+        self.per_page = Some(crate::page::clamp_per_page(per_page));
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<crate::Page<crate::models::User>> {
+        let url = format!(
+            "/orgs/{org}/outside_collaborators",
+            org = self.handler.owner
+        );
+        self.handler.crab.get(url, Some(&self)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.orgs("rust-lang");
+        let list = handler
+            .list_outside_collaborators()
+            .filter(crate::params::orgs::OutsideCollaboratorFilter::TwoFactorDisabled)
+            .per_page(100)
+            .page(2u32);
+
+        assert_eq!(
+            serde_json::to_value(list).unwrap(),
+            serde_json::json!({
+                "filter": "2fa_disabled",
+                "per_page": 100,
+                "page": 2,
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(handler.list_outside_collaborators()).unwrap(),
+            serde_json::json!({})
+        );
+    }
+}
@@ -44,8 +44,8 @@ impl<'octo, 'b> ListReposBuilder<'octo, 'b> {
     }
 
     /// Results per page (max 100).
-    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
-        self.per_page = Some(per_page.into());
+    pub fn per_page(mut self, per_page: impl std::convert::TryInto<i64>) -> Self {
+        self.per_page = Some(crate::page::clamp_per_page(per_page));
         self
     }
 
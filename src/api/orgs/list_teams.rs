@@ -0,0 +1,44 @@
+use super::*;
+
+/// A builder pattern struct for listing an organization's teams.
+///
+/// created by [`OrgHandler::list_teams`]
+///
+/// [`OrgHandler::list_teams`]: ./struct.OrgHandler.html#method.list_teams
+#[derive(serde::Serialize)]
+pub struct ListTeamsBuilder<'octo, 'b> {
+    #[serde(skip)]
+    handler: &'b OrgHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'b> ListTeamsBuilder<'octo, 'b> {
+    pub(crate) fn new(handler: &'b OrgHandler<'octo>) -> Self {
+        Self {
+            handler,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl std::convert::TryInto<i64>) -> Self {
+        self.per_page = Some(crate::page::clamp_per_page(per_page));
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<crate::Page<crate::models::Team>> {
+        let url = format!("/orgs/{org}/teams", org = self.handler.owner);
+        self.handler.crab.get(url, Some(&self)).await
+    }
+}
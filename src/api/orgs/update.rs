@@ -0,0 +1,131 @@
+use super::*;
+
+/// A builder pattern struct for updating an organization's settings. Only
+/// the settings that have been set are sent, so unset settings are left
+/// unchanged.
+///
+/// created by [`OrgHandler::update`]
+///
+/// [`OrgHandler::update`]: ./struct.OrgHandler.html#method.update
+#[derive(serde::Serialize)]
+pub struct UpdateOrgBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r OrgHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    billing_email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    company: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    has_organization_projects: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_repository_permission: Option<crate::params::orgs::DefaultRepositoryPermission>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    members_can_create_repositories: Option<bool>,
+}
+
+impl<'octo, 'r> UpdateOrgBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r OrgHandler<'octo>) -> Self {
+        Self {
+            handler,
+            billing_email: None,
+            company: None,
+            email: None,
+            location: None,
+            description: None,
+            has_organization_projects: None,
+            default_repository_permission: None,
+            members_can_create_repositories: None,
+        }
+    }
+
+    /// The billing email address, this isn't publicized.
+    pub fn billing_email(mut self, billing_email: impl Into<String>) -> Self {
+        self.billing_email = Some(billing_email.into());
+        self
+    }
+
+    /// The company name.
+    pub fn company(mut self, company: impl Into<String>) -> Self {
+        self.company = Some(company.into());
+        self
+    }
+
+    /// The publicly visible email address.
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.email = Some(email.into());
+        self
+    }
+
+    /// The location.
+    pub fn location(mut self, location: impl Into<String>) -> Self {
+        self.location = Some(location.into());
+        self
+    }
+
+    /// The description of the organization.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Whether the organization can use organization projects.
+    pub fn has_organization_projects(mut self, has_organization_projects: bool) -> Self {
+        self.has_organization_projects = Some(has_organization_projects);
+        self
+    }
+
+    /// The default permission level members have for the organization's
+    /// repositories.
+    pub fn default_repository_permission(
+        mut self,
+        default_repository_permission: crate::params::orgs::DefaultRepositoryPermission,
+    ) -> Self {
+        self.default_repository_permission = Some(default_repository_permission);
+        self
+    }
+
+    /// Whether non-admin members can create repositories.
+    pub fn members_can_create_repositories(
+        mut self,
+        members_can_create_repositories: bool,
+    ) -> Self {
+        self.members_can_create_repositories = Some(members_can_create_repositories);
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<crate::models::Organization> {
+        let route = format!("/orgs/{org}", org = self.handler.owner);
+
+        self.handler.crab.patch(route, Some(&self)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.orgs("rust-lang");
+        let update = handler
+            .update()
+            .description("Empowering everyone to build reliable software.")
+            .default_repository_permission(crate::params::orgs::DefaultRepositoryPermission::Read)
+            .members_can_create_repositories(false);
+
+        assert_eq!(
+            serde_json::to_value(update).unwrap(),
+            serde_json::json!({
+                "description": "Empowering everyone to build reliable software.",
+                "default_repository_permission": "read",
+                "members_can_create_repositories": false,
+            })
+        )
+    }
+}
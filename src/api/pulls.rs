@@ -1,11 +1,22 @@
 //! The pull request API.
 
 mod create;
+mod create_review;
 mod list;
+mod list_commits;
+mod list_files;
+mod merge;
+mod update;
+
+use snafu::ResultExt;
 
 use crate::{Octocrab, Page};
 
-pub use self::{create::CreatePullRequestBuilder, list::ListPullRequestsBuilder};
+pub use self::{
+    create::CreatePullRequestBuilder, create_review::CreateReviewBuilder,
+    list::ListPullRequestsBuilder, list_commits::ListCommitsBuilder, list_files::ListFilesBuilder,
+    merge::MergePullRequestBuilder, update::UpdatePullRequestBuilder,
+};
 
 /// A client to GitHub's pull request API.
 ///
@@ -20,7 +31,11 @@ pub struct PullRequestHandler<'octo> {
 
 impl<'octo> PullRequestHandler<'octo> {
     pub(crate) fn new(crab: &'octo Octocrab, owner: String, repo: String) -> Self {
-        Self { crab, owner, repo }
+        Self {
+            crab,
+            owner: crate::encoding::segment(&owner),
+            repo: crate::encoding::segment(&repo),
+        }
     }
 
     /// Checks if a given pull request has been merged.
@@ -31,12 +46,15 @@ impl<'octo> PullRequestHandler<'octo> {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn is_merged(&self, pr: u64) -> crate::Result<bool> {
+    pub async fn is_merged(
+        &self,
+        pr: impl Into<crate::models::PullRequestNumber>,
+    ) -> crate::Result<bool> {
         let route = format!(
             "/repos/{owner}/{repo}/pulls/{pr}/merge",
             owner = self.owner,
             repo = self.repo,
-            pr = pr
+            pr = pr.into()
         );
         let response = self
             .crab
@@ -46,6 +64,56 @@ impl<'octo> PullRequestHandler<'octo> {
         Ok(response.status() == 204)
     }
 
+    /// Merges the pull request `pr` into its base branch.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params;
+    ///
+    /// let result = octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .merge(101)
+    ///     // Optional Parameters
+    ///     .method(params::pulls::MergeMethod::Squash)
+    ///     .commit_title("Add a feature (#101)")
+    ///     .sha("6dcb09b5b57875f334f61aebed695e2e4193db5e")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn merge(
+        &self,
+        pr: impl Into<crate::models::PullRequestNumber>,
+    ) -> merge::MergePullRequestBuilder<'octo, '_> {
+        merge::MergePullRequestBuilder::new(self, pr.into())
+    }
+
+    /// Creates a review on the pull request `pr`, approving it, requesting
+    /// changes, or just commenting. Without an event the review is left
+    /// pending.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::pulls::{ReviewComment, ReviewEvent};
+    ///
+    /// let review = octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .create_review(101)
+    ///     // Optional Parameters
+    ///     .event(ReviewEvent::RequestChanges)
+    ///     .body("A couple of nits.")
+    ///     .comments(vec![ReviewComment::at_line("src/lib.rs", 12, "Typo")])
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_review(
+        &self,
+        pr: impl Into<crate::models::PullRequestNumber>,
+    ) -> create_review::CreateReviewBuilder<'octo, '_> {
+        create_review::CreateReviewBuilder::new(self, pr.into())
+    }
+
     /// Get's a given pull request with by its `pr` number.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
@@ -54,16 +122,240 @@ impl<'octo> PullRequestHandler<'octo> {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get(&self, pr: u64) -> crate::Result<crate::models::PullRequest> {
+    pub async fn get(
+        &self,
+        pr: impl Into<crate::models::PullRequestNumber>,
+    ) -> crate::Result<crate::models::PullRequest> {
         let url = format!(
             "/repos/{owner}/{repo}/pulls/{pr}",
             owner = self.owner,
             repo = self.repo,
-            pr = pr
+            pr = pr.into()
         );
         self.crab.get(url, None::<&()>).await
     }
 
+    /// Gets whether the pull request `pr` can be merged without conflicts.
+    ///
+    /// *Note* GitHub computes mergeability in the background, so this is
+    /// `None` until GitHub has finished, e.g. right after the pull request
+    /// was created or its base branch changed. Requesting the pull request
+    /// starts the computation, use [`poll_mergeability`] to wait for it.
+    /// [`PullRequest::mergeable_state`] has more detail once it's known.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let mergeable = octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .mergeability(101)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`poll_mergeability`]: #method.poll_mergeability
+    /// [`PullRequest::mergeable_state`]: ../models/struct.PullRequest.html#structfield.mergeable_state
+    pub async fn mergeability(
+        &self,
+        pr: impl Into<crate::models::PullRequestNumber>,
+    ) -> crate::Result<Option<bool>> {
+        Ok(self.get(pr).await?.mergeable)
+    }
+
+    /// Like [`mergeability`], but if GitHub hasn't finished computing it yet,
+    /// requests the pull request again, with an increasing delay in between,
+    /// until it has or `timeout` has elapsed. Returns `None` if it's still
+    /// unknown after `timeout`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use std::time::Duration;
+    ///
+    /// let mergeable = octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .poll_mergeability(101, Duration::from_secs(30))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`mergeability`]: #method.mergeability
+    pub async fn poll_mergeability(
+        &self,
+        pr: impl Into<crate::models::PullRequestNumber>,
+        timeout: std::time::Duration,
+    ) -> crate::Result<Option<bool>> {
+        let pr = pr.into();
+        let start = std::time::Instant::now();
+        let mut delay = std::time::Duration::from_millis(500);
+
+        loop {
+            let mergeable = self.mergeability(pr).await?;
+            let remaining = timeout.checked_sub(start.elapsed()).unwrap_or_default();
+
+            if mergeable.is_some() || remaining == std::time::Duration::default() {
+                return Ok(mergeable);
+            }
+
+            tokio::time::delay_for(delay.min(remaining)).await;
+            delay = (delay * 2).min(std::time::Duration::from_secs(5));
+        }
+    }
+
+    /// Gets the changes of the pull request `pr` as a unified diff.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let diff = octocrab::instance().pulls("owner", "repo").diff(101).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn diff(
+        &self,
+        pr: impl Into<crate::models::PullRequestNumber>,
+    ) -> crate::Result<String> {
+        self.get_text(pr.into(), "application/vnd.github.v3.diff")
+            .await
+    }
+
+    /// Gets the commits of the pull request `pr` as a series of patches in
+    /// the format of `git format-patch`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let patch = octocrab::instance().pulls("owner", "repo").patch(101).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn patch(
+        &self,
+        pr: impl Into<crate::models::PullRequestNumber>,
+    ) -> crate::Result<String> {
+        self.get_text(pr.into(), "application/vnd.github.v3.patch")
+            .await
+    }
+
+    /// Gets the pull request in a plain text `media_type`, returning an error
+    /// if GitHub responded with JSON instead.
+    async fn get_text(
+        &self,
+        pr: crate::models::PullRequestNumber,
+        media_type: &'static str,
+    ) -> crate::Result<String> {
+        let route = format!(
+            "/repos/{owner}/{repo}/pulls/{pr}",
+            owner = self.owner,
+            repo = self.repo,
+            pr = pr
+        );
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            reqwest::header::ACCEPT,
+            reqwest::header::HeaderValue::from_static(media_type),
+        );
+
+        let response = self
+            .crab
+            ._get_with(self.crab.absolute_url(route)?, None::<&()>, headers)
+            .await?;
+        let response = Octocrab::map_github_error(response).await?;
+        let is_json = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|content_type| content_type.to_str().ok())
+            .map(|content_type| content_type.contains("json"))
+            .unwrap_or(false);
+
+        if is_json {
+            return Err(
+                format!("expected `{}`, but GitHub responded with JSON", media_type).into(),
+            )
+            .context(crate::error::Other);
+        }
+
+        response.text().await.context(crate::error::Http)
+    }
+
+    /// Requests reviews on the pull request from the given users and teams
+    /// (by their slug), returning the updated pull request. Use
+    /// [`remove_requested_reviewers`] to cancel the requests.
+    ///
+    /// *Note* GitHub responds with `422 Unprocessable Entity` if one of the
+    /// `reviewers` is the pull request's author or isn't a collaborator, see
+    /// [`Error::is_validation_failed`].
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let result = octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .request_reviews(101, &[String::from("ferris")], &[String::from("compiler")])
+    ///     .await;
+    ///
+    /// match result {
+    ///     Ok(pull_request) => println!("{:?}", pull_request.requested_reviewers),
+    ///     // e.g. ferris opened the pull request.
+    ///     Err(error) if error.is_validation_failed() => {}
+    ///     Err(error) => return Err(error),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`remove_requested_reviewers`]: #method.remove_requested_reviewers
+    /// [`Error::is_validation_failed`]: ../enum.Error.html#method.is_validation_failed
+    pub async fn request_reviews(
+        &self,
+        pr: impl Into<crate::models::PullRequestNumber>,
+        reviewers: &[String],
+        team_reviewers: &[String],
+    ) -> crate::Result<crate::models::PullRequest> {
+        let route = format!(
+            "/repos/{owner}/{repo}/pulls/{pr}/requested_reviewers",
+            owner = self.owner,
+            repo = self.repo,
+            pr = pr.into()
+        );
+        let body = reviewers_body(reviewers, team_reviewers);
+
+        self.crab.post(route, Some(&body)).await
+    }
+
+    /// Removes the given users and teams (by their slug) from the reviewers
+    /// requested on the pull request, returning the reviewers that are still
+    /// requested afterwards.
+    ///
+    /// *Note* GitHub silently ignores reviewers that weren't requested, so
+    /// check the returned reviewers to confirm who was actually removed.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let remaining = octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .remove_requested_reviewers(101, &[String::from("ferris")], &[])
+    ///     .await?;
+    ///
+    /// assert!(remaining.iter().all(|user| user.login != "ferris"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_requested_reviewers(
+        &self,
+        pr: impl Into<crate::models::PullRequestNumber>,
+        reviewers: &[String],
+        team_reviewers: &[String],
+    ) -> crate::Result<Vec<crate::models::User>> {
+        let route = format!(
+            "/repos/{owner}/{repo}/pulls/{pr}/requested_reviewers",
+            owner = self.owner,
+            repo = self.repo,
+            pr = pr.into()
+        );
+        let request = self
+            .crab
+            .client
+            .delete(self.crab.absolute_url(route)?)
+            .json(&reviewers_body(reviewers, team_reviewers));
+        let response = Octocrab::map_github_error(self.crab.execute(request).await?).await?;
+        let pull_request: crate::models::PullRequest =
+            crate::FromResponse::from_response(response).await?;
+
+        Ok(pull_request.requested_reviewers)
+    }
+
     /// Create a new pull request.
     ///
     /// - `title` — The title of the new pull request.
@@ -95,6 +387,63 @@ impl<'octo> PullRequestHandler<'octo> {
         create::CreatePullRequestBuilder::new(self, title, head, base)
     }
 
+    /// Updates the pull request `pr`, only the fields you set on the builder
+    /// are changed.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let pr = octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .update(101)
+    ///     // Optional Parameters
+    ///     .title("Updated title")
+    ///     .body("New body")
+    ///     .base("main")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(
+        &self,
+        pr: impl Into<crate::models::PullRequestNumber>,
+    ) -> update::UpdatePullRequestBuilder<'octo, '_> {
+        update::UpdatePullRequestBuilder::new(self, pr.into())
+    }
+
+    /// Closes the pull request `pr` without merging it.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let pr = octocrab::instance().pulls("owner", "repo").close(101).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn close(
+        &self,
+        pr: impl Into<crate::models::PullRequestNumber>,
+    ) -> crate::Result<crate::models::PullRequest> {
+        self.update(pr)
+            .state(crate::params::State::Closed)
+            .send()
+            .await
+    }
+
+    /// Reopens the closed pull request `pr`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let pr = octocrab::instance().pulls("owner", "repo").reopen(101).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn reopen(
+        &self,
+        pr: impl Into<crate::models::PullRequestNumber>,
+    ) -> crate::Result<crate::models::PullRequest> {
+        self.update(pr)
+            .state(crate::params::State::Open)
+            .send()
+            .await
+    }
+
     /// Creates a new `ListPullRequestsBuilder` that can be configured to filter
     /// listing pulling requests.
     /// ```no_run
@@ -117,7 +466,333 @@ impl<'octo> PullRequestHandler<'octo> {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn list(&self) -> list::ListPullRequestsBuilder {
+    pub fn list(&self) -> list::ListPullRequestsBuilder<'octo, '_> {
         list::ListPullRequestsBuilder::new(self)
     }
+
+    /// Lists the files changed in the pull request `pr`, up to a maximum of
+    /// 3000 files.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .list_files(101)
+    ///     // Optional Parameters
+    ///     .per_page(100)
+    ///     .page(2u32)
+    ///     .send()
+    ///     .await?;
+    ///
+    /// for file in page {
+    ///     println!("{}: +{} -{}", file.filename, file.additions, file.deletions);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_files(
+        &self,
+        pr: impl Into<crate::models::PullRequestNumber>,
+    ) -> list_files::ListFilesBuilder<'octo, '_> {
+        list_files::ListFilesBuilder::new(self, pr.into())
+    }
+
+    /// Lists the commits of the pull request `pr`, oldest first, up to a
+    /// maximum of 250 commits.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .list_commits(101)
+    ///     // Optional Parameters
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_commits(
+        &self,
+        pr: impl Into<crate::models::PullRequestNumber>,
+    ) -> list_commits::ListCommitsBuilder<'octo, '_> {
+        list_commits::ListCommitsBuilder::new(self, pr.into())
+    }
+}
+
+/// The body for requesting or removing reviewers, omitting empty lists.
+fn reviewers_body(
+    reviewers: &[String],
+    team_reviewers: &[String],
+) -> serde_json::Map<String, serde_json::Value> {
+    let mut body = serde_json::Map::new();
+
+    if !reviewers.is_empty() {
+        body.insert("reviewers".into(), reviewers.into());
+    }
+
+    if !team_reviewers.is_empty() {
+        body.insert("team_reviewers".into(), team_reviewers.into());
+    }
+
+    body
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        matchers::{body_json, header, method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    const DIFF: &str = "diff --git a/README b/README\n\
+                        index c57eff5..980a0d5 100644\n\
+                        --- a/README\n\
+                        +++ b/README\n\
+                        @@ -1 +1 @@\n\
+                        -Hello World!\n\
+                        +Hello World\n";
+
+    #[tokio::test]
+    async fn diff() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/pulls/1347"))
+            .and(header("accept", "application/vnd.github.v3.diff"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/plain; charset=utf-8")
+                    .set_body_string(DIFF),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/pulls/1348"))
+            .respond_with(ResponseTemplate::new(406).set_body_json(serde_json::json!({
+                "message": "Sorry, the diff exceeded the maximum number of lines (20000)"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/pulls/1349"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let pulls = octocrab.pulls("octocat", "Hello-World");
+
+        assert_eq!(pulls.diff(1347).await.unwrap(), DIFF);
+
+        let error = pulls.diff(1348).await.unwrap_err();
+        assert_eq!(
+            error.status_code(),
+            Some(reqwest::StatusCode::NOT_ACCEPTABLE)
+        );
+        assert!(error.to_string().contains("maximum number of lines"));
+
+        let error = pulls.patch(1349).await.unwrap_err();
+        assert!(matches!(error, crate::Error::Other { .. }));
+        assert!(error
+            .to_string()
+            .contains("application/vnd.github.v3.patch"));
+    }
+
+    #[tokio::test]
+    async fn list_files() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/pulls/1347/files"))
+            .and(query_param("per_page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {
+                    "sha": "bbcd538c8e72b8c175046e27cc8f907076331401",
+                    "filename": "file1.txt",
+                    "status": "added",
+                    "additions": 103,
+                    "deletions": 21,
+                    "changes": 124,
+                    "blob_url": "https://github.com/octocat/Hello-World/blob/6dcb09b5b57875f334f61aebed695e2e4193db5e/file1.txt",
+                    "raw_url": "https://github.com/octocat/Hello-World/raw/6dcb09b5b57875f334f61aebed695e2e4193db5e/file1.txt",
+                    "contents_url": "https://api.github.com/repos/octocat/Hello-World/contents/file1.txt?ref=6dcb09b5b57875f334f61aebed695e2e4193db5e",
+                    "patch": "@@ -132,7 +132,7 @@ module Test @@ -1000,7 +1000,7 @@ module Test"
+                },
+                {
+                    "sha": "e69de29bb2d1d6434b8b29ae775ad8c2e48c5391",
+                    "filename": "logo.png",
+                    "status": "modified",
+                    "additions": 0,
+                    "deletions": 0,
+                    "changes": 0,
+                    "blob_url": "https://github.com/octocat/Hello-World/blob/6dcb09b5b57875f334f61aebed695e2e4193db5e/logo.png",
+                    "raw_url": "https://github.com/octocat/Hello-World/raw/6dcb09b5b57875f334f61aebed695e2e4193db5e/logo.png",
+                    "contents_url": "https://api.github.com/repos/octocat/Hello-World/contents/logo.png?ref=6dcb09b5b57875f334f61aebed695e2e4193db5e"
+                }
+            ])))
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let files = octocrab
+            .pulls("octocat", "Hello-World")
+            .list_files(1347)
+            .per_page(2)
+            .send()
+            .await
+            .unwrap()
+            .items;
+
+        assert_eq!(files[0].filename, "file1.txt");
+        assert_eq!(files[0].status, "added");
+        assert_eq!(files[0].changes, 124);
+        assert!(files[0].patch.is_some());
+        assert!(files[1].patch.is_none());
+    }
+
+    #[tokio::test]
+    async fn list_commits() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/pulls/1347/commits"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {
+                    "url": "https://api.github.com/repos/octocat/Hello-World/commits/6dcb09b5b57875f334f61aebed695e2e4193db5e",
+                    "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+                    "node_id": "MDY6Q29tbWl0NmRjYjA5YjViNTc4NzVmMzM0ZjYxYWViZWQ2OTVlMmU0MTkzZGI1ZQ==",
+                    "html_url": "https://github.com/octocat/Hello-World/commit/6dcb09b5b57875f334f61aebed695e2e4193db5e",
+                    "comments_url": "https://api.github.com/repos/octocat/Hello-World/commits/6dcb09b5b57875f334f61aebed695e2e4193db5e/comments",
+                    "author": null,
+                    "committer": null
+                }
+            ])))
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let commits = octocrab
+            .pulls("octocat", "Hello-World")
+            .list_commits(1347)
+            .send()
+            .await
+            .unwrap()
+            .items;
+
+        assert_eq!(commits.len(), 1);
+        assert_eq!(
+            commits[0].sha.as_deref(),
+            Some("6dcb09b5b57875f334f61aebed695e2e4193db5e")
+        );
+        assert!(commits[0].files.is_none());
+    }
+
+    #[tokio::test]
+    async fn remove_requested_reviewers() {
+        let server = MockServer::start().await;
+        let pull_request: serde_json::Value =
+            serde_json::from_str(include_str!("../../tests/resources/pull_request.json")).unwrap();
+        Mock::given(method("DELETE"))
+            .and(path(
+                "/repos/octocat/Hello-World/pulls/1347/requested_reviewers",
+            ))
+            .and(body_json(serde_json::json!({
+                "reviewers": ["hubot", "not-requested"],
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(pull_request))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let remaining = octocrab
+            .pulls("octocat", "Hello-World")
+            .remove_requested_reviewers(
+                1347,
+                &[String::from("hubot"), String::from("not-requested")],
+                &[],
+            )
+            .await
+            .unwrap();
+
+        let logins: Vec<_> = remaining.iter().map(|user| user.login.as_str()).collect();
+        assert_eq!(logins, ["other_user"]);
+    }
+
+    #[tokio::test]
+    async fn request_reviews() {
+        let server = MockServer::start().await;
+        let pull_request: serde_json::Value =
+            serde_json::from_str(include_str!("../../tests/resources/pull_request.json")).unwrap();
+        Mock::given(method("POST"))
+            .and(path(
+                "/repos/octocat/Hello-World/pulls/1347/requested_reviewers",
+            ))
+            .and(body_json(
+                serde_json::json!({ "reviewers": ["other_user"] }),
+            ))
+            .respond_with(ResponseTemplate::new(201).set_body_json(pull_request))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path(
+                "/repos/octocat/Hello-World/pulls/1347/requested_reviewers",
+            ))
+            .and(body_json(serde_json::json!({ "team_reviewers": ["justice-league"] })))
+            .respond_with(ResponseTemplate::new(422).set_body_json(serde_json::json!({
+                "message": "Reviews may only be requested from collaborators. One or more of the teams you specified is not a collaborator of the octocat/Hello-World repository.",
+                "documentation_url": "https://docs.github.com/rest/pulls/review-requests#request-reviewers-for-a-pull-request"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let pulls = octocrab.pulls("octocat", "Hello-World");
+
+        let pull_request = pulls
+            .request_reviews(1347, &[String::from("other_user")], &[])
+            .await
+            .unwrap();
+        assert_eq!(pull_request.requested_reviewers[0].login, "other_user");
+
+        let error = pulls
+            .request_reviews(1347, &[], &[String::from("justice-league")])
+            .await
+            .unwrap_err();
+        assert!(error.is_validation_failed());
+    }
+
+    #[tokio::test]
+    async fn poll_mergeability() {
+        let mut pull_request: serde_json::Value =
+            serde_json::from_str(include_str!("../../tests/resources/pull_request.json")).unwrap();
+        let server = MockServer::start().await;
+        pull_request["mergeable"] = serde_json::Value::Null;
+        pull_request["mergeable_state"] = "unknown".into();
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/pulls/1347"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&pull_request))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&server)
+            .await;
+        pull_request["mergeable"] = true.into();
+        pull_request["mergeable_state"] = "clean".into();
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/pulls/1347"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&pull_request))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let pulls = octocrab.pulls("octocat", "Hello-World");
+
+        assert_eq!(pulls.mergeability(1347).await.unwrap(), None);
+        assert_eq!(
+            pulls
+                .poll_mergeability(1347, std::time::Duration::from_secs(10))
+                .await
+                .unwrap(),
+            Some(true)
+        );
+    }
 }
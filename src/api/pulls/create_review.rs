@@ -0,0 +1,179 @@
+use super::*;
+
+/// A builder pattern struct for submitting a review on a pull request.
+///
+/// created by [`PullRequestHandler::create_review`]
+///
+/// [`PullRequestHandler::create_review`]: ./struct.PullRequestHandler.html#method.create_review
+#[derive(serde::Serialize)]
+pub struct CreateReviewBuilder<'octo, 'b> {
+    #[serde(skip)]
+    handler: &'b PullRequestHandler<'octo>,
+    #[serde(skip)]
+    pr: crate::models::PullRequestNumber,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commit_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    #[serde(skip_serializing_if = "is_pending")]
+    event: Option<crate::params::pulls::ReviewEvent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comments: Option<Vec<crate::params::pulls::ReviewComment>>,
+}
+
+/// GitHub leaves a review pending when no event is given, and rejects an
+/// explicit `PENDING`.
+fn is_pending(event: &Option<crate::params::pulls::ReviewEvent>) -> bool {
+    matches!(
+        event,
+        None | Some(crate::params::pulls::ReviewEvent::Pending)
+    )
+}
+
+impl<'octo, 'b> CreateReviewBuilder<'octo, 'b> {
+    pub(crate) fn new(
+        handler: &'b PullRequestHandler<'octo>,
+        pr: crate::models::PullRequestNumber,
+    ) -> Self {
+        Self {
+            handler,
+            pr,
+            commit_id: None,
+            body: None,
+            event: None,
+            comments: None,
+        }
+    }
+
+    /// The SHA of the commit to review. Default: the most recent commit on
+    /// the pull request.
+    pub fn commit_id(mut self, commit_id: impl Into<String>) -> Self {
+        self.commit_id = Some(commit_id.into());
+        self
+    }
+
+    /// The body text of the review. Required when the event is
+    /// `RequestChanges` or `Comment`.
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// The review action to perform. Default: `Pending`.
+    pub fn event(mut self, event: impl Into<crate::params::pulls::ReviewEvent>) -> Self {
+        self.event = Some(event.into());
+        self
+    }
+
+    /// Inline comments to submit along with the review.
+    pub fn comments(
+        mut self,
+        comments: impl Into<Vec<crate::params::pulls::ReviewComment>>,
+    ) -> Self {
+        self.comments = Some(comments.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<crate::models::Review> {
+        let route = format!(
+            "/repos/{owner}/{repo}/pulls/{pr}/reviews",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+            pr = self.pr,
+        );
+
+        self.handler.crab.post(route, Some(&self)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::params::pulls::{ReviewComment, ReviewEvent};
+    use wiremock::{
+        matchers::{body_json, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[test]
+    fn serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.pulls("rust-lang", "rust");
+        let review = handler
+            .create_review(1347)
+            .body("Looks good")
+            .event(ReviewEvent::RequestChanges)
+            .comments(vec![
+                ReviewComment::at_position("file.md", 6, "Fix this"),
+                ReviewComment::at_line("file.md", 12, "And this"),
+            ]);
+
+        assert_eq!(
+            serde_json::to_value(review).unwrap(),
+            serde_json::json!({
+                "body": "Looks good",
+                "event": "REQUEST_CHANGES",
+                "comments": [
+                    { "path": "file.md", "position": 6, "body": "Fix this" },
+                    { "path": "file.md", "line": 12, "body": "And this" },
+                ],
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(handler.create_review(1347).event(ReviewEvent::Pending)).unwrap(),
+            serde_json::json!({})
+        );
+    }
+
+    #[tokio::test]
+    async fn create_review() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/repos/octocat/Hello-World/pulls/12/reviews"))
+            .and(body_json(serde_json::json!({ "event": "APPROVE" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": 80,
+                "node_id": "MDE3OlB1bGxSZXF1ZXN0UmV2aWV3ODA=",
+                "user": {
+                    "login": "octocat",
+                    "id": 1,
+                    "node_id": "MDQ6VXNlcjE=",
+                    "avatar_url": "https://github.com/images/error/octocat_happy.gif",
+                    "gravatar_id": "",
+                    "url": "https://api.github.com/users/octocat",
+                    "html_url": "https://github.com/octocat",
+                    "followers_url": "https://api.github.com/users/octocat/followers",
+                    "following_url": "https://api.github.com/users/octocat/following{/other_user}",
+                    "gists_url": "https://api.github.com/users/octocat/gists{/gist_id}",
+                    "starred_url": "https://api.github.com/users/octocat/starred{/owner}{/repo}",
+                    "subscriptions_url": "https://api.github.com/users/octocat/subscriptions",
+                    "organizations_url": "https://api.github.com/users/octocat/orgs",
+                    "repos_url": "https://api.github.com/users/octocat/repos",
+                    "events_url": "https://api.github.com/users/octocat/events{/privacy}",
+                    "received_events_url": "https://api.github.com/users/octocat/received_events",
+                    "type": "User",
+                    "site_admin": false
+                },
+                "body": "",
+                "state": "APPROVED",
+                "html_url": "https://github.com/octocat/Hello-World/pull/12#pullrequestreview-80",
+                "pull_request_url": "https://api.github.com/repos/octocat/Hello-World/pulls/12",
+                "submitted_at": "2019-11-17T17:43:43Z",
+                "commit_id": "ecdd80bb57125d7ba9641ffaa4d7d2c19d3f3091"
+            })))
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let review = octocrab
+            .pulls("octocat", "Hello-World")
+            .create_review(12)
+            .event(ReviewEvent::Approve)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(review.id, Some(80));
+        assert_eq!(review.state, Some(crate::models::ReviewState::Approved));
+    }
+}
@@ -16,6 +16,8 @@ pub struct ListPullRequestsBuilder<'octo, 'b> {
     direction: Option<crate::params::Direction>,
     per_page: Option<u8>,
     page: Option<u32>,
+    #[serde(skip)]
+    etag: Option<String>,
 }
 
 impl<'octo, 'b> ListPullRequestsBuilder<'octo, 'b> {
@@ -29,6 +31,7 @@ impl<'octo, 'b> ListPullRequestsBuilder<'octo, 'b> {
             direction: None,
             per_page: None,
             page: None,
+            etag: None,
         }
     }
 
@@ -69,8 +72,8 @@ impl<'octo, 'b> ListPullRequestsBuilder<'octo, 'b> {
     }
 
     /// Results per page (max 100).
-    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
-        self.per_page = Some(per_page.into());
+    pub fn per_page(mut self, per_page: impl std::convert::TryInto<i64>) -> Self {
+        self.per_page = Some(crate::page::clamp_per_page(per_page));
         self
     }
 
@@ -80,6 +83,18 @@ impl<'octo, 'b> ListPullRequestsBuilder<'octo, 'b> {
         self
     }
 
+    /// Only fetch the results if they have changed since the response with
+    /// this `ETag`, see [`Page::etag`]. If they haven't, the page is empty
+    /// and [`Page::not_modified`] is set, and the request doesn't count
+    /// against the rate limit.
+    ///
+    /// [`Page::etag`]: ../struct.Page.html#structfield.etag
+    /// [`Page::not_modified`]: ../struct.Page.html#structfield.not_modified
+    pub fn etag(mut self, etag: impl Into<String>) -> Self {
+        self.etag = Some(etag.into());
+        self
+    }
+
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<Page<crate::models::PullRequest>> {
         let url = format!(
@@ -87,7 +102,10 @@ impl<'octo, 'b> ListPullRequestsBuilder<'octo, 'b> {
             owner = self.handler.owner,
             repo = self.handler.repo
         );
-        self.handler.crab.get(url, Some(&self)).await
+        self.handler
+            .crab
+            .get_page_if_none_match(url, Some(&self), self.etag.as_deref())
+            .await
     }
 }
 
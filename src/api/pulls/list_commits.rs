@@ -0,0 +1,55 @@
+use super::*;
+
+/// A builder pattern struct for listing the commits of a pull request.
+///
+/// created by [`PullRequestHandler::list_commits`]
+///
+/// [`PullRequestHandler::list_commits`]: ./struct.PullRequestHandler.html#method.list_commits
+#[derive(serde::Serialize)]
+pub struct ListCommitsBuilder<'octo, 'b> {
+    #[serde(skip)]
+    handler: &'b PullRequestHandler<'octo>,
+    #[serde(skip)]
+    pr: crate::models::PullRequestNumber,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'b> ListCommitsBuilder<'octo, 'b> {
+    pub(crate) fn new(
+        handler: &'b PullRequestHandler<'octo>,
+        pr: crate::models::PullRequestNumber,
+    ) -> Self {
+        Self {
+            handler,
+            pr,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl std::convert::TryInto<i64>) -> Self {
+        self.per_page = Some(crate::page::clamp_per_page(per_page));
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Page<crate::models::Commit>> {
+        let url = format!(
+            "/repos/{owner}/{repo}/pulls/{pr}/commits",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+            pr = self.pr,
+        );
+        self.handler.crab.get(url, Some(&self)).await
+    }
+}
@@ -0,0 +1,169 @@
+use super::*;
+
+/// A builder pattern struct for merging a pull request.
+///
+/// created by [`PullRequestHandler::merge`]
+///
+/// [`PullRequestHandler::merge`]: ./struct.PullRequestHandler.html#method.merge
+#[derive(serde::Serialize)]
+pub struct MergePullRequestBuilder<'octo, 'b> {
+    #[serde(skip)]
+    handler: &'b PullRequestHandler<'octo>,
+    #[serde(skip)]
+    pr: crate::models::PullRequestNumber,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commit_title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commit_message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sha: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    merge_method: Option<crate::params::pulls::MergeMethod>,
+}
+
+impl<'octo, 'b> MergePullRequestBuilder<'octo, 'b> {
+    pub(crate) fn new(
+        handler: &'b PullRequestHandler<'octo>,
+        pr: crate::models::PullRequestNumber,
+    ) -> Self {
+        Self {
+            handler,
+            pr,
+            commit_title: None,
+            commit_message: None,
+            sha: None,
+            merge_method: None,
+        }
+    }
+
+    /// The title of the merge commit. Default: GitHub's default title.
+    pub fn commit_title(mut self, commit_title: impl Into<String>) -> Self {
+        self.commit_title = Some(commit_title.into());
+        self
+    }
+
+    /// Extra detail to append to the merge commit's message.
+    pub fn commit_message(mut self, commit_message: impl Into<String>) -> Self {
+        self.commit_message = Some(commit_message.into());
+        self
+    }
+
+    /// The SHA that the pull request's head must match to allow the merge,
+    /// to avoid merging commits pushed after they were reviewed.
+    pub fn sha(mut self, sha: impl Into<String>) -> Self {
+        self.sha = Some(sha.into());
+        self
+    }
+
+    /// How to merge the pull request. Default: `Merge`.
+    pub fn method(mut self, method: impl Into<crate::params::pulls::MergeMethod>) -> Self {
+        self.merge_method = Some(method.into());
+        self
+    }
+
+    /// Sends the actual request.
+    ///
+    /// If the pull request can't be merged (e.g. because of conflicts or
+    /// failing required checks), GitHub responds with `405 Method Not
+    /// Allowed`, and if the head doesn't match [`sha`](#method.sha) with
+    /// `409 Conflict`. Both are returned as `Error::GitHub`, and can be told
+    /// apart with [`Error::status_code`].
+    ///
+    /// [`Error::status_code`]: ../enum.Error.html#method.status_code
+    pub async fn send(self) -> crate::Result<crate::models::MergeResult> {
+        let route = format!(
+            "/repos/{owner}/{repo}/pulls/{pr}/merge",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+            pr = self.pr,
+        );
+        let request = self
+            .handler
+            .crab
+            .client
+            .put(self.handler.crab.absolute_url(route)?)
+            .json(&self);
+        let response = self.handler.crab.execute(request).await?;
+
+        crate::FromResponse::from_response(Octocrab::map_github_error(response).await?).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        matchers::{body_json, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[test]
+    fn serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.pulls("rust-lang", "rust");
+        let merge = handler
+            .merge(1347)
+            .commit_title("Merge #1347")
+            .commit_message("Fixes #1")
+            .sha("6dcb09b5b57875f334f61aebed695e2e4193db5e")
+            .method(crate::params::pulls::MergeMethod::Squash);
+
+        assert_eq!(
+            serde_json::to_value(merge).unwrap(),
+            serde_json::json!({
+                "commit_title": "Merge #1347",
+                "commit_message": "Fixes #1",
+                "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+                "merge_method": "squash",
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(handler.merge(1347)).unwrap(),
+            serde_json::json!({})
+        );
+    }
+
+    #[tokio::test]
+    async fn merge() {
+        let server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path("/repos/octocat/Hello-World/pulls/1347/merge"))
+            .and(body_json(serde_json::json!({ "merge_method": "rebase" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+                "merged": true,
+                "message": "Pull Request successfully merged"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/repos/octocat/Hello-World/pulls/1348/merge"))
+            .respond_with(ResponseTemplate::new(409).set_body_json(serde_json::json!({
+                "message": "Head branch was modified. Review and try the merge again."
+            })))
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let pulls = octocrab.pulls("octocat", "Hello-World");
+
+        let result = pulls
+            .merge(1347)
+            .method(crate::params::pulls::MergeMethod::Rebase)
+            .send()
+            .await
+            .unwrap();
+        assert!(result.merged);
+        assert_eq!(
+            result.sha.as_deref(),
+            Some("6dcb09b5b57875f334f61aebed695e2e4193db5e")
+        );
+
+        let error = pulls
+            .merge(1348)
+            .sha("0000000000000000000000000000000000000000")
+            .send()
+            .await
+            .unwrap_err();
+        assert_eq!(error.status_code(), Some(reqwest::StatusCode::CONFLICT));
+    }
+}
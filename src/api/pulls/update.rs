@@ -0,0 +1,135 @@
+use super::*;
+
+/// A builder pattern struct for updating a pull request. Only the fields that
+/// have been set are sent, so unset fields are left unchanged.
+///
+/// created by [`PullRequestHandler::update`]
+///
+/// [`PullRequestHandler::update`]: ./struct.PullRequestHandler.html#method.update
+#[derive(serde::Serialize)]
+pub struct UpdatePullRequestBuilder<'octo, 'b> {
+    #[serde(skip)]
+    handler: &'b PullRequestHandler<'octo>,
+    #[serde(skip)]
+    pr: crate::models::PullRequestNumber,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<crate::params::State>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    base: Option<String>,
+}
+
+impl<'octo, 'b> UpdatePullRequestBuilder<'octo, 'b> {
+    pub(crate) fn new(
+        handler: &'b PullRequestHandler<'octo>,
+        pr: crate::models::PullRequestNumber,
+    ) -> Self {
+        Self {
+            handler,
+            pr,
+            title: None,
+            body: None,
+            state: None,
+            base: None,
+        }
+    }
+
+    /// The title of the pull request.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// The contents of the pull request.
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Whether the pull request is open or closed, GitHub rejects
+    /// `State::All`.
+    pub fn state(mut self, state: impl Into<crate::params::State>) -> Self {
+        self.state = Some(state.into());
+        self
+    }
+
+    /// The name of the branch the changes should be pulled into. It must be
+    /// a branch of the pull request's repository.
+    pub fn base(mut self, base: impl Into<String>) -> Self {
+        self.base = Some(base.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<crate::models::PullRequest> {
+        let route = format!(
+            "/repos/{owner}/{repo}/pulls/{pr}",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+            pr = self.pr,
+        );
+
+        self.handler.crab.patch(route, Some(&self)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        matchers::{body_json, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[test]
+    fn serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.pulls("rust-lang", "rust");
+        let update = handler
+            .update(1347)
+            .title("Amazing new feature")
+            .body("Please pull these awesome changes in!")
+            .state(crate::params::State::Open)
+            .base("master");
+
+        assert_eq!(
+            serde_json::to_value(update).unwrap(),
+            serde_json::json!({
+                "title": "Amazing new feature",
+                "body": "Please pull these awesome changes in!",
+                "state": "open",
+                "base": "master",
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(handler.update(1347).state(crate::params::State::Closed)).unwrap(),
+            serde_json::json!({ "state": "closed" })
+        );
+    }
+
+    #[tokio::test]
+    async fn close_and_reopen() {
+        let server = MockServer::start().await;
+        let pull_request: serde_json::Value =
+            serde_json::from_str(include_str!("../../../tests/resources/pull_request.json"))
+                .unwrap();
+        for state in &["closed", "open"] {
+            Mock::given(method("PATCH"))
+                .and(path("/repos/octocat/Hello-World/pulls/1347"))
+                .and(body_json(serde_json::json!({ "state": state })))
+                .respond_with(ResponseTemplate::new(200).set_body_json(pull_request.clone()))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let octocrab = crate::setup(&server);
+        let pulls = octocrab.pulls("octocat", "Hello-World");
+
+        let pr = pulls.close(1347).await.unwrap();
+        assert_eq!(pr.number, 1347);
+        pulls.reopen(1347).await.unwrap();
+    }
+}
@@ -0,0 +1,2483 @@
+//! The repository API.
+
+mod autolinks;
+mod branches;
+mod checks;
+mod collaborators;
+mod commit_comments;
+mod commits;
+mod compare;
+mod contents;
+mod deployments;
+mod generate;
+mod hooks;
+mod issue_events;
+mod readme;
+mod releases;
+mod secret_scanning;
+mod source_import;
+mod stats;
+mod statuses;
+mod tags;
+mod topics;
+mod update;
+
+use crate::{models, Octocrab, Page, Result};
+
+pub use self::{
+    autolinks::{AutolinksHandler, CreateAutolinkBuilder},
+    branches::ListBranchesBuilder,
+    checks::ListCheckRunsBuilder,
+    collaborators::ListCollaboratorsBuilder,
+    commit_comments::ListCommitCommentsBuilder,
+    commits::GetCommitBuilder,
+    compare::CompareCommitsBuilder,
+    contents::GetContentBuilder,
+    deployments::{DeploymentsHandler, ListDeploymentsBuilder},
+    generate::GenerateRepoBuilder,
+    hooks::{CreateHookBuilder, HooksHandler, UpdateHookBuilder},
+    issue_events::ListIssueEventsBuilder,
+    readme::GetReadmeBuilder,
+    releases::ListReleasesBuilder,
+    secret_scanning::{
+        ListSecretScanningAlertsBuilder, SecretScanningAlertsHandler,
+        UpdateSecretScanningAlertBuilder,
+    },
+    source_import::{SourceImportHandler, StartImportBuilder},
+    stats::StatsHandler,
+    statuses::CreateStatusBuilder,
+    tags::ListTagsBuilder,
+    topics::ListTopicsBuilder,
+    update::UpdateRepoBuilder,
+};
+
+/// Handler for GitHub's repository API.
+///
+/// Created with [`Octocrab::repos`].
+///
+/// [`Octocrab::repos`]: ../struct.Octocrab.html#method.repos
+pub struct RepoHandler<'octo> {
+    crab: &'octo Octocrab,
+    owner: String,
+    repo: String,
+}
+
+impl<'octo> RepoHandler<'octo> {
+    pub(crate) fn new(crab: &'octo Octocrab, owner: String, repo: String) -> Self {
+        Self {
+            crab,
+            owner: crate::encoding::segment(&owner),
+            repo: crate::encoding::segment(&repo),
+        }
+    }
+
+    /// Creates an `AutolinksHandler` for managing the repository's autolink
+    /// references, which turn references to external resources (e.g.
+    /// `JIRA-123`) into links.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let autolinks = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .autolinks()
+    ///     .list()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn autolinks(&self) -> AutolinksHandler<'octo, '_> {
+        AutolinksHandler::new(self)
+    }
+
+    /// Creates a `DeploymentsHandler` for the repository's deployments.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let deployment = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .deployments()
+    ///     .get(1)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn deployments(&self) -> DeploymentsHandler<'octo, '_> {
+        DeploymentsHandler::new(self)
+    }
+
+    /// Creates a `HooksHandler` for managing the repository's webhooks.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let hooks = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .hooks()
+    ///     .list()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn hooks(&self) -> HooksHandler<'octo, '_> {
+        HooksHandler::new(self)
+    }
+
+    /// Creates a `SecretScanningAlertsHandler` for the secrets that have
+    /// been found in the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .secret_scanning_alerts()
+    ///     .list()
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn secret_scanning_alerts(&self) -> SecretScanningAlertsHandler<'octo, '_> {
+        SecretScanningAlertsHandler::new(self)
+    }
+
+    /// Creates a `SourceImportHandler` for importing the repository from
+    /// another version control system.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let import = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .source_import()
+    ///     .status()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn source_import(&self) -> SourceImportHandler<'octo, '_> {
+        SourceImportHandler::new(self)
+    }
+
+    /// Creates a `StatsHandler` for the repository's statistics.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let participation = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .stats()
+    ///     .participation()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stats(&self) -> StatsHandler<'octo, '_> {
+        StatsHandler::new(self)
+    }
+
+    /// Gets the repository.
+    ///
+    /// *Note* This makes a conditional request using the `ETag` of the
+    /// previous response, so if the repository hasn't changed since the last
+    /// call, the cached repository is returned, without counting against the
+    /// rate limit.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let repo = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .get()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self) -> Result<models::Repository> {
+        let route = format!(
+            "/repos/{owner}/{repo}",
+            owner = self.owner,
+            repo = self.repo,
+        );
+
+        self.crab.get_cached(route).await
+    }
+
+    /// Update the repository's settings, only the settings you set on the
+    /// builder are changed.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let repo = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .update()
+    ///     .allow_merge_commit(false)
+    ///     .delete_branch_on_merge(true)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self) -> UpdateRepoBuilder<'octo, '_> {
+        UpdateRepoBuilder::new(self)
+    }
+
+    /// Creates a new repository called `name` from this repository, which
+    /// must be a template repository. The new repository is owned by the
+    /// authenticated user unless you set an owner on the builder.
+    ///
+    /// *Note* If this repository isn't a template GitHub responds with
+    /// `422 Unprocessable Entity`, which you can check with
+    /// [`Error::status_code`]. This sets the `baptiste` preview
+    /// automatically.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let repo = octocrab::instance()
+    ///     .repos("owner", "template")
+    ///     .generate_from_template("new-repo")
+    ///     .owner("org")
+    ///     .private(true)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error::status_code`]: ../enum.Error.html#method.status_code
+    pub fn generate_from_template(
+        &self,
+        name: impl Into<String>,
+    ) -> GenerateRepoBuilder<'octo, '_> {
+        GenerateRepoBuilder::new(self, name.into())
+    }
+}
+
+/// # Issue Events
+impl<'octo> RepoHandler<'octo> {
+    /// Lists the events of every issue and pull request in the repository,
+    /// most recent first.
+    ///
+    /// *Note* GitHub doesn't support a `since` filter for this endpoint, to
+    /// ingest events incrementally page through the results until you reach
+    /// the id of the most recent event you've already seen.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .list_issue_events()
+    ///     // Optional Parameters
+    ///     .per_page(100)
+    ///     .page(2u32)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_issue_events(&self) -> ListIssueEventsBuilder<'octo, '_> {
+        ListIssueEventsBuilder::new(self)
+    }
+}
+
+/// # Releases
+impl<'octo> RepoHandler<'octo> {
+    /// Lists the repository's published releases, most recent first. Use
+    /// [`ListReleasesBuilder::include_drafts`] to also list draft releases.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .list_releases()
+    ///     // Optional Parameters
+    ///     .include_drafts(true)
+    ///     .per_page(100)
+    ///     .page(2u32)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ListReleasesBuilder::include_drafts`]: ./struct.ListReleasesBuilder.html#method.include_drafts
+    pub fn list_releases(&self) -> ListReleasesBuilder<'octo, '_> {
+        ListReleasesBuilder::new(self)
+    }
+}
+
+/// # Archives
+impl<'octo> RepoHandler<'octo> {
+    /// Downloads a gzipped tarball of the repository at `ref`, which can be a
+    /// commit SHA, a branch name, or a tag name.
+    ///
+    /// GitHub redirects the request to `codeload.github.com`, the redirect is
+    /// followed automatically and the `Authorization` header is not sent to
+    /// the other host. All files in the archive are inside a single top-level
+    /// directory named `{owner}-{repo}-{short sha}`, e.g.
+    /// `octocat-Hello-World-7fd1a60`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let tarball = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .download_tarball("v1.0.0")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_tarball(&self, r#ref: impl AsRef<str>) -> Result<bytes::Bytes> {
+        Self::archive_bytes(self.stream_tarball(r#ref).await?).await
+    }
+
+    /// Downloads a zip archive of the repository at `ref`, like
+    /// [`RepoHandler::download_tarball`].
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let zipball = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .download_zipball("master")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RepoHandler::download_tarball`]: #method.download_tarball
+    pub async fn download_zipball(&self, r#ref: impl AsRef<str>) -> Result<bytes::Bytes> {
+        Self::archive_bytes(self.stream_zipball(r#ref).await?).await
+    }
+
+    /// Requests a gzipped tarball of the repository at `ref` like
+    /// [`RepoHandler::download_tarball`], returning the response without
+    /// reading its body so that large archives can be read in chunks.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let mut response = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .stream_tarball("master")
+    ///     .await?;
+    ///
+    /// while let Some(chunk) = response.chunk().await.unwrap() {
+    ///     println!("Received {} bytes", chunk.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RepoHandler::download_tarball`]: #method.download_tarball
+    pub async fn stream_tarball(&self, r#ref: impl AsRef<str>) -> Result<reqwest::Response> {
+        self.archive("tarball", r#ref.as_ref()).await
+    }
+
+    /// Requests a zip archive of the repository at `ref` like
+    /// [`RepoHandler::download_zipball`], returning the response without
+    /// reading its body so that large archives can be read in chunks.
+    ///
+    /// [`RepoHandler::download_zipball`]: #method.download_zipball
+    pub async fn stream_zipball(&self, r#ref: impl AsRef<str>) -> Result<reqwest::Response> {
+        self.archive("zipball", r#ref.as_ref()).await
+    }
+
+    async fn archive(&self, format: &str, r#ref: &str) -> Result<reqwest::Response> {
+        let route = format!(
+            "/repos/{owner}/{repo}/{format}/{ref}",
+            owner = self.owner,
+            repo = self.repo,
+            format = format,
+            ref = crate::encoding::path(r#ref),
+        );
+        let response = self
+            .crab
+            ._get(self.crab.absolute_url(route)?, None::<&()>)
+            .await?;
+
+        Octocrab::map_github_error(response).await
+    }
+
+    async fn archive_bytes(response: reqwest::Response) -> Result<bytes::Bytes> {
+        use snafu::ResultExt;
+
+        response.bytes().await.context(crate::error::Http)
+    }
+}
+
+/// # Topics
+impl<'octo> RepoHandler<'octo> {
+    /// Gets the names of the repository's topics.
+    ///
+    /// *Note* This only returns the first page of topics, use
+    /// [`RepoHandler::list_topics`] if the repository has more topics than
+    /// fit on a single page. This sets the `mercy` preview automatically.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let topics = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .get_topics()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RepoHandler::list_topics`]: #method.list_topics
+    pub async fn get_topics(&self) -> Result<Vec<String>> {
+        Ok(self.list_topics().send().await?.items)
+    }
+
+    /// Lists the names of the repository's topics, one page at a time.
+    ///
+    /// *Note* This sets the `mercy` preview automatically.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .list_topics()
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_topics(&self) -> ListTopicsBuilder<'octo, '_> {
+        ListTopicsBuilder::new(self)
+    }
+}
+
+/// # Contents
+impl<'octo> RepoHandler<'octo> {
+    /// Gets the contents of the file at `path` in the repository.
+    ///
+    /// *Note* GitHub's contents API doesn't return the content of files larger
+    /// than 1 MB. For those files, the content is transparently fetched from
+    /// the git data API with [`RepoHandler::get_blob`] instead, so `content`
+    /// always contains the full (base64 encoded) file.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let contents = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .get_content("src/lib.rs")
+    ///     .r#ref("master")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RepoHandler::get_blob`]: #method.get_blob
+    pub fn get_content(&self, path: impl Into<String>) -> GetContentBuilder<'octo, '_> {
+        GetContentBuilder::new(self, path.into())
+    }
+
+    /// Gets the repository's README.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let readme = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .get_readme()
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_readme(&self) -> GetReadmeBuilder<'octo, '_> {
+        GetReadmeBuilder::new(self, None)
+    }
+
+    /// Gets the README in the directory `dir` of the repository. GitHub
+    /// responds with `404 Not Found` if the directory has no README, use
+    /// [`GetReadmeBuilder::send_opt`] to get `None` instead.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// if let Some(readme) = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .get_readme_in("packages/parser")
+    ///     .r#ref("v1.0.0")
+    ///     .send_opt()
+    ///     .await?
+    /// {
+    ///     println!("{}", readme.html_url);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`GetReadmeBuilder::send_opt`]: ./struct.GetReadmeBuilder.html#method.send_opt
+    pub fn get_readme_in(&self, dir: impl Into<String>) -> GetReadmeBuilder<'octo, '_> {
+        GetReadmeBuilder::new(self, Some(dir.into()))
+    }
+}
+
+/// # Git Data
+impl<'octo> RepoHandler<'octo> {
+    /// Gets the blob with the given `sha`, this supports blobs of up to
+    /// 100 MB.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let blob = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .get_blob("3a0f86fb8db8eea7ccbb9a95f325ddbedfb25e15")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_blob(&self, sha: impl AsRef<str>) -> Result<models::GitBlob> {
+        let route = format!(
+            "/repos/{owner}/{repo}/git/blobs/{sha}",
+            owner = self.owner,
+            repo = self.repo,
+            sha = crate::encoding::path(sha.as_ref()),
+        );
+
+        self.crab.get(route, None::<&()>).await
+    }
+
+    /// Gets the full tree for `sha` recursively, in a single request. `sha` can
+    /// be a tree SHA, a commit SHA, or a ref name such as a branch.
+    ///
+    /// *Note* GitHub limits the recursive tree to 100,000 entries (or 7 MB),
+    /// if the tree is larger than that, `truncated` will be `true` and you'll
+    /// need to fetch the remaining subtrees non-recursively.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let tree = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .get_tree_recursive("master")
+    ///     .await?;
+    ///
+    /// if tree.truncated {
+    ///     println!("Only got {} entries", tree.tree.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_tree_recursive(&self, sha: impl AsRef<str>) -> Result<models::GitTree> {
+        let route = format!(
+            "/repos/{owner}/{repo}/git/trees/{sha}",
+            owner = self.owner,
+            repo = self.repo,
+            sha = crate::encoding::path(sha.as_ref()),
+        );
+
+        self.crab.get(route, Some(&[("recursive", "1")])).await
+    }
+
+    /// Lists the paths of every file (blob) in the tree at `sha` that starts
+    /// with `prefix`, using [`RepoHandler::get_tree_recursive`]. `prefix` is
+    /// matched against the full path as is, so use a trailing slash
+    /// (e.g. `"src/"`) to only match a directory.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let paths = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .list_paths("master", "src/")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RepoHandler::get_tree_recursive`]: #method.get_tree_recursive
+    pub async fn list_paths(
+        &self,
+        sha: impl AsRef<str>,
+        prefix: impl AsRef<str>,
+    ) -> Result<Vec<String>> {
+        let tree = self.get_tree_recursive(sha).await?;
+
+        Ok(tree.blob_paths(prefix.as_ref()).map(String::from).collect())
+    }
+}
+
+/// # Commits
+impl<'octo> RepoHandler<'octo> {
+    /// Gets the commit `ref`, which can be a commit SHA, a branch name, or a
+    /// tag name, including its `stats` and changed `files`. Use
+    /// [`GetCommitBuilder::stats_only`] if you only need the number of
+    /// additions and deletions.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let commit = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .get_commit("6dcb09b5b57875f334f61aebed695e2e4193db5e")
+    ///     .stats_only()
+    ///     .send()
+    ///     .await?;
+    ///
+    /// if let Some(stats) = commit.stats {
+    ///     println!("+{} -{}", stats.additions, stats.deletions);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`GetCommitBuilder::stats_only`]: ./struct.GetCommitBuilder.html#method.stats_only
+    pub fn get_commit(&self, r#ref: impl Into<String>) -> GetCommitBuilder<'octo, '_> {
+        GetCommitBuilder::new(self, r#ref.into())
+    }
+
+    /// Compares the commits `base` and `head`, which can be commit SHAs,
+    /// branch names, or tag names. `head` can also be in another repository
+    /// of the same network, as `owner:ref`.
+    ///
+    /// GitHub paginates the commits of large comparisons, use
+    /// [`CompareCommitsBuilder::send_all`] to collect all of them.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let comparison = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .compare("v1.0.0", "v2.0.0")
+    ///     .per_page(100)
+    ///     .send_all()
+    ///     .await?;
+    ///
+    /// for commit in comparison.commits {
+    ///     println!("{:?}", commit.sha);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`CompareCommitsBuilder::send_all`]: ./struct.CompareCommitsBuilder.html#method.send_all
+    pub fn compare(
+        &self,
+        base: impl Into<String>,
+        head: impl Into<String>,
+    ) -> CompareCommitsBuilder<'octo, '_> {
+        CompareCommitsBuilder::new(self, base.into(), head.into())
+    }
+
+    /// Lists the merged pull requests that introduced the commit `sha` to the
+    /// repository, or if the commit isn't in the default branch, the open
+    /// pull requests that contain it.
+    ///
+    /// *Note* This sets the `groot` preview automatically.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .list_pulls_for_commit("6dcb09b5b57875f334f61aebed695e2e4193db5e")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_pulls_for_commit(
+        &self,
+        sha: impl AsRef<str>,
+    ) -> Result<Page<models::PullRequest>> {
+        let route = format!(
+            "/repos/{owner}/{repo}/commits/{sha}/pulls",
+            owner = self.owner,
+            repo = self.repo,
+            sha = crate::encoding::path(sha.as_ref()),
+        );
+
+        self.crab
+            .get_with(route, None::<&()>, crate::preview_headers("groot"))
+            .await
+    }
+
+    /// Lists the branches whose head is the commit `sha`. This isn't
+    /// paginated.
+    ///
+    /// *Note* This sets the `groot` preview automatically.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let branches = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .list_branches_for_head_commit("6dcb09b5b57875f334f61aebed695e2e4193db5e")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_branches_for_head_commit(
+        &self,
+        sha: impl AsRef<str>,
+    ) -> Result<Vec<models::Branch>> {
+        let route = format!(
+            "/repos/{owner}/{repo}/commits/{sha}/branches-where-head",
+            owner = self.owner,
+            repo = self.repo,
+            sha = crate::encoding::path(sha.as_ref()),
+        );
+
+        self.crab
+            .get_with(route, None::<&()>, crate::preview_headers("groot"))
+            .await
+    }
+
+    /// Lists the comments on every commit in the repository, oldest first.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .list_commit_comments()
+    ///     // Optional Parameters
+    ///     .per_page(100)
+    ///     .page(2u32)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_commit_comments(&self) -> ListCommitCommentsBuilder<'octo, '_> {
+        ListCommitCommentsBuilder::new(self)
+    }
+}
+
+/// # Branches
+impl<'octo> RepoHandler<'octo> {
+    /// Lists the repository's branches.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .list_branches()
+    ///     // Optional Parameters
+    ///     .protected(true)
+    ///     .per_page(100)
+    ///     .page(2u32)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_branches(&self) -> ListBranchesBuilder<'octo, '_> {
+        ListBranchesBuilder::new(self)
+    }
+
+    /// Renames the branch `old_name` to `new_name`, returning the renamed
+    /// branch. GitHub automatically updates open pull requests and branch
+    /// protection rules that target the branch.
+    ///
+    /// **Note** Renaming the default branch requires admin access to the
+    /// repository, otherwise GitHub returns a `403 Forbidden` error. If a
+    /// branch named `new_name` already exists, GitHub returns a
+    /// `422 Unprocessable Entity` error.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let branch = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .rename_branch("master", "main")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn rename_branch(
+        &self,
+        old_name: impl AsRef<str>,
+        new_name: impl AsRef<str>,
+    ) -> Result<models::Branch> {
+        let route = format!(
+            "/repos/{owner}/{repo}/branches/{branch}/rename",
+            owner = self.owner,
+            repo = self.repo,
+            branch = crate::encoding::path(old_name.as_ref()),
+        );
+
+        self.crab
+            .post(
+                route,
+                Some(&serde_json::json!({ "new_name": new_name.as_ref() })),
+            )
+            .await
+    }
+}
+
+/// # Branch Protection
+///
+/// These require admin access to the repository, and `branch` to be
+/// protected. GitHub responds with `404 Not Found` otherwise, see
+/// [`Error::is_not_found`].
+///
+/// [`Error::is_not_found`]: ../enum.Error.html#method.is_not_found
+impl<'octo> RepoHandler<'octo> {
+    /// Gets the status checks that must pass before merging into `branch`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let checks = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .get_required_status_checks("main")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_required_status_checks(
+        &self,
+        branch: impl AsRef<str>,
+    ) -> Result<models::RequiredStatusChecks> {
+        self.crab
+            .get(
+                self.required_status_checks_route(branch.as_ref()),
+                None::<&()>,
+            )
+            .await
+    }
+
+    /// Lists the names of the status checks that must pass before merging
+    /// into `branch`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let contexts = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .list_required_status_check_contexts("main")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_required_status_check_contexts(
+        &self,
+        branch: impl AsRef<str>,
+    ) -> Result<Vec<String>> {
+        let route = format!(
+            "{}/contexts",
+            self.required_status_checks_route(branch.as_ref())
+        );
+
+        self.crab.get(route, None::<&()>).await
+    }
+
+    /// Adds `contexts` to the status checks required on `branch`, returning
+    /// the updated list.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let contexts = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .add_required_status_check_contexts("main", &[String::from("ci/lint")])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_required_status_check_contexts(
+        &self,
+        branch: impl AsRef<str>,
+        contexts: &[String],
+    ) -> Result<Vec<String>> {
+        self.send_required_status_check_contexts(reqwest::Method::POST, branch.as_ref(), contexts)
+            .await
+    }
+
+    /// Replaces the status checks required on `branch` with `contexts`,
+    /// returning the updated list.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let contexts = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .replace_required_status_check_contexts(
+    ///         "main",
+    ///         &[String::from("ci/test"), String::from("ci/lint")],
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn replace_required_status_check_contexts(
+        &self,
+        branch: impl AsRef<str>,
+        contexts: &[String],
+    ) -> Result<Vec<String>> {
+        self.send_required_status_check_contexts(reqwest::Method::PUT, branch.as_ref(), contexts)
+            .await
+    }
+
+    /// Removes `contexts` from the status checks required on `branch`,
+    /// returning the updated list.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let contexts = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .remove_required_status_check_contexts("main", &[String::from("ci/lint")])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_required_status_check_contexts(
+        &self,
+        branch: impl AsRef<str>,
+        contexts: &[String],
+    ) -> Result<Vec<String>> {
+        self.send_required_status_check_contexts(reqwest::Method::DELETE, branch.as_ref(), contexts)
+            .await
+    }
+
+    async fn send_required_status_check_contexts(
+        &self,
+        method: reqwest::Method,
+        branch: &str,
+        contexts: &[String],
+    ) -> Result<Vec<String>> {
+        let route = format!("{}/contexts", self.required_status_checks_route(branch));
+        let request = self
+            .crab
+            .client
+            .request(method, self.crab.absolute_url(route)?)
+            .json(&serde_json::json!({ "contexts": contexts }));
+        let response = self.crab.execute(request).await?;
+
+        crate::FromResponse::from_response(Octocrab::map_github_error(response).await?).await
+    }
+
+    fn required_status_checks_route(&self, branch: &str) -> String {
+        format!(
+            "/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks",
+            owner = self.owner,
+            repo = self.repo,
+            branch = crate::encoding::path(branch),
+        )
+    }
+}
+
+/// # Collaborators
+impl<'octo> RepoHandler<'octo> {
+    /// Lists the users with access to the repository, along with their
+    /// permissions. For organization-owned repositories this includes
+    /// organization members with access through a team or the
+    /// organization's default permission.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::repos::{Affiliation, Permission};
+    ///
+    /// let page = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .list_collaborators()
+    ///     // Optional Parameters
+    ///     .affiliation(Affiliation::Outside)
+    ///     .permission(Permission::Admin)
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    ///
+    /// for collaborator in page {
+    ///     println!("{} can push: {}", collaborator.user.login, collaborator.permissions.push);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_collaborators(&self) -> ListCollaboratorsBuilder<'octo, '_> {
+        ListCollaboratorsBuilder::new(self)
+    }
+}
+
+/// # Tags
+impl<'octo> RepoHandler<'octo> {
+    /// Lists the repository's tags.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .list_tags()
+    ///     // Optional Parameters
+    ///     .per_page(100)
+    ///     .page(2u32)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_tags(&self) -> ListTagsBuilder<'octo, '_> {
+        ListTagsBuilder::new(self)
+    }
+}
+
+/// # Statuses
+impl<'octo> RepoHandler<'octo> {
+    /// Gets the combined status for `ref`, which can be a commit SHA, a branch
+    /// name, or a tag name. The combined `state` is `failure` if any status is
+    /// `error` or `failure`, `pending` if there are no statuses or a status is
+    /// `pending`, and `success` otherwise. Only the latest status for each
+    /// context is included.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::StatusState;
+    ///
+    /// let status = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .combined_status("master")
+    ///     .await?;
+    ///
+    /// if status.state == StatusState::Success {
+    ///     println!("All {} statuses passed", status.total_count);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn combined_status(&self, r#ref: impl AsRef<str>) -> Result<models::CombinedStatus> {
+        let route = format!(
+            "/repos/{owner}/{repo}/commits/{ref}/status",
+            owner = self.owner,
+            repo = self.repo,
+            ref = crate::encoding::path(r#ref.as_ref()),
+        );
+
+        self.crab.get(route, None::<&()>).await
+    }
+
+    /// Creates a commit status with `state` for the commit `sha`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::StatusState;
+    ///
+    /// let status = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .create_status("6dcb09b5b57875f334f61aebed695e2e4193db5e", StatusState::Success)
+    ///     .context("continuous-integration/jenkins")
+    ///     .description("The build succeeded!")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_status(
+        &self,
+        sha: impl Into<String>,
+        state: models::StatusState,
+    ) -> CreateStatusBuilder<'octo, '_> {
+        CreateStatusBuilder::new(self, sha.into(), state)
+    }
+
+    /// Sets a `success` status for `context` on the commit `sha`, a shorthand
+    /// for [`RepoHandler::create_status`].
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .set_success("6dcb09b", "ci/build", "https://ci.example.com/1000")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`RepoHandler::create_status`]: #method.create_status
+    pub async fn set_success(
+        &self,
+        sha: impl Into<String>,
+        context: impl Into<String>,
+        target_url: impl Into<String>,
+    ) -> Result<models::Status> {
+        self.set_status(sha, models::StatusState::Success, context, target_url)
+            .await
+    }
+
+    /// Sets a `failure` status for `context` on the commit `sha`, a shorthand
+    /// for [`RepoHandler::create_status`].
+    ///
+    /// [`RepoHandler::create_status`]: #method.create_status
+    pub async fn set_failure(
+        &self,
+        sha: impl Into<String>,
+        context: impl Into<String>,
+        target_url: impl Into<String>,
+    ) -> Result<models::Status> {
+        self.set_status(sha, models::StatusState::Failure, context, target_url)
+            .await
+    }
+
+    /// Sets a `pending` status for `context` on the commit `sha`, a shorthand
+    /// for [`RepoHandler::create_status`].
+    ///
+    /// [`RepoHandler::create_status`]: #method.create_status
+    pub async fn set_pending(
+        &self,
+        sha: impl Into<String>,
+        context: impl Into<String>,
+        target_url: impl Into<String>,
+    ) -> Result<models::Status> {
+        self.set_status(sha, models::StatusState::Pending, context, target_url)
+            .await
+    }
+
+    async fn set_status(
+        &self,
+        sha: impl Into<String>,
+        state: models::StatusState,
+        context: impl Into<String>,
+        target_url: impl Into<String>,
+    ) -> Result<models::Status> {
+        self.create_status(sha, state)
+            .context(context)
+            .target_url(target_url)
+            .send()
+            .await
+    }
+}
+
+/// # Checks
+impl<'octo> RepoHandler<'octo> {
+    /// Lists the check runs for `ref`, which can be a commit SHA, a branch
+    /// name, or a tag name. The total number of check runs is available in
+    /// [`Page::total_count`].
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::checks::Status;
+    ///
+    /// let page = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .list_check_runs_for_ref("master")
+    ///     .status(Status::Completed)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Page::total_count`]: ../struct.Page.html#structfield.total_count
+    pub fn list_check_runs_for_ref(
+        &self,
+        r#ref: impl Into<String>,
+    ) -> ListCheckRunsBuilder<'octo, '_> {
+        ListCheckRunsBuilder::new(self, r#ref.into())
+    }
+
+    /// Lists the check suites for `ref`, which can be a commit SHA, a branch
+    /// name, or a tag name. The total number of check suites is available in
+    /// [`Page::total_count`].
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .list_check_suites_for_ref("master")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Page::total_count`]: ../struct.Page.html#structfield.total_count
+    pub async fn list_check_suites_for_ref(
+        &self,
+        r#ref: impl AsRef<str>,
+    ) -> Result<Page<models::CheckSuite>> {
+        let route = format!(
+            "/repos/{owner}/{repo}/commits/{ref}/check-suites",
+            owner = self.owner,
+            repo = self.repo,
+            ref = crate::encoding::path(r#ref.as_ref()),
+        );
+
+        self.crab
+            .get_enveloped_page(
+                route,
+                None::<&()>,
+                reqwest::header::HeaderMap::new(),
+                "check_suites",
+            )
+            .await
+    }
+
+    /// Triggers GitHub to rerequest an existing check suite, without pushing
+    /// new code to the repository. This resets the suite's status to
+    /// `queued` and clears its conclusion.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .rerequest_check_suite(5)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn rerequest_check_suite(&self, suite_id: u64) -> Result<()> {
+        let route = format!(
+            "/repos/{owner}/{repo}/check-suites/{suite_id}/rerequest",
+            owner = self.owner,
+            repo = self.repo,
+            suite_id = suite_id,
+        );
+
+        let response = self
+            .crab
+            ._post(self.crab.absolute_url(route)?, None::<&()>)
+            .await?;
+
+        if response.status() == 201 {
+            Ok(())
+        } else {
+            Octocrab::map_github_error(response).await.map(drop)
+        }
+    }
+}
+
+/// # Community
+impl<'octo> RepoHandler<'octo> {
+    /// Gets the code of conduct GitHub detected in the repository.
+    ///
+    /// *Note* This sets the `scarlet-witch` preview automatically.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let code_of_conduct = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .code_of_conduct()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn code_of_conduct(&self) -> Result<models::CodeOfConduct> {
+        let route = format!(
+            "/repos/{owner}/{repo}/community/code_of_conduct",
+            owner = self.owner,
+            repo = self.repo,
+        );
+
+        self.crab
+            .get_with(route, None::<&()>, crate::preview_headers("scarlet-witch"))
+            .await
+    }
+}
+
+/// # Environments
+impl<'octo> RepoHandler<'octo> {
+    /// Lists the repository's deployment environments.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let environments = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .environments()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn environments(&self) -> Result<Vec<models::Environment>> {
+        let route = format!(
+            "/repos/{owner}/{repo}/environments",
+            owner = self.owner,
+            repo = self.repo,
+        );
+
+        self.crab
+            .get_enveloped_page::<models::Environment, _, _>(
+                route,
+                None::<&()>,
+                reqwest::header::HeaderMap::new(),
+                "environments",
+            )
+            .await
+            .map(|page| page.items)
+    }
+
+    /// Gets the public key of `environment`, which is needed to encrypt
+    /// secrets before creating or updating them.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let key = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .get_environment_public_key("production")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_environment_public_key(
+        &self,
+        environment: impl AsRef<str>,
+    ) -> Result<models::PublicKey> {
+        let route = format!(
+            "/repos/{owner}/{repo}/environments/{environment}/secrets/public-key",
+            owner = self.owner,
+            repo = self.repo,
+            environment = crate::encoding::segment(environment.as_ref()),
+        );
+
+        self.crab.get(route, None::<&()>).await
+    }
+
+    /// Creates or updates the secret `name` of `environment`.
+    /// `encrypted_value` must already be encrypted with the environment's
+    /// public key, identified by `key_id`, using a libsodium sealed box.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::instance();
+    /// let repo = octocrab.repos("owner", "repo");
+    /// let key = repo.get_environment_public_key("production").await?;
+    /// # let encrypted_value = String::new();
+    ///
+    /// repo.create_or_update_environment_secret(
+    ///     "production",
+    ///     "DEPLOY_TOKEN",
+    ///     encrypted_value,
+    ///     key.key_id,
+    /// )
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_or_update_environment_secret(
+        &self,
+        environment: impl AsRef<str>,
+        name: impl AsRef<str>,
+        encrypted_value: impl Into<String>,
+        key_id: impl Into<String>,
+    ) -> Result<()> {
+        let route = format!(
+            "/repos/{owner}/{repo}/environments/{environment}/secrets/{name}",
+            owner = self.owner,
+            repo = self.repo,
+            environment = crate::encoding::segment(environment.as_ref()),
+            name = crate::encoding::segment(name.as_ref()),
+        );
+        let request =
+            self.crab
+                .client
+                .put(self.crab.absolute_url(route)?)
+                .json(&serde_json::json!({
+                    "encrypted_value": encrypted_value.into(),
+                    "key_id": key_id.into(),
+                }));
+        let response = self.crab.execute(request).await?;
+
+        // GitHub responds with `201 Created` for new secrets, and with
+        // `204 No Content` for updated secrets.
+        if response.status() == 201 {
+            Ok(())
+        } else {
+            Octocrab::map_no_content(response).await
+        }
+    }
+
+    /// Lists the branch policies of `environment`, which restrict the
+    /// branches that can deploy to it.
+    ///
+    /// *Note* This requires admin access to the repository, GitHub responds
+    /// with `403 Forbidden` otherwise, which can be checked with
+    /// [`Error::status_code`]. GitHub responds with `404 Not Found` if the
+    /// environment doesn't use custom branch policies.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let policies = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .list_environment_branch_policies("production")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error::status_code`]: ../enum.Error.html#method.status_code
+    pub async fn list_environment_branch_policies(
+        &self,
+        environment: impl AsRef<str>,
+    ) -> Result<Page<models::EnvironmentBranchPolicy>> {
+        self.crab
+            .get_enveloped_page(
+                self.environment_branch_policies_route(environment.as_ref()),
+                None::<&()>,
+                reqwest::header::HeaderMap::new(),
+                "branch_policies",
+            )
+            .await
+    }
+
+    /// Allows the branches matching `name_pattern` (e.g. `release/*`) to
+    /// deploy to `environment`. Requires admin access to the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let policy = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .create_environment_branch_policy("production", "release/*")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_environment_branch_policy(
+        &self,
+        environment: impl AsRef<str>,
+        name_pattern: impl Into<String>,
+    ) -> Result<models::EnvironmentBranchPolicy> {
+        self.crab
+            .post(
+                self.environment_branch_policies_route(environment.as_ref()),
+                Some(&serde_json::json!({ "name": name_pattern.into() })),
+            )
+            .await
+    }
+
+    /// Deletes the branch policy `id` of `environment`. Requires admin access
+    /// to the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .delete_environment_branch_policy("production", 361471)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_environment_branch_policy(
+        &self,
+        environment: impl AsRef<str>,
+        id: u64,
+    ) -> Result<()> {
+        let route = format!(
+            "{}/{}",
+            self.environment_branch_policies_route(environment.as_ref()),
+            id
+        );
+        let response = self
+            .crab
+            ._delete(self.crab.absolute_url(route)?, None::<&()>)
+            .await?;
+
+        Octocrab::map_no_content(response).await
+    }
+
+    fn environment_branch_policies_route(&self, environment: &str) -> String {
+        format!(
+            "/repos/{owner}/{repo}/environments/{environment}/deployment-branch-policies",
+            owner = self.owner,
+            repo = self.repo,
+            environment = crate::encoding::segment(environment),
+        )
+    }
+}
+
+/// # Vulnerability Alerts
+impl<'octo> RepoHandler<'octo> {
+    /// Checks whether vulnerability alerts (and Dependabot alerts) are
+    /// enabled for the repository. The authenticated user must have admin
+    /// access to the repository.
+    ///
+    /// *Note* This sets the `dorian` preview automatically.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let enabled = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .vulnerability_alerts_enabled()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn vulnerability_alerts_enabled(&self) -> Result<bool> {
+        let response = self
+            .crab
+            ._get_with(
+                self.vulnerability_alerts_url()?,
+                None::<&()>,
+                crate::preview_headers("dorian"),
+            )
+            .await?;
+
+        Octocrab::map_boolean_status(response).await
+    }
+
+    /// Enables vulnerability alerts for the repository.
+    ///
+    /// *Note* This sets the `dorian` preview automatically.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .enable_vulnerability_alerts()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn enable_vulnerability_alerts(&self) -> Result<()> {
+        let request = self
+            .crab
+            .client
+            .put(self.vulnerability_alerts_url()?)
+            .headers(crate::preview_headers("dorian"));
+
+        Octocrab::map_no_content(self.crab.execute(request).await?).await
+    }
+
+    /// Disables vulnerability alerts for the repository.
+    ///
+    /// *Note* This sets the `dorian` preview automatically.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .disable_vulnerability_alerts()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn disable_vulnerability_alerts(&self) -> Result<()> {
+        let request = self
+            .crab
+            .client
+            .delete(self.vulnerability_alerts_url()?)
+            .headers(crate::preview_headers("dorian"));
+
+        Octocrab::map_no_content(self.crab.execute(request).await?).await
+    }
+
+    fn vulnerability_alerts_url(&self) -> Result<reqwest::Url> {
+        self.crab.absolute_url(format!(
+            "/repos/{owner}/{repo}/vulnerability-alerts",
+            owner = self.owner,
+            repo = self.repo,
+        ))
+    }
+}
+
+/// # Tag Protection
+///
+/// These require admin access to the repository. GitHub responds with
+/// `403 Forbidden` otherwise, see [`Error::status_code`].
+///
+/// [`Error::status_code`]: ../enum.Error.html#method.status_code
+impl<'octo> RepoHandler<'octo> {
+    /// Lists the repository's tag protection patterns.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let protections = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .list_tag_protection()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_tag_protection(&self) -> Result<Vec<models::TagProtection>> {
+        self.crab
+            .get(self.tag_protection_route(), None::<&()>)
+            .await
+    }
+
+    /// Protects the tags matching `pattern`, e.g. `v1.*`, so that only admins
+    /// and maintainers can create or delete them.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let protection = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .create_tag_protection("v1.*")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_tag_protection(
+        &self,
+        pattern: impl AsRef<str>,
+    ) -> Result<models::TagProtection> {
+        self.crab
+            .post(
+                self.tag_protection_route(),
+                Some(&serde_json::json!({ "pattern": pattern.as_ref() })),
+            )
+            .await
+    }
+
+    /// Deletes the tag protection pattern with the given `id`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .delete_tag_protection(2)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_tag_protection(&self, id: u64) -> Result<()> {
+        let route = format!("{}/{}", self.tag_protection_route(), id);
+        let response = self
+            .crab
+            ._delete(self.crab.absolute_url(route)?, None::<&()>)
+            .await?;
+
+        Octocrab::map_no_content(response).await
+    }
+
+    fn tag_protection_route(&self) -> String {
+        format!(
+            "/repos/{owner}/{repo}/tags/protection",
+            owner = self.owner,
+            repo = self.repo,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        matchers::{body_json, header, method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    fn contents(content: &str, encoding: &str) -> serde_json::Value {
+        serde_json::json!({
+            "type": "file",
+            "encoding": encoding,
+            "size": 5_000_000,
+            "name": "large.bin",
+            "path": "assets/large.bin",
+            "content": content,
+            "sha": "3d21ec53a331a6f037a91c368710b99387d012c1",
+            "url": "https://api.github.com/repos/octocat/Hello-World/contents/assets/large.bin",
+            "git_url": "https://api.github.com/repos/octocat/Hello-World/git/blobs/3d21ec53a331a6f037a91c368710b99387d012c1",
+            "html_url": "https://github.com/octocat/Hello-World/blob/master/assets/large.bin",
+            "download_url": "https://raw.githubusercontent.com/octocat/Hello-World/master/assets/large.bin",
+        })
+    }
+
+    async fn mount_blob(server: &MockServer) {
+        Mock::given(method("GET"))
+            .and(path(
+                "/repos/octocat/Hello-World/git/blobs/3d21ec53a331a6f037a91c368710b99387d012c1",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "sha": "3d21ec53a331a6f037a91c368710b99387d012c1",
+                "node_id": "MDQ6QmxvYjNkMjFlYzUzYTMzMWE2ZjAzN2E5MWMzNjg3MTBiOTkzODdkMDEyYzE=",
+                "size": 5_000_000,
+                "url": "https://api.github.com/repos/octocat/Hello-World/git/blobs/3d21ec53a331a6f037a91c368710b99387d012c1",
+                "content": "TGFyZ2UgZmlsZQ==",
+                "encoding": "base64",
+            })))
+            .expect(1)
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn get_content_without_content_falls_back_to_blob() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/contents/assets/large.bin"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(contents("", "none")))
+            .mount(&server)
+            .await;
+        mount_blob(&server).await;
+
+        let contents = crate::setup(&server)
+            .repos("octocat", "Hello-World")
+            .get_content("assets/large.bin")
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(contents.content, "TGFyZ2UgZmlsZQ==");
+        assert_eq!(contents.encoding, "base64");
+    }
+
+    #[tokio::test]
+    async fn get_content_too_large_falls_back_to_blob() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/contents/assets/large.bin"))
+            .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+                "message": "This API returns blobs up to 1 MB in size. The requested blob is too large to fetch via this API, please use the Git Data API instead.",
+                "documentation_url": "https://docs.github.com/rest/reference/repos#get-repository-content",
+            })))
+            .mount(&server)
+            .await;
+        let mut entry = contents("", "none");
+        entry.as_object_mut().unwrap().remove("content");
+        entry.as_object_mut().unwrap().remove("encoding");
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/contents/assets"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(vec![entry]))
+            .mount(&server)
+            .await;
+        mount_blob(&server).await;
+
+        let contents = crate::setup(&server)
+            .repos("octocat", "Hello-World")
+            .get_content("assets/large.bin")
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(contents.path, "assets/large.bin");
+        assert_eq!(contents.content, "TGFyZ2UgZmlsZQ==");
+        assert_eq!(contents.encoding, "base64");
+    }
+
+    #[tokio::test]
+    async fn get_content_of_file_or_directory() {
+        let server = MockServer::start().await;
+        let mut file = contents("SGVsbG8=", "base64");
+        file["size"] = 5.into();
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/contents/assets/large.bin"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&file))
+            .mount(&server)
+            .await;
+        let mut entry = file.clone();
+        entry.as_object_mut().unwrap().remove("content");
+        entry.as_object_mut().unwrap().remove("encoding");
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/contents/assets"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                entry,
+                {
+                    "type": "dir",
+                    "size": 0,
+                    "name": "icons",
+                    "path": "assets/icons",
+                    "sha": "a84d88e7554fc1fa21bcbc4efae3c782a70d2b9d",
+                    "url": "https://api.github.com/repos/octocat/Hello-World/contents/assets/icons",
+                    "git_url": "https://api.github.com/repos/octocat/Hello-World/git/trees/a84d88e7554fc1fa21bcbc4efae3c782a70d2b9d",
+                    "html_url": "https://github.com/octocat/Hello-World/tree/master/assets/icons",
+                    "download_url": null
+                }
+            ])))
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let repo = octocrab.repos("octocat", "Hello-World");
+
+        let response = repo
+            .get_content("assets/large.bin")
+            .send_any()
+            .await
+            .unwrap();
+        assert_eq!(response.as_file().unwrap().content, "SGVsbG8=");
+        assert!(response.as_dir().is_none());
+
+        let response = repo.get_content("assets").send_any().await.unwrap();
+        let entries = response.as_dir().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].entry_type, "file");
+        assert_eq!(entries[1].entry_type, "dir");
+        assert_eq!(entries[1].path, "assets/icons");
+        assert!(entries[1].download_url.is_none());
+        assert!(response.as_file().is_none());
+
+        let error = repo.get_content("assets").send().await.unwrap_err();
+        assert!(matches!(error, crate::Error::Other { .. }));
+    }
+
+    #[tokio::test]
+    async fn get_topics() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/topics"))
+            .and(header("accept", "application/vnd.github.mercy-preview"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "names": ["octocat", "atom", "electron", "api"],
+            })))
+            .mount(&server)
+            .await;
+
+        let topics = crate::setup(&server)
+            .repos("octocat", "Hello-World")
+            .get_topics()
+            .await
+            .unwrap();
+
+        assert_eq!(topics, ["octocat", "atom", "electron", "api"]);
+    }
+
+    #[tokio::test]
+    async fn rename_branch() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/repos/octocat/Hello-World/branches/master/rename"))
+            .and(body_json(serde_json::json!({ "new_name": "main" })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "name": "main",
+                "commit": {
+                    "sha": "7fd1a60b01f91b314f59955a4e4d4e80d8edf11d",
+                    "url": "https://api.github.com/repos/octocat/Hello-World/commits/7fd1a60b01f91b314f59955a4e4d4e80d8edf11d",
+                },
+                "protected": true,
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/repos/octocat/Hello-World/branches/dev/rename"))
+            .respond_with(ResponseTemplate::new(422).set_body_json(serde_json::json!({
+                "message": "Branch already exists",
+                "documentation_url": "https://docs.github.com/rest/reference/repos#rename-a-branch",
+            })))
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let repo = octocrab.repos("octocat", "Hello-World");
+
+        let branch = repo.rename_branch("master", "main").await.unwrap();
+        assert_eq!(branch.name, "main");
+        assert_eq!(
+            branch.commit.sha,
+            "7fd1a60b01f91b314f59955a4e4d4e80d8edf11d"
+        );
+        assert!(branch.protected);
+
+        assert!(matches!(
+            repo.rename_branch("dev", "main").await,
+            Err(crate::Error::GitHub { .. })
+        ));
+    }
+
+    fn comparison(total_commits: u64, shas: &[&str]) -> serde_json::Value {
+        let commit = |sha: &str| {
+            serde_json::json!({
+                "url": format!("https://api.github.com/repos/octocat/Hello-World/commits/{}", sha),
+                "sha": sha,
+                "node_id": "MDY6Q29tbWl0NmRjYjA5YjViNTc4NzVmMzM0ZjYxYWViZWQ2OTVlMmU0MTkzZGI1ZQ==",
+                "html_url": format!("https://github.com/octocat/Hello-World/commit/{}", sha),
+                "comments_url": format!("https://api.github.com/repos/octocat/Hello-World/commits/{}/comments", sha),
+                "author": null,
+                "committer": null
+            })
+        };
+
+        serde_json::json!({
+            "url": "https://api.github.com/repos/octocat/Hello-World/compare/master...topic",
+            "html_url": "https://github.com/octocat/Hello-World/compare/master...topic",
+            "permalink_url": "https://github.com/octocat/Hello-World/compare/octocat:bbcd538c8e72b8c175046e27cc8f907076331401...octocat:0328041d1152db8ae77652d1618a02e57f745f17",
+            "diff_url": "https://github.com/octocat/Hello-World/compare/master...topic.diff",
+            "patch_url": "https://github.com/octocat/Hello-World/compare/master...topic.patch",
+            "base_commit": commit("bbcd538c8e72b8c175046e27cc8f907076331401"),
+            "merge_base_commit": commit("bbcd538c8e72b8c175046e27cc8f907076331401"),
+            "status": "ahead",
+            "ahead_by": total_commits,
+            "behind_by": 0,
+            "total_commits": total_commits,
+            "commits": shas.iter().map(|sha| commit(sha)).collect::<Vec<_>>(),
+            "files": []
+        })
+    }
+
+    #[tokio::test]
+    async fn compare_send_all() {
+        let server = MockServer::start().await;
+        for (page, expected, shas) in &[("1", 2, &["a1", "b2"][..]), ("2", 1, &["c3"][..])] {
+            Mock::given(method("GET"))
+                .and(path(
+                    "/repos/octocat/Hello-World/compare/master...feature/topic",
+                ))
+                .and(query_param("per_page", "2"))
+                .and(query_param("page", *page))
+                .respond_with(ResponseTemplate::new(200).set_body_json(comparison(3, shas)))
+                .expect(*expected)
+                .mount(&server)
+                .await;
+        }
+
+        let octocrab = crate::setup(&server);
+        let repo = octocrab.repos("octocat", "Hello-World");
+
+        let first = repo
+            .compare("master", "feature/topic")
+            .per_page(2)
+            .page(1u32)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(first.status, "ahead");
+        assert_eq!(first.total_commits, 3);
+        assert_eq!(first.commits.len(), 2);
+
+        let all = repo
+            .compare("master", "feature/topic")
+            .per_page(2)
+            .send_all()
+            .await
+            .unwrap();
+        let shas: Vec<_> = all
+            .commits
+            .iter()
+            .map(|commit| commit.sha.as_deref().unwrap())
+            .collect();
+        assert_eq!(shas, ["a1", "b2", "c3"]);
+    }
+
+    #[tokio::test]
+    async fn get_commit_stats_only() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/commits/6dcb09b"))
+            .and(query_param("per_page", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "url": "https://api.github.com/repos/octocat/Hello-World/commits/6dcb09b5b57875f334f61aebed695e2e4193db5e",
+                "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+                "node_id": "MDY6Q29tbWl0NmRjYjA5YjViNTc4NzVmMzM0ZjYxYWViZWQ2OTVlMmU0MTkzZGI1ZQ==",
+                "html_url": "https://github.com/octocat/Hello-World/commit/6dcb09b5b57875f334f61aebed695e2e4193db5e",
+                "comments_url": "https://api.github.com/repos/octocat/Hello-World/commits/6dcb09b5b57875f334f61aebed695e2e4193db5e/comments",
+                "author": null,
+                "committer": null,
+                "stats": { "additions": 104, "deletions": 4, "total": 108 },
+                "files": [{
+                    "filename": "file1.txt",
+                    "additions": 10,
+                    "deletions": 2,
+                    "changes": 12,
+                    "status": "modified",
+                    "raw_url": "https://github.com/octocat/Hello-World/raw/7ca483543807a51b6079e54ac4cc392bc29ae284/file1.txt",
+                    "blob_url": "https://github.com/octocat/Hello-World/blob/7ca483543807a51b6079e54ac4cc392bc29ae284/file1.txt",
+                    "patch": "@@ -29,7 +29,7 @@",
+                }],
+            })))
+            .mount(&server)
+            .await;
+
+        let commit = crate::setup(&server)
+            .repos("octocat", "Hello-World")
+            .get_commit("6dcb09b")
+            .stats_only()
+            .send()
+            .await
+            .unwrap();
+
+        let stats = commit.stats.unwrap();
+        assert_eq!(stats.additions, 104);
+        assert_eq!(stats.deletions, 4);
+        assert_eq!(commit.files.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn get_returns_cached_repository_when_not_modified() {
+        let server = MockServer::start().await;
+        let repository: serde_json::Value =
+            serde_json::from_str(include_str!("../../tests/resources/repository.json")).unwrap();
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World"))
+            .and(header(
+                "if-none-match",
+                r#""644b5b0155e6404a9cc4bd9d8b1ae730""#,
+            ))
+            .respond_with(
+                ResponseTemplate::new(304)
+                    .insert_header("etag", r#""644b5b0155e6404a9cc4bd9d8b1ae730""#)
+                    .insert_header("x-ratelimit-remaining", "4999"),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("etag", r#""644b5b0155e6404a9cc4bd9d8b1ae730""#)
+                    .insert_header("x-ratelimit-remaining", "4999")
+                    .set_body_json(repository),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let repo = octocrab.repos("octocat", "Hello-World");
+
+        let first = repo.get().await.unwrap();
+        let second = repo.get().await.unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(second.full_name, "octocat/Hello-World");
+    }
+
+    #[tokio::test]
+    async fn status_shorthands() {
+        let server = MockServer::start().await;
+        for state in &["success", "failure", "pending"] {
+            Mock::given(method("POST"))
+                .and(path("/repos/octocat/Hello-World/statuses/6dcb09b"))
+                .and(body_json(serde_json::json!({
+                    "state": state,
+                    "target_url": "https://ci.example.com/1000/output",
+                    "context": "ci/build",
+                })))
+                .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                    "id": 1,
+                    "state": state,
+                    "context": "ci/build",
+                    "target_url": "https://ci.example.com/1000/output",
+                })))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let octocrab = crate::setup(&server);
+        let repo = octocrab.repos("octocat", "Hello-World");
+        let target_url = "https://ci.example.com/1000/output";
+
+        let success = repo
+            .set_success("6dcb09b", "ci/build", target_url)
+            .await
+            .unwrap();
+        let failure = repo
+            .set_failure("6dcb09b", "ci/build", target_url)
+            .await
+            .unwrap();
+        let pending = repo
+            .set_pending("6dcb09b", "ci/build", target_url)
+            .await
+            .unwrap();
+
+        assert_eq!(success.state, crate::models::StatusState::Success);
+        assert_eq!(failure.state, crate::models::StatusState::Failure);
+        assert_eq!(pending.state, crate::models::StatusState::Pending);
+    }
+
+    #[tokio::test]
+    async fn encodes_branch_names() {
+        let server = MockServer::start().await;
+        let combined_status: serde_json::Value =
+            serde_json::from_str(include_str!("../../tests/resources/combined_status.json"))
+                .unwrap();
+        Mock::given(method("GET"))
+            .and(path(
+                "/repos/octocat/Hello-World/commits/feature/foo%20bar%23baz/status",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(combined_status))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let status = crate::setup(&server)
+            .repos("octocat", "Hello-World")
+            .combined_status("feature/foo bar#baz")
+            .await
+            .unwrap();
+
+        assert_eq!(status.total_count, 2);
+    }
+
+    #[tokio::test]
+    async fn code_of_conduct() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/community/code_of_conduct"))
+            .and(header(
+                "accept",
+                "application/vnd.github.scarlet-witch-preview",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "key": "contributor_covenant",
+                "name": "Contributor Covenant",
+                "url": "https://api.github.com/repos/octocat/Hello-World/community/code_of_conduct",
+                "body": "# Contributor Covenant Code of Conduct\n\n## Our Pledge\n",
+                "html_url": "https://github.com/octocat/Hello-World/blob/master/CODE_OF_CONDUCT.md"
+            })))
+            .mount(&server)
+            .await;
+
+        let code_of_conduct = crate::setup(&server)
+            .repos("octocat", "Hello-World")
+            .code_of_conduct()
+            .await
+            .unwrap();
+
+        assert_eq!(code_of_conduct.key, "contributor_covenant");
+        assert_eq!(code_of_conduct.name, "Contributor Covenant");
+        assert!(code_of_conduct
+            .body
+            .unwrap()
+            .starts_with("# Contributor Covenant"));
+    }
+
+    #[tokio::test]
+    async fn download_tarball_follows_redirect() {
+        let server = MockServer::start().await;
+        let codeload = MockServer::start().await;
+        let archive_path = "/octocat/Hello-World/legacy.tar.gz/v1.0.0";
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/tarball/v1.0.0"))
+            .and(header("authorization", "Bearer secret"))
+            .respond_with(
+                ResponseTemplate::new(302)
+                    .insert_header("location", &*format!("{}{}", codeload.uri(), archive_path)),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(archive_path))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(&b"\x1f\x8b archive"[..]))
+            .expect(1)
+            .mount(&codeload)
+            .await;
+
+        let octocrab = crate::Octocrab::builder()
+            .personal_token(String::from("secret"))
+            .base_url(&server.uri())
+            .unwrap()
+            .build()
+            .unwrap();
+        let tarball = octocrab
+            .repos("octocat", "Hello-World")
+            .download_tarball("v1.0.0")
+            .await
+            .unwrap();
+
+        assert_eq!(&tarball[..], &b"\x1f\x8b archive"[..]);
+        let requests = codeload.received_requests().await.unwrap();
+        assert!(!requests[0].headers.contains_key(&"authorization".into()));
+    }
+
+    #[tokio::test]
+    async fn environments() {
+        let server = MockServer::start().await;
+        let environments: serde_json::Value =
+            serde_json::from_str(include_str!("../../tests/resources/environments.json")).unwrap();
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/environments"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(environments))
+            .mount(&server)
+            .await;
+
+        let environments = crate::setup(&server)
+            .repos("octocat", "Hello-World")
+            .environments()
+            .await
+            .unwrap();
+
+        assert_eq!(environments.len(), 2);
+        let production = &environments[0];
+        assert_eq!(production.id, 161088068);
+        assert_eq!(production.name, "production");
+        assert_eq!(production.protection_rules[0].r#type, "wait_timer");
+        assert_eq!(production.protection_rules[0].wait_timer, Some(30));
+        assert!(
+            production
+                .deployment_branch_policy
+                .as_ref()
+                .unwrap()
+                .protected_branches
+        );
+        assert!(environments[1].deployment_branch_policy.is_none());
+    }
+
+    #[tokio::test]
+    async fn create_or_update_environment_secret() {
+        let server = MockServer::start().await;
+        Mock::given(method("PUT"))
+            .and(path(
+                "/repos/octocat/Hello-World/environments/production/secrets/DEPLOY_TOKEN",
+            ))
+            .and(body_json(serde_json::json!({
+                "encrypted_value": "c2VjcmV0",
+                "key_id": "012345678912345678",
+            })))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        crate::setup(&server)
+            .repos("octocat", "Hello-World")
+            .create_or_update_environment_secret(
+                "production",
+                "DEPLOY_TOKEN",
+                "c2VjcmV0",
+                "012345678912345678",
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn list_environment_branch_policies() {
+        let server = MockServer::start().await;
+        let policies: serde_json::Value = serde_json::from_str(include_str!(
+            "../../tests/resources/environment_branch_policies.json"
+        ))
+        .unwrap();
+        Mock::given(method("GET"))
+            .and(path(
+                "/repos/octocat/Hello-World/environments/production/deployment-branch-policies",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(policies))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(
+                "/repos/octocat/Hello-World/environments/staging/deployment-branch-policies",
+            ))
+            .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+                "message": "Must have admin rights to Repository.",
+                "documentation_url": "https://docs.github.com/rest"
+            })))
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let repo = octocrab.repos("octocat", "Hello-World");
+        let page = repo
+            .list_environment_branch_policies("production")
+            .await
+            .unwrap();
+
+        assert_eq!(page.total_count, Some(2));
+        assert_eq!(page.items[0].id, 361471);
+        assert_eq!(page.items[0].name, "release/*");
+        assert_eq!(page.items[1].name, "main");
+
+        let error = repo
+            .list_environment_branch_policies("staging")
+            .await
+            .unwrap_err();
+        assert_eq!(error.status_code(), Some(reqwest::StatusCode::FORBIDDEN));
+    }
+
+    #[tokio::test]
+    async fn list_pulls_for_commit() {
+        let server = MockServer::start().await;
+        let pull_request: serde_json::Value =
+            serde_json::from_str(include_str!("../../tests/resources/pull_request.json")).unwrap();
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/commits/6dcb09b/pulls"))
+            .and(header("accept", "application/vnd.github.groot-preview"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(vec![pull_request]))
+            .mount(&server)
+            .await;
+
+        let page = crate::setup(&server)
+            .repos("octocat", "Hello-World")
+            .list_pulls_for_commit("6dcb09b")
+            .await
+            .unwrap();
+
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.items[0].number, 1347);
+        assert_eq!(page.items[0].head.ref_field, "new-topic");
+    }
+
+    #[tokio::test]
+    async fn list_branches_for_head_commit() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(
+                "/repos/octocat/Hello-World/commits/6dcb09b/branches-where-head",
+            ))
+            .and(header("accept", "application/vnd.github.groot-preview"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {
+                    "name": "branch_5",
+                    "commit": {
+                        "sha": "c5b97d5ae6c19d5c5df71a34c7fbeeda2479ccbc",
+                        "url": "https://api.github.com/repos/octocat/Hello-World/commits/c5b97d5ae6c19d5c5df71a34c7fbeeda2479ccbc"
+                    },
+                    "protected": false
+                }
+            ])))
+            .mount(&server)
+            .await;
+
+        let branches = crate::setup(&server)
+            .repos("octocat", "Hello-World")
+            .list_branches_for_head_commit("6dcb09b")
+            .await
+            .unwrap();
+
+        assert_eq!(branches.len(), 1);
+        assert_eq!(branches[0].name, "branch_5");
+        assert_eq!(
+            branches[0].commit.sha,
+            "c5b97d5ae6c19d5c5df71a34c7fbeeda2479ccbc"
+        );
+        assert!(!branches[0].protected);
+    }
+
+    #[tokio::test]
+    async fn list_check_runs_for_ref() {
+        let server = MockServer::start().await;
+        let check_runs: serde_json::Value =
+            serde_json::from_str(include_str!("../../tests/resources/check_runs.json")).unwrap();
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/commits/master/check-runs"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(check_runs))
+            .mount(&server)
+            .await;
+
+        let page = crate::setup(&server)
+            .repos("octocat", "Hello-World")
+            .list_check_runs_for_ref("master")
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(page.total_count, Some(1));
+        assert_eq!(page.items.len(), 1);
+        let check_run = &page.items[0];
+        assert_eq!(check_run.name.as_deref(), Some("mighty_readme"));
+        assert_eq!(
+            check_run.status,
+            Some(crate::models::CheckStatus::Completed)
+        );
+        assert_eq!(check_run.conclusion.as_deref(), Some("neutral"));
+        assert_eq!(check_run.check_suite.as_ref().unwrap().id, 5);
+        assert_eq!(check_run.pull_requests.as_ref().unwrap()[0].number, 1347);
+    }
+
+    #[tokio::test]
+    async fn list_check_suites_for_ref() {
+        let server = MockServer::start().await;
+        let check_suites: serde_json::Value =
+            serde_json::from_str(include_str!("../../tests/resources/check_suites.json")).unwrap();
+        Mock::given(method("GET"))
+            .and(path(
+                "/repos/octocat/Hello-World/commits/master/check-suites",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(check_suites))
+            .mount(&server)
+            .await;
+
+        let page = crate::setup(&server)
+            .repos("octocat", "Hello-World")
+            .list_check_suites_for_ref("master")
+            .await
+            .unwrap();
+
+        assert_eq!(page.total_count, Some(1));
+        let check_suite = &page.items[0];
+        assert_eq!(check_suite.id, 5);
+        assert_eq!(
+            check_suite.status,
+            Some(crate::models::CheckStatus::Completed)
+        );
+        assert_eq!(check_suite.conclusion.as_deref(), Some("neutral"));
+        assert_eq!(
+            check_suite.head_sha,
+            "d6fde92930d4715a2b49857d24b940956b26d2d3"
+        );
+        assert_eq!(
+            check_suite.app.as_ref().unwrap().slug.as_deref(),
+            Some("octoapp")
+        );
+        assert!(check_suite.pull_requests.as_ref().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn rerequest_check_suite() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/repos/octocat/Hello-World/check-suites/5/rerequest"))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/repos/octocat/Hello-World/check-suites/6/rerequest"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "message": "Not Found",
+                "documentation_url": "https://docs.github.com/rest",
+            })))
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let repo = octocrab.repos("octocat", "Hello-World");
+
+        repo.rerequest_check_suite(5).await.unwrap();
+        assert!(repo.rerequest_check_suite(6).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn vulnerability_alerts_enabled() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/enabled/vulnerability-alerts"))
+            .and(header("accept", "application/vnd.github.dorian-preview"))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/disabled/vulnerability-alerts"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "message": "Vulnerability alerts are disabled.",
+                "documentation_url": "https://docs.github.com/rest",
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/proxied/vulnerability-alerts"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+
+        assert!(octocrab
+            .repos("octocat", "enabled")
+            .vulnerability_alerts_enabled()
+            .await
+            .unwrap());
+        assert!(!octocrab
+            .repos("octocat", "disabled")
+            .vulnerability_alerts_enabled()
+            .await
+            .unwrap());
+        assert!(octocrab
+            .repos("octocat", "proxied")
+            .vulnerability_alerts_enabled()
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn toggle_vulnerability_alerts() {
+        let server = MockServer::start().await;
+        for verb in &["PUT", "DELETE"] {
+            Mock::given(method(*verb))
+                .and(path("/repos/octocat/Hello-World/vulnerability-alerts"))
+                .and(header("accept", "application/vnd.github.dorian-preview"))
+                .respond_with(ResponseTemplate::new(204))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let octocrab = crate::setup(&server);
+        let repo = octocrab.repos("octocat", "Hello-World");
+
+        repo.enable_vulnerability_alerts().await.unwrap();
+        repo.disable_vulnerability_alerts().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn required_status_checks() {
+        let server = MockServer::start().await;
+        let route =
+            "/repos/octocat/Hello-World/branches/release/v1/protection/required_status_checks";
+        Mock::given(method("GET"))
+            .and(path(route))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "url": format!("https://api.github.com{}", route),
+                "strict": true,
+                "contexts": ["continuous-integration/travis-ci"],
+                "contexts_url": format!("https://api.github.com{}/contexts", route),
+                "checks": [{ "context": "continuous-integration/travis-ci", "app_id": null }]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path(format!("{}/contexts", route)))
+            .and(body_json(serde_json::json!({
+                "contexts": ["ci/test", "ci/lint"],
+            })))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!(["ci/test", "ci/lint"])),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let repo = octocrab.repos("octocat", "Hello-World");
+        let checks = repo.get_required_status_checks("release/v1").await.unwrap();
+        assert!(checks.strict);
+        assert_eq!(checks.checks[0].context, "continuous-integration/travis-ci");
+        assert_eq!(checks.checks[0].app_id, None);
+
+        let contexts = repo
+            .replace_required_status_check_contexts(
+                "release/v1",
+                &[String::from("ci/test"), String::from("ci/lint")],
+            )
+            .await
+            .unwrap();
+        assert_eq!(contexts, ["ci/test", "ci/lint"]);
+    }
+
+    #[tokio::test]
+    async fn tag_protection() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/repos/octocat/Hello-World/tags/protection"))
+            .and(body_json(serde_json::json!({ "pattern": "v1.*" })))
+            .respond_with(
+                ResponseTemplate::new(201).set_body_json(
+                    serde_json::json!({ "id": 2, "pattern": "v1.*", "enabled": true }),
+                ),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/repos/octocat/Hello-World/tags/protection/2"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/tags/protection"))
+            .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+                "message": "Must have admin rights to Repository.",
+                "documentation_url": "https://docs.github.com/rest/repos/tags#list-tag-protection-states-for-a-repository"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let repo = octocrab.repos("octocat", "Hello-World");
+
+        let protection = repo.create_tag_protection("v1.*").await.unwrap();
+        assert_eq!(protection.id, 2);
+        repo.delete_tag_protection(protection.id).await.unwrap();
+
+        let error = repo.list_tag_protection().await.unwrap_err();
+        assert_eq!(error.status_code(), Some(reqwest::StatusCode::FORBIDDEN));
+    }
+}
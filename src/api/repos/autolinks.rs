@@ -0,0 +1,172 @@
+use super::*;
+
+/// A client to GitHub's autolink reference API.
+///
+/// Created with [`RepoHandler::autolinks`].
+///
+/// [`RepoHandler::autolinks`]: ../struct.RepoHandler.html#method.autolinks
+pub struct AutolinksHandler<'octo, 'r> {
+    handler: &'r RepoHandler<'octo>,
+}
+
+impl<'octo, 'r> AutolinksHandler<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>) -> Self {
+        Self { handler }
+    }
+
+    /// Lists the autolinks configured for the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .autolinks()
+    ///     .list()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list(&self) -> Result<Page<models::Autolink>> {
+        let route = format!(
+            "/repos/{owner}/{repo}/autolinks",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+        );
+
+        self.handler.crab.get(route, None::<&()>).await
+    }
+
+    /// Creates an autolink, so that references such as `JIRA-123` link to
+    /// `url_template` with `<num>` replaced by the reference number.
+    /// `is_alphanumeric` controls whether the reference can contain letters
+    /// as well as numbers.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let autolink = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .autolinks()
+    ///     .create("JIRA-", "https://jira.example.com/browse/JIRA-<num>", false)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create(
+        &self,
+        key_prefix: impl Into<String>,
+        url_template: impl Into<String>,
+        is_alphanumeric: bool,
+    ) -> CreateAutolinkBuilder<'octo, '_> {
+        CreateAutolinkBuilder::new(
+            self.handler,
+            key_prefix.into(),
+            url_template.into(),
+            is_alphanumeric,
+        )
+    }
+
+    /// Gets a single autolink by its `id`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let autolink = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .autolinks()
+    ///     .get(42)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, id: u64) -> Result<models::Autolink> {
+        let route = format!(
+            "/repos/{owner}/{repo}/autolinks/{id}",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+            id = id,
+        );
+
+        self.handler.crab.get(route, None::<&()>).await
+    }
+
+    /// Deletes a single autolink by its `id`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .autolinks()
+    ///     .delete(42)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(&self, id: u64) -> Result<()> {
+        let route = format!(
+            "/repos/{owner}/{repo}/autolinks/{id}",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+            id = id,
+        );
+
+        let response = self
+            .handler
+            .crab
+            ._delete(self.handler.crab.absolute_url(route)?, None::<&()>)
+            .await?;
+
+        Octocrab::map_no_content(response).await
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct CreateAutolinkBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    key_prefix: String,
+    url_template: String,
+    is_alphanumeric: bool,
+}
+
+impl<'octo, 'r> CreateAutolinkBuilder<'octo, 'r> {
+    pub(crate) fn new(
+        handler: &'r RepoHandler<'octo>,
+        key_prefix: String,
+        url_template: String,
+        is_alphanumeric: bool,
+    ) -> Self {
+        Self {
+            handler,
+            key_prefix,
+            url_template,
+            is_alphanumeric,
+        }
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<models::Autolink> {
+        let route = format!(
+            "/repos/{owner}/{repo}/autolinks",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+        );
+
+        self.handler.crab.post(route, Some(&self)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.repos("owner", "repo");
+        let autolinks = handler.autolinks();
+        let create = autolinks.create("TICKET-", "https://example.com/TICKET?query=<num>", true);
+
+        assert_eq!(
+            serde_json::to_value(create).unwrap(),
+            serde_json::json!({
+                "key_prefix": "TICKET-",
+                "url_template": "https://example.com/TICKET?query=<num>",
+                "is_alphanumeric": true,
+            })
+        )
+    }
+}
@@ -0,0 +1,103 @@
+use super::*;
+
+/// A builder pattern struct for listing a repository's branches.
+///
+/// created by [`RepoHandler::list_branches`]
+///
+/// [`RepoHandler::list_branches`]: ./struct.RepoHandler.html#method.list_branches
+#[derive(serde::Serialize)]
+pub struct ListBranchesBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    protected: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListBranchesBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>) -> Self {
+        Self {
+            handler,
+            protected: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Only list protected branches if `true`, or only unprotected branches
+    /// if `false`. Default: all branches.
+    pub fn protected(mut self, protected: bool) -> Self {
+        self.protected = Some(protected);
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl std::convert::TryInto<i64>) -> Self {
+        self.per_page = Some(crate::page::clamp_per_page(per_page));
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<Page<models::Branch>> {
+        let route = format!(
+            "/repos/{owner}/{repo}/branches",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+        );
+
+        self.handler.crab.get(route, Some(&self)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        matchers::{method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[tokio::test]
+    async fn list_branches() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/branches"))
+            .and(query_param("protected", "true"))
+            .and(query_param("per_page", "100"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                    "name": "master",
+                    "commit": {
+                        "sha": "c5b97d5ae6c19d5c5df71a34c7fbeeda2479ccbc",
+                        "url": "https://api.github.com/repos/octocat/Hello-World/commits/c5b97d5ae6c19d5c5df71a34c7fbeeda2479ccbc"
+                    },
+                    "protected": true
+                }])),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let page = octocrab
+            .repos("octocat", "Hello-World")
+            .list_branches()
+            .protected(true)
+            .per_page(100)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.items[0].name, "master");
+        assert!(page.items[0].protected);
+    }
+}
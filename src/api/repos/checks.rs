@@ -0,0 +1,111 @@
+use super::*;
+
+/// A builder pattern struct for listing the check runs for a ref.
+///
+/// created by [`RepoHandler::list_check_runs_for_ref`]
+///
+/// [`RepoHandler::list_check_runs_for_ref`]: ./struct.RepoHandler.html#method.list_check_runs_for_ref
+#[derive(serde::Serialize)]
+pub struct ListCheckRunsBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    #[serde(skip)]
+    r#ref: String,
+    check_name: Option<String>,
+    status: Option<crate::params::checks::Status>,
+    filter: Option<crate::params::checks::Filter>,
+    per_page: Option<u8>,
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListCheckRunsBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>, r#ref: String) -> Self {
+        Self {
+            handler,
+            r#ref,
+            check_name: None,
+            status: None,
+            filter: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Only return check runs with the name `check_name`.
+    pub fn check_name(mut self, check_name: impl Into<String>) -> Self {
+        self.check_name = Some(check_name.into());
+        self
+    }
+
+    /// Only return check runs with `status`.
+    pub fn status(mut self, status: crate::params::checks::Status) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Whether to return only the most recent check runs (the default) or
+    /// all of them.
+    pub fn filter(mut self, filter: crate::params::checks::Filter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl std::convert::TryInto<i64>) -> Self {
+        self.per_page = Some(crate::page::clamp_per_page(per_page));
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<Page<models::CheckRun>> {
+        let route = format!(
+            "/repos/{owner}/{repo}/commits/{ref}/check-runs",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+            ref = crate::encoding::path(&self.r#ref),
+        );
+
+        self.handler
+            .crab
+            .get_enveloped_page(
+                route,
+                Some(&self),
+                reqwest::header::HeaderMap::new(),
+                "check_runs",
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.repos("rust-lang", "rust");
+        let list = handler
+            .list_check_runs_for_ref("master")
+            .check_name("build")
+            .status(crate::params::checks::Status::InProgress)
+            .filter(crate::params::checks::Filter::All)
+            .per_page(100)
+            .page(1u8);
+
+        assert_eq!(
+            serde_json::to_value(list).unwrap(),
+            serde_json::json!({
+                "check_name": "build",
+                "status": "in_progress",
+                "filter": "all",
+                "per_page": 100,
+                "page": 1,
+            })
+        )
+    }
+}
@@ -0,0 +1,147 @@
+use super::*;
+
+/// A builder pattern struct for listing a repository's collaborators.
+///
+/// created by [`RepoHandler::list_collaborators`]
+///
+/// [`RepoHandler::list_collaborators`]: ./struct.RepoHandler.html#method.list_collaborators
+#[derive(serde::Serialize)]
+pub struct ListCollaboratorsBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    affiliation: Option<crate::params::repos::Affiliation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    permission: Option<crate::params::repos::Permission>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListCollaboratorsBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>) -> Self {
+        Self {
+            handler,
+            affiliation: None,
+            permission: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Filter collaborators by how they're affiliated with the repository.
+    /// Default: `All`.
+    pub fn affiliation(
+        mut self,
+        affiliation: impl Into<crate::params::repos::Affiliation>,
+    ) -> Self {
+        self.affiliation = Some(affiliation.into());
+        self
+    }
+
+    /// Only list collaborators with exactly this permission.
+    pub fn permission(mut self, permission: impl Into<crate::params::repos::Permission>) -> Self {
+        self.permission = Some(permission.into());
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl std::convert::TryInto<i64>) -> Self {
+        self.per_page = Some(crate::page::clamp_per_page(per_page));
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<Page<models::Collaborator>> {
+        let route = format!(
+            "/repos/{owner}/{repo}/collaborators",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+        );
+
+        self.handler.crab.get(route, Some(&self)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::params::repos::{Affiliation, Permission};
+    use wiremock::{
+        matchers::{method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[test]
+    fn serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.repos("rust-lang", "rust");
+        let list = handler
+            .list_collaborators()
+            .affiliation(Affiliation::Outside)
+            .permission(Permission::Maintain)
+            .per_page(100)
+            .page(2u32);
+
+        assert_eq!(
+            serde_json::to_value(list).unwrap(),
+            serde_json::json!({
+                "affiliation": "outside",
+                "permission": "maintain",
+                "per_page": 100,
+                "page": 2,
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(handler.list_collaborators()).unwrap(),
+            serde_json::json!({})
+        );
+    }
+
+    #[tokio::test]
+    async fn list_collaborators() {
+        let server = MockServer::start().await;
+        let mut collaborator = serde_json::from_str::<serde_json::Value>(include_str!(
+            "../../../tests/resources/repository.json"
+        ))
+        .unwrap()["owner"]
+            .clone();
+        collaborator["permissions"] = serde_json::json!({
+            "pull": true,
+            "triage": true,
+            "push": true,
+            "maintain": false,
+            "admin": false
+        });
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/collaborators"))
+            .and(query_param("affiliation", "direct"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(vec![collaborator]))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let page = octocrab
+            .repos("octocat", "Hello-World")
+            .list_collaborators()
+            .affiliation(Affiliation::Direct)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(page.items.len(), 1);
+        let collaborator = &page.items[0];
+        assert_eq!(collaborator.user.login, "octocat");
+        assert!(collaborator.permissions.push);
+        assert!(!collaborator.permissions.admin);
+        assert_eq!(collaborator.permissions.triage, Some(true));
+        assert_eq!(collaborator.permissions.maintain, Some(false));
+    }
+}
@@ -0,0 +1,95 @@
+use super::*;
+
+/// A builder pattern struct for listing the comments on every commit in a
+/// repository.
+///
+/// created by [`RepoHandler::list_commit_comments`]
+///
+/// [`RepoHandler::list_commit_comments`]: ./struct.RepoHandler.html#method.list_commit_comments
+#[derive(serde::Serialize)]
+pub struct ListCommitCommentsBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListCommitCommentsBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>) -> Self {
+        Self {
+            handler,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl std::convert::TryInto<i64>) -> Self {
+        self.per_page = Some(crate::page::clamp_per_page(per_page));
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<Page<models::CommitComment>> {
+        let route = format!(
+            "/repos/{owner}/{repo}/comments",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+        );
+
+        self.handler.crab.get(route, Some(&self)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        matchers::{method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[tokio::test]
+    async fn list_commit_comments() {
+        let server = MockServer::start().await;
+        let comments: serde_json::Value = serde_json::from_str(include_str!(
+            "../../../tests/resources/commit_comments.json"
+        ))
+        .unwrap();
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/comments"))
+            .and(query_param("per_page", "100"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(comments))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let page = octocrab
+            .repos("octocat", "Hello-World")
+            .list_commit_comments()
+            .per_page(100)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(page.items.len(), 2);
+        let comment = &page.items[0];
+        assert_eq!(comment.body, "Great stuff");
+        assert_eq!(comment.path.as_deref(), Some("file1.txt"));
+        assert_eq!(comment.line, Some(14));
+        assert_eq!(
+            comment.commit_id,
+            "6dcb09b5b57875f334f61aebed695e2e4193db5e"
+        );
+        assert_eq!(comment.user.as_ref().unwrap().login, "octocat");
+        assert_eq!(page.items[1].path, None);
+    }
+}
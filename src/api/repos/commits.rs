@@ -0,0 +1,79 @@
+use super::*;
+
+/// A builder pattern struct for getting a single commit.
+///
+/// created by [`RepoHandler::get_commit`]
+///
+/// [`RepoHandler::get_commit`]: ./struct.RepoHandler.html#method.get_commit
+#[derive(serde::Serialize)]
+pub struct GetCommitBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    #[serde(skip)]
+    r#ref: String,
+    per_page: Option<u8>,
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> GetCommitBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>, r#ref: String) -> Self {
+        Self {
+            handler,
+            r#ref,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Only fetch what's needed for the commit's `stats`, rather than every
+    /// file and its patch. This uses the REST API and requests a single file
+    /// per page, since `stats` always covers the whole commit, so `files`
+    /// contains at most one file. This avoids transferring megabytes of patch
+    /// data for large commits, but if you need the files as well, it's
+    /// cheaper to fetch them in the same request.
+    pub fn stats_only(self) -> Self {
+        self.per_page(1u8).page(1u32)
+    }
+
+    /// Files per page (max 100).
+    pub fn per_page(mut self, per_page: impl std::convert::TryInto<i64>) -> Self {
+        self.per_page = Some(crate::page::clamp_per_page(per_page));
+        self
+    }
+
+    /// Page number of the files to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<models::Commit> {
+        let route = format!(
+            "/repos/{owner}/{repo}/commits/{ref}",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+            ref = crate::encoding::path(&self.r#ref),
+        );
+
+        self.handler.crab.get(route, Some(&self)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn serialize_stats_only() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.repos("rust-lang", "rust");
+        let get = handler.get_commit("master").stats_only();
+
+        assert_eq!(
+            serde_json::to_value(get).unwrap(),
+            serde_json::json!({
+                "per_page": 1,
+                "page": 1,
+            })
+        )
+    }
+}
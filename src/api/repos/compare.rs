@@ -0,0 +1,91 @@
+use super::*;
+
+/// A builder pattern struct for comparing two commits.
+///
+/// created by [`RepoHandler::compare`]
+///
+/// [`RepoHandler::compare`]: ./struct.RepoHandler.html#method.compare
+#[derive(serde::Serialize)]
+pub struct CompareCommitsBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    #[serde(skip)]
+    base: String,
+    #[serde(skip)]
+    head: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> CompareCommitsBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>, base: String, head: String) -> Self {
+        Self {
+            handler,
+            base,
+            head,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Commits per page (max 100).
+    pub fn per_page(mut self, per_page: impl std::convert::TryInto<i64>) -> Self {
+        self.per_page = Some(crate::page::clamp_per_page(per_page));
+        self
+    }
+
+    /// Page number of the commits to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request, returning a single page of `commits`.
+    /// Without [`per_page`](#method.per_page) or [`page`](#method.page),
+    /// GitHub returns at most the first 250 commits, check
+    /// [`total_commits`] to tell if any were left out.
+    ///
+    /// [`total_commits`]: ../models/struct.CommitComparison.html#structfield.total_commits
+    pub async fn send(self) -> Result<models::CommitComparison> {
+        self.handler.crab.get(self.route(), Some(&self)).await
+    }
+
+    /// Sends a request for every page of `commits`, starting from
+    /// [`page`](#method.page), and collects them into a single comparison.
+    /// The rest of the comparison, including `files`, comes from the first
+    /// page. *Note* GitHub includes at most 300 `files`, regardless of
+    /// pagination.
+    pub async fn send_all(mut self) -> Result<models::CommitComparison> {
+        let mut page = self.page.unwrap_or(1);
+        self.page = Some(page);
+        let mut comparison: models::CommitComparison =
+            self.handler.crab.get(self.route(), Some(&self)).await?;
+
+        while (comparison.commits.len() as u64) < comparison.total_commits {
+            page += 1;
+            self.page = Some(page);
+            let next: models::CommitComparison =
+                self.handler.crab.get(self.route(), Some(&self)).await?;
+
+            if next.commits.is_empty() {
+                break;
+            }
+
+            comparison.commits.extend(next.commits);
+        }
+
+        Ok(comparison)
+    }
+
+    fn route(&self) -> String {
+        format!(
+            "/repos/{owner}/{repo}/compare/{base}...{head}",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+            base = crate::encoding::path(&self.base),
+            head = crate::encoding::path(&self.head),
+        )
+    }
+}
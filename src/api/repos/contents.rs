@@ -0,0 +1,151 @@
+use snafu::ResultExt;
+
+use super::*;
+
+/// A builder pattern struct for fetching the contents of a file.
+///
+/// created by [`RepoHandler::get_content`]
+///
+/// [`RepoHandler::get_content`]: ./struct.RepoHandler.html#method.get_content
+#[derive(serde::Serialize)]
+pub struct GetContentBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    #[serde(skip)]
+    path: String,
+    #[serde(rename = "ref")]
+    r#ref: Option<String>,
+}
+
+impl<'octo, 'r> GetContentBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>, path: String) -> Self {
+        Self {
+            handler,
+            path,
+            r#ref: None,
+        }
+    }
+
+    /// The name of the commit, branch, or tag to get the file from.
+    /// Default: the repository's default branch.
+    pub fn r#ref(mut self, r#ref: impl Into<String>) -> Self {
+        self.r#ref = Some(r#ref.into());
+        self
+    }
+
+    /// Sends the actual request. Fails if `path` is a directory, use
+    /// [`send_any`](#method.send_any) if it can be either.
+    pub async fn send(self) -> Result<models::Contents> {
+        let path = self.path.clone();
+
+        match self.send_any().await? {
+            models::ContentResponse::File(contents) => Ok(*contents),
+            _ => Err(format!("`{}` is a directory, not a file", path).into())
+                .context(crate::error::Other),
+        }
+    }
+
+    /// Sends the actual request, for a `path` that can be either a file or a
+    /// directory.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::ContentResponse;
+    ///
+    /// match octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .get_content("src")
+    ///     .send_any()
+    ///     .await?
+    /// {
+    ///     ContentResponse::File(file) => println!("{} bytes", file.size),
+    ///     ContentResponse::Dir(entries) => {
+    ///         for entry in entries {
+    ///             println!("{} ({})", entry.path, entry.entry_type);
+    ///         }
+    ///     }
+    ///     _ => {}
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_any(self) -> Result<models::ContentResponse> {
+        let route = self.contents_route(&self.path);
+
+        let contents = match self.handler.crab.get(route, Some(&self)).await {
+            Ok(serde_json::Value::Array(entries)) => {
+                let entries = serde_json::Value::Array(entries);
+                return serde_json::from_value(entries.clone())
+                    .map(models::ContentResponse::Dir)
+                    .context(crate::error::Json { json: entries });
+            }
+            Ok(contents) => serde_json::from_value(contents.clone())
+                .context(crate::error::Json { json: contents })?,
+            // Older versions of GitHub refuse files over 1 MB entirely, in
+            // which case the file's SHA has to be found in its parent directory.
+            Err(crate::Error::GitHub { source, .. }) if is_too_large(&source) => {
+                let contents = self.find_in_parent().await?;
+                return self
+                    .with_blob_content(contents)
+                    .await
+                    .map(|contents| models::ContentResponse::File(Box::new(contents)));
+            }
+            Err(error) => return Err(error),
+        };
+
+        // Files between 1 MB and 100 MB are returned without their content.
+        if is_missing_content(&contents) {
+            self.with_blob_content(contents)
+                .await
+                .map(|contents| models::ContentResponse::File(Box::new(contents)))
+        } else {
+            Ok(models::ContentResponse::File(Box::new(contents)))
+        }
+    }
+
+    fn contents_route(&self, path: &str) -> String {
+        format!(
+            "/repos/{owner}/{repo}/contents/{path}",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+            path = crate::encoding::path(path),
+        )
+    }
+
+    async fn find_in_parent(&self) -> Result<models::Contents> {
+        let path = self.path.trim_matches('/');
+        let parent = path.rsplit_once('/').map_or("", |(parent, _)| parent);
+        let entries: Vec<serde_json::Value> = self
+            .handler
+            .crab
+            .get(self.contents_route(parent), Some(&self))
+            .await?;
+
+        entries
+            .into_iter()
+            .find(|entry| entry["path"] == path)
+            .map(|mut entry| {
+                // Directory listings don't include the content of files.
+                entry["content"] = serde_json::Value::from("");
+                entry["encoding"] = serde_json::Value::from("none");
+                serde_json::from_value(entry.clone()).context(crate::error::Json { json: entry })
+            })
+            .ok_or_else(|| format!("`{}` was not found in its parent directory", path).into())
+            .context(crate::error::Other)?
+    }
+
+    async fn with_blob_content(&self, mut contents: models::Contents) -> Result<models::Contents> {
+        let blob = self.handler.get_blob(&contents.sha).await?;
+        contents.content = blob.content;
+        contents.encoding = blob.encoding;
+
+        Ok(contents)
+    }
+}
+
+fn is_missing_content(contents: &models::Contents) -> bool {
+    contents.encoding == "none" && contents.content.is_empty()
+}
+
+fn is_too_large(error: &crate::GitHubError) -> bool {
+    error.message.contains("too_large") || error.message.contains("too large")
+}
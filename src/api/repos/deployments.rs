@@ -0,0 +1,169 @@
+use super::*;
+
+/// A client to GitHub's deployments API.
+///
+/// Created with [`RepoHandler::deployments`].
+///
+/// [`RepoHandler::deployments`]: ./struct.RepoHandler.html#method.deployments
+pub struct DeploymentsHandler<'octo, 'r> {
+    handler: &'r RepoHandler<'octo>,
+}
+
+impl<'octo, 'r> DeploymentsHandler<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>) -> Self {
+        Self { handler }
+    }
+
+    /// Lists the repository's deployments, most recent first.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// // Get the latest deployment to production.
+    /// let page = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .deployments()
+    ///     .list()
+    ///     .environment("production")
+    ///     .per_page(1)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListDeploymentsBuilder<'octo, '_> {
+        ListDeploymentsBuilder::new(self.handler)
+    }
+
+    /// Gets the deployment with the given `id`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let deployment = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .deployments()
+    ///     .get(1)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, id: u64) -> Result<models::Deployment> {
+        let route = format!(
+            "/repos/{owner}/{repo}/deployments/{id}",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+            id = id,
+        );
+
+        self.handler.crab.get(route, None::<&()>).await
+    }
+}
+
+/// A builder pattern struct for listing deployments.
+///
+/// created by [`DeploymentsHandler::list`]
+///
+/// [`DeploymentsHandler::list`]: ./struct.DeploymentsHandler.html#method.list
+#[derive(serde::Serialize)]
+pub struct ListDeploymentsBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    sha: Option<String>,
+    #[serde(rename = "ref")]
+    r#ref: Option<String>,
+    task: Option<String>,
+    environment: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListDeploymentsBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>) -> Self {
+        Self {
+            handler,
+            sha: None,
+            r#ref: None,
+            task: None,
+            environment: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Only list deployments of the commit `sha`.
+    pub fn sha(mut self, sha: impl Into<String>) -> Self {
+        self.sha = Some(sha.into());
+        self
+    }
+
+    /// Only list deployments of `ref`, which can be a branch name, a tag
+    /// name, or a commit SHA.
+    pub fn ref_(mut self, r#ref: impl Into<String>) -> Self {
+        self.r#ref = Some(r#ref.into());
+        self
+    }
+
+    /// Only list deployments with the task `task`, e.g. `deploy` or
+    /// `deploy:migrations`.
+    pub fn task(mut self, task: impl Into<String>) -> Self {
+        self.task = Some(task.into());
+        self
+    }
+
+    /// Only list deployments to `environment`, e.g. `staging` or
+    /// `production`.
+    pub fn environment(mut self, environment: impl Into<String>) -> Self {
+        self.environment = Some(environment.into());
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl std::convert::TryInto<i64>) -> Self {
+        self.per_page = Some(crate::page::clamp_per_page(per_page));
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<Page<models::Deployment>> {
+        let route = format!(
+            "/repos/{owner}/{repo}/deployments",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+        );
+
+        self.handler.crab.get(route, Some(&self)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.repos("rust-lang", "rust");
+        let deployments = handler.deployments();
+        let list = deployments
+            .list()
+            .sha("6dcb09b5b57875f334f61aebed695e2e4193db5e")
+            .ref_("master")
+            .task("deploy")
+            .environment("production")
+            .per_page(1)
+            .page(2u8);
+
+        assert_eq!(
+            serde_json::to_value(list).unwrap(),
+            serde_json::json!({
+                "sha": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+                "ref": "master",
+                "task": "deploy",
+                "environment": "production",
+                "per_page": 1,
+                "page": 2,
+            })
+        )
+    }
+}
@@ -0,0 +1,150 @@
+use super::*;
+
+/// A builder pattern struct for creating a new repository from a template
+/// repository.
+///
+/// created by [`RepoHandler::generate_from_template`]
+///
+/// *Note* This sets the `baptiste` preview automatically.
+///
+/// [`RepoHandler::generate_from_template`]: ./struct.RepoHandler.html#method.generate_from_template
+#[derive(serde::Serialize)]
+pub struct GenerateRepoBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    private: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    include_all_branches: Option<bool>,
+}
+
+impl<'octo, 'r> GenerateRepoBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>, name: String) -> Self {
+        Self {
+            handler,
+            name,
+            owner: None,
+            description: None,
+            private: None,
+            include_all_branches: None,
+        }
+    }
+
+    /// The name of the new repository.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// The user or organization that owns the new repository. Default: the
+    /// authenticated user.
+    pub fn owner(mut self, owner: impl Into<String>) -> Self {
+        self.owner = Some(owner.into());
+        self
+    }
+
+    /// A short description of the new repository.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Whether the new repository is private. Default: `false`.
+    pub fn private(mut self, private: bool) -> Self {
+        self.private = Some(private);
+        self
+    }
+
+    /// Whether to copy every branch of the template, rather than only its
+    /// default branch. Default: `false`.
+    pub fn include_all_branches(mut self, include_all_branches: bool) -> Self {
+        self.include_all_branches = Some(include_all_branches);
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<models::Repository> {
+        let route = format!(
+            "/repos/{owner}/{repo}/generate",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+        );
+        let request = self
+            .handler
+            .crab
+            .client
+            .post(self.handler.crab.absolute_url(route)?)
+            .headers(crate::preview_headers("baptiste"))
+            .json(&self);
+        let response = self.handler.crab.execute(request).await?;
+
+        crate::FromResponse::from_response(Octocrab::map_github_error(response).await?).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        matchers::{header, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[test]
+    fn serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.repos("octocat", "template");
+        let generate = handler
+            .generate_from_template("Hello-World")
+            .owner("octo-org")
+            .description("This is your first repository")
+            .private(true)
+            .include_all_branches(false);
+
+        assert_eq!(
+            serde_json::to_value(generate).unwrap(),
+            serde_json::json!({
+                "name": "Hello-World",
+                "owner": "octo-org",
+                "description": "This is your first repository",
+                "private": true,
+                "include_all_branches": false,
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(handler.generate_from_template("Hello-World")).unwrap(),
+            serde_json::json!({ "name": "Hello-World" })
+        );
+    }
+
+    #[tokio::test]
+    async fn not_a_template() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/repos/octocat/Hello-World/generate"))
+            .and(header("accept", "application/vnd.github.baptiste-preview"))
+            .respond_with(ResponseTemplate::new(422).set_body_json(serde_json::json!({
+                "message": "octocat/Hello-World is not a template repository",
+                "documentation_url": "https://docs.github.com/rest/reference/repos#create-a-repository-using-a-template"
+            })))
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let error = octocrab
+            .repos("octocat", "Hello-World")
+            .generate_from_template("new-repo")
+            .send()
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            error.status_code(),
+            Some(reqwest::StatusCode::UNPROCESSABLE_ENTITY)
+        );
+    }
+}
@@ -0,0 +1,491 @@
+use super::*;
+
+/// A client to GitHub's repository webhook API.
+///
+/// Created with [`RepoHandler::hooks`].
+///
+/// [`RepoHandler::hooks`]: ../struct.RepoHandler.html#method.hooks
+pub struct HooksHandler<'octo, 'r> {
+    handler: &'r RepoHandler<'octo>,
+}
+
+impl<'octo, 'r> HooksHandler<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>) -> Self {
+        Self { handler }
+    }
+
+    /// Lists the webhooks configured for the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .hooks()
+    ///     .list()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list(&self) -> Result<Page<models::Hook>> {
+        self.handler.crab.get(self.route(None), None::<&()>).await
+    }
+
+    /// Creates a webhook delivering payloads to `url`. By default the hook
+    /// is active, and is only triggered by `push` events.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::repos::HookContentType;
+    ///
+    /// let hook = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .hooks()
+    ///     .create("https://example.com/webhook")
+    ///     // Optional Parameters
+    ///     .content_type(HookContentType::Json)
+    ///     .secret("hunter2")
+    ///     .events(vec![String::from("push"), String::from("pull_request")])
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create(&self, url: impl Into<String>) -> CreateHookBuilder<'octo, '_> {
+        CreateHookBuilder::new(self.handler, url.into())
+    }
+
+    /// Gets a single webhook by its `id`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let hook = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .hooks()
+    ///     .get(42)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, id: u64) -> Result<models::Hook> {
+        self.handler
+            .crab
+            .get(self.route(Some(id)), None::<&()>)
+            .await
+    }
+
+    /// Updates a single webhook by its `id`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let hook = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .hooks()
+    ///     .update(42)
+    ///     .active(false)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self, id: u64) -> UpdateHookBuilder<'octo, '_> {
+        UpdateHookBuilder::new(self.handler, id)
+    }
+
+    /// Deletes a single webhook by its `id`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .hooks()
+    ///     .delete(42)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(&self, id: u64) -> Result<()> {
+        let response = self
+            .handler
+            .crab
+            ._delete(
+                self.handler.crab.absolute_url(self.route(Some(id)))?,
+                None::<&()>,
+            )
+            .await?;
+
+        Octocrab::map_no_content(response).await
+    }
+
+    /// Sends a `ping` event to the webhook with the given `id`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .hooks()
+    ///     .ping(42)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn ping(&self, id: u64) -> Result<()> {
+        let route = format!("{}/pings", self.route(Some(id)));
+        let response = self
+            .handler
+            .crab
+            ._post(self.handler.crab.absolute_url(route)?, None::<&()>)
+            .await?;
+
+        Octocrab::map_no_content(response).await
+    }
+
+    /// Triggers the webhook with the given `id` with the latest push to the
+    /// repository, if it's subscribed to `push` events.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .hooks()
+    ///     .test_push(42)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn test_push(&self, id: u64) -> Result<()> {
+        let route = format!("{}/tests", self.route(Some(id)));
+        let response = self
+            .handler
+            .crab
+            ._post(self.handler.crab.absolute_url(route)?, None::<&()>)
+            .await?;
+
+        Octocrab::map_no_content(response).await
+    }
+
+    /// Lists the recent deliveries of the webhook with the given `hook_id`.
+    /// The request and response of each delivery are only included by
+    /// [`get_delivery`](#method.get_delivery).
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .hooks()
+    ///     .list_deliveries(42)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_deliveries(&self, hook_id: u64) -> Result<Page<models::HookDelivery>> {
+        let route = format!("{}/deliveries", self.route(Some(hook_id)));
+
+        self.handler.crab.get(route, None::<&()>).await
+    }
+
+    /// Gets a single delivery of a webhook, including the request that was
+    /// sent and the response that was received.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let delivery = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .hooks()
+    ///     .get_delivery(42, 12345678)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_delivery(
+        &self,
+        hook_id: u64,
+        delivery_id: u64,
+    ) -> Result<models::HookDelivery> {
+        let route = format!(
+            "{}/deliveries/{delivery_id}",
+            self.route(Some(hook_id)),
+            delivery_id = delivery_id,
+        );
+
+        self.handler.crab.get(route, None::<&()>).await
+    }
+
+    /// Redelivers a delivery of a webhook. The new attempt is queued, and
+    /// shows up in [`list_deliveries`](#method.list_deliveries) once made.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .hooks()
+    ///     .redeliver(42, 12345678)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn redeliver(&self, hook_id: u64, delivery_id: u64) -> Result<()> {
+        let route = format!(
+            "{}/deliveries/{delivery_id}/attempts",
+            self.route(Some(hook_id)),
+            delivery_id = delivery_id,
+        );
+        let response = self
+            .handler
+            .crab
+            ._post(self.handler.crab.absolute_url(route)?, None::<&()>)
+            .await?;
+
+        if response.status() == 202 {
+            Ok(())
+        } else {
+            Octocrab::map_github_error(response).await.map(drop)
+        }
+    }
+
+    fn route(&self, id: Option<u64>) -> String {
+        let route = format!(
+            "/repos/{owner}/{repo}/hooks",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+        );
+
+        match id {
+            Some(id) => format!("{}/{}", route, id),
+            None => route,
+        }
+    }
+}
+
+#[derive(Default, serde::Serialize)]
+struct Config {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_type: Option<crate::params::repos::HookContentType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    secret: Option<String>,
+}
+
+impl Config {
+    fn is_empty(&self) -> bool {
+        self.url.is_none() && self.content_type.is_none() && self.secret.is_none()
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct CreateHookBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    name: &'static str,
+    config: Config,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    events: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    active: Option<bool>,
+}
+
+impl<'octo, 'r> CreateHookBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>, url: String) -> Self {
+        Self {
+            handler,
+            name: "web",
+            config: Config {
+                url: Some(url),
+                ..Config::default()
+            },
+            events: None,
+            active: None,
+        }
+    }
+
+    /// The URL payloads are delivered to.
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.config.url = Some(url.into());
+        self
+    }
+
+    /// The media type used to serialize the payloads. Default: `Form`.
+    pub fn content_type(mut self, content_type: crate::params::repos::HookContentType) -> Self {
+        self.config.content_type = Some(content_type);
+        self
+    }
+
+    /// The key used to sign the payloads, sent in the
+    /// `X-Hub-Signature-256` header.
+    pub fn secret(mut self, secret: impl Into<String>) -> Self {
+        self.config.secret = Some(secret.into());
+        self
+    }
+
+    /// The events the hook is triggered for. Default: `["push"]`.
+    pub fn events(mut self, events: impl Into<Vec<String>>) -> Self {
+        self.events = Some(events.into());
+        self
+    }
+
+    /// Whether payloads are delivered when the hook is triggered. Default:
+    /// `true`.
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = Some(active);
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<models::Hook> {
+        let route = format!(
+            "/repos/{owner}/{repo}/hooks",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+        );
+
+        self.handler.crab.post(route, Some(&self)).await
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct UpdateHookBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    #[serde(skip)]
+    id: u64,
+    #[serde(skip_serializing_if = "Config::is_empty")]
+    config: Config,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    events: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    active: Option<bool>,
+}
+
+impl<'octo, 'r> UpdateHookBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>, id: u64) -> Self {
+        Self {
+            handler,
+            id,
+            config: Config::default(),
+            events: None,
+            active: None,
+        }
+    }
+
+    /// The URL payloads are delivered to.
+    ///
+    /// *Note* GitHub replaces the whole configuration when any part of it is
+    /// updated, so the URL must be set whenever the content type or secret
+    /// are.
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.config.url = Some(url.into());
+        self
+    }
+
+    /// The media type used to serialize the payloads.
+    pub fn content_type(mut self, content_type: crate::params::repos::HookContentType) -> Self {
+        self.config.content_type = Some(content_type);
+        self
+    }
+
+    /// The key used to sign the payloads.
+    pub fn secret(mut self, secret: impl Into<String>) -> Self {
+        self.config.secret = Some(secret.into());
+        self
+    }
+
+    /// The events the hook is triggered for, replacing the current ones.
+    pub fn events(mut self, events: impl Into<Vec<String>>) -> Self {
+        self.events = Some(events.into());
+        self
+    }
+
+    /// Whether payloads are delivered when the hook is triggered.
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = Some(active);
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<models::Hook> {
+        let route = format!(
+            "/repos/{owner}/{repo}/hooks/{id}",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+            id = self.id,
+        );
+
+        self.handler.crab.patch(route, Some(&self)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::params::repos::HookContentType;
+
+    #[test]
+    fn serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.repos("owner", "repo");
+        let hooks = handler.hooks();
+        let create = hooks
+            .create("https://example.com/webhook")
+            .content_type(HookContentType::Json)
+            .secret("hunter2")
+            .events(vec![String::from("push"), String::from("pull_request")])
+            .active(true);
+
+        assert_eq!(
+            serde_json::to_value(create).unwrap(),
+            serde_json::json!({
+                "name": "web",
+                "config": {
+                    "url": "https://example.com/webhook",
+                    "content_type": "json",
+                    "secret": "hunter2",
+                },
+                "events": ["push", "pull_request"],
+                "active": true,
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(hooks.create("https://example.com/webhook")).unwrap(),
+            serde_json::json!({
+                "name": "web",
+                "config": { "url": "https://example.com/webhook" },
+            })
+        );
+    }
+
+    #[test]
+    fn serialize_update() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.repos("owner", "repo");
+        let hooks = handler.hooks();
+
+        assert_eq!(
+            serde_json::to_value(hooks.update(1).active(false)).unwrap(),
+            serde_json::json!({ "active": false })
+        );
+        assert_eq!(
+            serde_json::to_value(hooks.update(1).url("https://example.com/new")).unwrap(),
+            serde_json::json!({ "config": { "url": "https://example.com/new" } })
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let hook: crate::models::Hook = serde_json::from_value(serde_json::json!({
+            "type": "Repository",
+            "id": 12345678,
+            "name": "web",
+            "active": true,
+            "events": ["push", "pull_request"],
+            "config": {
+                "content_type": "json",
+                "insecure_ssl": "0",
+                "secret": "********",
+                "url": "https://example.com/webhook"
+            },
+            "updated_at": "2019-06-03T00:57:16Z",
+            "created_at": "2019-06-03T00:57:16Z",
+            "url": "https://api.github.com/repos/octocat/Hello-World/hooks/12345678",
+            "test_url": "https://api.github.com/repos/octocat/Hello-World/hooks/12345678/test",
+            "ping_url": "https://api.github.com/repos/octocat/Hello-World/hooks/12345678/pings",
+            "last_response": { "code": null, "status": "unused", "message": null }
+        }))
+        .unwrap();
+
+        assert_eq!(hook.id, 12345678);
+        assert_eq!(hook.config.content_type.as_deref(), Some("json"));
+        assert_eq!(
+            hook.config.url.as_deref(),
+            Some("https://example.com/webhook")
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use super::*;
+
+/// A builder pattern struct for listing the issue events of a repository.
+///
+/// created by [`RepoHandler::list_issue_events`]
+///
+/// [`RepoHandler::list_issue_events`]: ./struct.RepoHandler.html#method.list_issue_events
+#[derive(serde::Serialize)]
+pub struct ListIssueEventsBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListIssueEventsBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>) -> Self {
+        Self {
+            handler,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl std::convert::TryInto<i64>) -> Self {
+        self.per_page = Some(crate::page::clamp_per_page(per_page));
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<Page<models::IssueEvent>> {
+        let route = format!(
+            "/repos/{owner}/{repo}/issues/events",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+        );
+
+        self.handler.crab.get(route, Some(&self)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        matchers::{method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[tokio::test]
+    async fn list_issue_events() {
+        let server = MockServer::start().await;
+        let events: serde_json::Value =
+            serde_json::from_str(include_str!("../../../tests/resources/issue_events.json"))
+                .unwrap();
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/issues/events"))
+            .and(query_param("per_page", "100"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(events))
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let page = octocrab
+            .repos("octocat", "Hello-World")
+            .list_issue_events()
+            .per_page(100)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.items[0].id, Some(6430295168));
+        assert_eq!(page.items[0].event, Some(crate::models::Event::Closed));
+        assert_eq!(page.items[1].event, Some(crate::models::Event::Labeled));
+        assert_eq!(page.items[1].issue.as_ref().unwrap().number, 42);
+    }
+}
@@ -0,0 +1,110 @@
+use super::*;
+
+/// A builder pattern struct for fetching a README.
+///
+/// created by [`RepoHandler::get_readme`] and [`RepoHandler::get_readme_in`]
+///
+/// [`RepoHandler::get_readme`]: ./struct.RepoHandler.html#method.get_readme
+/// [`RepoHandler::get_readme_in`]: ./struct.RepoHandler.html#method.get_readme_in
+#[derive(serde::Serialize)]
+pub struct GetReadmeBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    #[serde(skip)]
+    dir: Option<String>,
+    #[serde(rename = "ref")]
+    r#ref: Option<String>,
+}
+
+impl<'octo, 'r> GetReadmeBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>, dir: Option<String>) -> Self {
+        Self {
+            handler,
+            dir,
+            r#ref: None,
+        }
+    }
+
+    /// The name of the commit, branch, or tag to get the README from.
+    /// Default: the repository's default branch.
+    pub fn r#ref(mut self, r#ref: impl Into<String>) -> Self {
+        self.r#ref = Some(r#ref.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<models::Contents> {
+        let mut route = format!(
+            "/repos/{owner}/{repo}/readme",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+        );
+
+        if let Some(dir) = self.dir.as_deref().map(|dir| dir.trim_matches('/')) {
+            route.push('/');
+            route.push_str(&crate::encoding::path(dir));
+        }
+
+        self.handler.crab.get(route, Some(&self)).await
+    }
+
+    /// Sends the actual request, returning `None` if there is no README.
+    pub async fn send_opt(self) -> Result<Option<models::Contents>> {
+        match self.send().await {
+            Ok(readme) => Ok(Some(readme)),
+            Err(error) if error.is_not_found() => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        matchers::{method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[tokio::test]
+    async fn get_readme_in() {
+        let server = MockServer::start().await;
+        let readme: serde_json::Value =
+            serde_json::from_str(include_str!("../../../tests/resources/readme.json")).unwrap();
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/readme/packages/parser"))
+            .and(query_param("ref", "v1.0.0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(readme))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/readme/packages/lexer"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "message": "Not Found",
+                "documentation_url": "https://docs.github.com/rest/repos/contents#get-a-repository-readme-for-a-directory"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let repo = octocrab.repos("octocat", "Hello-World");
+
+        let readme = repo
+            .get_readme_in("packages/parser/")
+            .r#ref("v1.0.0")
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(readme.name, "README.md");
+        assert_eq!(readme.path, "packages/parser/README.md");
+        assert_eq!(readme.encoding, "base64");
+
+        assert!(repo
+            .get_readme_in("packages/lexer")
+            .send_opt()
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
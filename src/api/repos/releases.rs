@@ -0,0 +1,106 @@
+use super::*;
+
+/// A builder pattern struct for listing a repository's releases.
+///
+/// created by [`RepoHandler::list_releases`]
+///
+/// [`RepoHandler::list_releases`]: ./struct.RepoHandler.html#method.list_releases
+#[derive(serde::Serialize)]
+pub struct ListReleasesBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    #[serde(skip)]
+    include_drafts: bool,
+    per_page: Option<u8>,
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListReleasesBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>) -> Self {
+        Self {
+            handler,
+            include_drafts: false,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Whether to include draft releases. Default: `false`, only published
+    /// releases are returned.
+    ///
+    /// *Note* GitHub only returns drafts to users with push access to the
+    /// repository, and doesn't support filtering them, so drafts are removed
+    /// after each page has been received. Pages may therefore contain fewer
+    /// than `per_page` releases.
+    pub fn include_drafts(mut self, include_drafts: bool) -> Self {
+        self.include_drafts = include_drafts;
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl std::convert::TryInto<i64>) -> Self {
+        self.per_page = Some(crate::page::clamp_per_page(per_page));
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<Page<models::Release>> {
+        let route = format!(
+            "/repos/{owner}/{repo}/releases",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+        );
+        let mut page: Page<models::Release> = self.handler.crab.get(route, Some(&self)).await?;
+
+        if !self.include_drafts {
+            page.items.retain(|release| !release.draft);
+        }
+
+        Ok(page)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[tokio::test]
+    async fn include_drafts() {
+        let server = MockServer::start().await;
+        let releases: serde_json::Value =
+            serde_json::from_str(include_str!("../../../tests/resources/releases.json")).unwrap();
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/releases"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(releases))
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let repo = octocrab.repos("octocat", "Hello-World");
+        let tags = |page: crate::Page<crate::models::Release>| {
+            page.into_iter()
+                .map(|release| release.tag_name)
+                .collect::<Vec<_>>()
+        };
+
+        let published = repo.list_releases().send().await.unwrap();
+        assert_eq!(tags(published), ["v1.1.0", "v1.0.0"]);
+
+        let all = repo
+            .list_releases()
+            .include_drafts(true)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(tags(all), ["v2.0.0", "v1.1.0", "v1.0.0"]);
+    }
+}
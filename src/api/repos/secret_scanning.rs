@@ -0,0 +1,304 @@
+use super::*;
+
+/// A client to GitHub's secret scanning API.
+///
+/// *Note* This requires the `repo` or `security_events` scope, or else GitHub
+/// responds with `403 Forbidden`, which is returned as an
+/// [`Error::GitHub`](../enum.Error.html#variant.GitHub) error.
+///
+/// Created with [`RepoHandler::secret_scanning_alerts`].
+///
+/// [`RepoHandler::secret_scanning_alerts`]: ./struct.RepoHandler.html#method.secret_scanning_alerts
+pub struct SecretScanningAlertsHandler<'octo, 'r> {
+    handler: &'r RepoHandler<'octo>,
+}
+
+impl<'octo, 'r> SecretScanningAlertsHandler<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>) -> Self {
+        Self { handler }
+    }
+
+    /// Lists the repository's secret scanning alerts.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::SecretScanningAlertState;
+    ///
+    /// let page = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .secret_scanning_alerts()
+    ///     .list()
+    ///     .state(SecretScanningAlertState::Open)
+    ///     .secret_type("github_personal_access_token")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListSecretScanningAlertsBuilder<'octo, '_> {
+        ListSecretScanningAlertsBuilder::new(self.handler)
+    }
+
+    /// Gets the secret scanning alert with the given `number`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let alert = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .secret_scanning_alerts()
+    ///     .get(42)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, number: u64) -> Result<models::SecretScanningAlert> {
+        self.handler
+            .crab
+            .get(route(self.handler, number), None::<&()>)
+            .await
+    }
+
+    /// Updates the secret scanning alert with the given `number`, e.g. to
+    /// resolve it.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::{SecretScanningAlertState, SecretScanningResolution};
+    ///
+    /// let alert = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .secret_scanning_alerts()
+    ///     .update(42)
+    ///     .state(SecretScanningAlertState::Resolved)
+    ///     .resolution(SecretScanningResolution::Revoked)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self, number: u64) -> UpdateSecretScanningAlertBuilder<'octo, '_> {
+        UpdateSecretScanningAlertBuilder::new(self.handler, number)
+    }
+}
+
+fn route(handler: &RepoHandler<'_>, number: u64) -> String {
+    format!(
+        "/repos/{owner}/{repo}/secret-scanning/alerts/{number}",
+        owner = handler.owner,
+        repo = handler.repo,
+        number = number,
+    )
+}
+
+/// A builder pattern struct for listing secret scanning alerts.
+///
+/// created by [`SecretScanningAlertsHandler::list`]
+///
+/// [`SecretScanningAlertsHandler::list`]: ./struct.SecretScanningAlertsHandler.html#method.list
+#[derive(serde::Serialize)]
+pub struct ListSecretScanningAlertsBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<models::SecretScanningAlertState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    secret_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resolution: Option<models::SecretScanningResolution>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListSecretScanningAlertsBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>) -> Self {
+        Self {
+            handler,
+            state: None,
+            secret_type: None,
+            resolution: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Filter alerts by `state`.
+    pub fn state(mut self, state: impl Into<models::SecretScanningAlertState>) -> Self {
+        self.state = Some(state.into());
+        self
+    }
+
+    /// Filter alerts by the type of secret, e.g. `github_personal_access_token`.
+    /// Multiple types can be given as a comma separated list.
+    pub fn secret_type(mut self, secret_type: impl Into<String>) -> Self {
+        self.secret_type = Some(secret_type.into());
+        self
+    }
+
+    /// Filter resolved alerts by their `resolution`.
+    pub fn resolution(mut self, resolution: impl Into<models::SecretScanningResolution>) -> Self {
+        self.resolution = Some(resolution.into());
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl std::convert::TryInto<i64>) -> Self {
+        self.per_page = Some(crate::page::clamp_per_page(per_page));
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<Page<models::SecretScanningAlert>> {
+        let route = format!(
+            "/repos/{owner}/{repo}/secret-scanning/alerts",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+        );
+
+        self.handler.crab.get(route, Some(&self)).await
+    }
+}
+
+/// A builder pattern struct for updating a secret scanning alert.
+///
+/// created by [`SecretScanningAlertsHandler::update`]
+///
+/// [`SecretScanningAlertsHandler::update`]: ./struct.SecretScanningAlertsHandler.html#method.update
+#[derive(serde::Serialize)]
+pub struct UpdateSecretScanningAlertBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    #[serde(skip)]
+    number: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<models::SecretScanningAlertState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resolution: Option<models::SecretScanningResolution>,
+}
+
+impl<'octo, 'r> UpdateSecretScanningAlertBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>, number: u64) -> Self {
+        Self {
+            handler,
+            number,
+            state: None,
+            resolution: None,
+        }
+    }
+
+    /// The new state of the alert.
+    pub fn state(mut self, state: impl Into<models::SecretScanningAlertState>) -> Self {
+        self.state = Some(state.into());
+        self
+    }
+
+    /// Why the alert was resolved, required when setting the state to
+    /// `resolved`.
+    pub fn resolution(mut self, resolution: impl Into<models::SecretScanningResolution>) -> Self {
+        self.resolution = Some(resolution.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<models::SecretScanningAlert> {
+        self.handler
+            .crab
+            .patch(route(self.handler, self.number), Some(&self))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        matchers::{method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[test]
+    fn serialize_update() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.repos("octocat", "Hello-World");
+        let alerts = handler.secret_scanning_alerts();
+        let update = alerts
+            .update(42)
+            .state(crate::models::SecretScanningAlertState::Resolved)
+            .resolution(crate::models::SecretScanningResolution::UsedInTests);
+
+        assert_eq!(
+            serde_json::to_value(update).unwrap(),
+            serde_json::json!({
+                "state": "resolved",
+                "resolution": "used_in_tests",
+            })
+        )
+    }
+
+    #[tokio::test]
+    async fn list() {
+        let server = MockServer::start().await;
+        let alerts: serde_json::Value = serde_json::from_str(include_str!(
+            "../../../tests/resources/secret_scanning_alerts.json"
+        ))
+        .unwrap();
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/secret-scanning/alerts"))
+            .and(query_param("state", "resolved"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(alerts))
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let page = octocrab
+            .repos("octocat", "Hello-World")
+            .secret_scanning_alerts()
+            .list()
+            .state(crate::models::SecretScanningAlertState::Resolved)
+            .send()
+            .await
+            .unwrap();
+
+        let alert = &page.items[0];
+        assert_eq!(alert.number, 2);
+        assert_eq!(
+            alert.state,
+            crate::models::SecretScanningAlertState::Resolved
+        );
+        assert_eq!(alert.secret_type, "adafruit_io_key");
+        assert_eq!(alert.secret, "aio_XXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+        assert_eq!(
+            alert.resolution,
+            Some(crate::models::SecretScanningResolution::FalsePositive)
+        );
+        assert_eq!(alert.resolved_by.as_ref().unwrap().login, "octocat");
+        assert!(page.items[1].resolution.is_none());
+    }
+
+    #[tokio::test]
+    async fn forbidden() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/secret-scanning/alerts/1"))
+            .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+                "message": "Resource not accessible by integration",
+                "documentation_url": "https://docs.github.com/rest/secret-scanning#get-a-secret-scanning-alert"
+            })))
+            .mount(&server)
+            .await;
+
+        let error = crate::setup(&server)
+            .repos("octocat", "Hello-World")
+            .secret_scanning_alerts()
+            .get(1)
+            .await
+            .unwrap_err();
+
+        let error = error.as_github().unwrap();
+        assert_eq!(error.status_code, 403);
+        assert_eq!(error.message, "Resource not accessible by integration");
+    }
+}
@@ -0,0 +1,206 @@
+use super::*;
+
+/// A client to GitHub's source import API, for importing a repository from
+/// another version control system.
+///
+/// *Note* This sets the `barred-rock` preview automatically.
+///
+/// Created with [`RepoHandler::source_import`].
+///
+/// [`RepoHandler::source_import`]: ./struct.RepoHandler.html#method.source_import
+pub struct SourceImportHandler<'octo, 'r> {
+    handler: &'r RepoHandler<'octo>,
+}
+
+impl<'octo, 'r> SourceImportHandler<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>) -> Self {
+        Self { handler }
+    }
+
+    /// Starts importing the repository at `vcs_url` into this repository.
+    /// Imports run in the background, use [`SourceImportHandler::status`] to
+    /// follow their progress.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params;
+    ///
+    /// let import = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .source_import()
+    ///     .start("https://svn.example.com/project", params::repos::Vcs::Subversion)
+    ///     .vcs_username("ferris")
+    ///     .vcs_password("hunter2")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`SourceImportHandler::status`]: ./struct.SourceImportHandler.html#method.status
+    pub fn start(
+        &self,
+        vcs_url: impl Into<String>,
+        vcs: impl Into<crate::params::repos::Vcs>,
+    ) -> StartImportBuilder<'octo, '_> {
+        StartImportBuilder::new(self.handler, vcs_url.into(), vcs.into())
+    }
+
+    /// Gets the progress of the repository's import.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::ImportStatus;
+    ///
+    /// let import = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .source_import()
+    ///     .status()
+    ///     .await?;
+    ///
+    /// if import.status == ImportStatus::Complete {
+    ///     println!("Import finished");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn status(&self) -> Result<models::Import> {
+        self.handler
+            .crab
+            .get_with(
+                self.route(),
+                None::<&()>,
+                crate::preview_headers("barred-rock"),
+            )
+            .await
+    }
+
+    /// Stops the repository's import.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .source_import()
+    ///     .cancel()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn cancel(&self) -> Result<()> {
+        let request = self
+            .handler
+            .crab
+            .client
+            .delete(self.handler.crab.absolute_url(self.route())?)
+            .headers(crate::preview_headers("barred-rock"));
+
+        Octocrab::map_no_content(self.handler.crab.execute(request).await?).await
+    }
+
+    fn route(&self) -> String {
+        format!(
+            "/repos/{owner}/{repo}/import",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+        )
+    }
+}
+
+/// A builder pattern struct for starting a source import.
+///
+/// created by [`SourceImportHandler::start`]
+///
+/// [`SourceImportHandler::start`]: ./struct.SourceImportHandler.html#method.start
+#[derive(serde::Serialize)]
+pub struct StartImportBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    vcs_url: String,
+    vcs: crate::params::repos::Vcs,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vcs_username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vcs_password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tfvc_project: Option<String>,
+}
+
+impl<'octo, 'r> StartImportBuilder<'octo, 'r> {
+    pub(crate) fn new(
+        handler: &'r RepoHandler<'octo>,
+        vcs_url: String,
+        vcs: crate::params::repos::Vcs,
+    ) -> Self {
+        Self {
+            handler,
+            vcs_url,
+            vcs,
+            vcs_username: None,
+            vcs_password: None,
+            tfvc_project: None,
+        }
+    }
+
+    /// The username to authenticate with the originating repository.
+    pub fn vcs_username(mut self, vcs_username: impl Into<String>) -> Self {
+        self.vcs_username = Some(vcs_username.into());
+        self
+    }
+
+    /// The password to authenticate with the originating repository.
+    pub fn vcs_password(mut self, vcs_password: impl Into<String>) -> Self {
+        self.vcs_password = Some(vcs_password.into());
+        self
+    }
+
+    /// The project to import, required when importing from a TFVC
+    /// repository.
+    pub fn tfvc_project(mut self, tfvc_project: impl Into<String>) -> Self {
+        self.tfvc_project = Some(tfvc_project.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<crate::Created<models::Import>> {
+        let route = format!(
+            "/repos/{owner}/{repo}/import",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+        );
+        let request = self
+            .handler
+            .crab
+            .client
+            .put(self.handler.crab.absolute_url(route)?)
+            .headers(crate::preview_headers("barred-rock"))
+            .json(&self);
+        let response = self.handler.crab.execute(request).await?;
+
+        crate::FromResponse::from_response(Octocrab::map_github_error(response).await?).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.repos("octocat", "socm");
+        let import = handler.source_import();
+        let start = import
+            .start(
+                "http://svn.mycompany.com/svn/myproject",
+                crate::params::repos::Vcs::Subversion,
+            )
+            .vcs_username("octocat")
+            .vcs_password("secret");
+
+        assert_eq!(
+            serde_json::to_value(start).unwrap(),
+            serde_json::json!({
+                "vcs_url": "http://svn.mycompany.com/svn/myproject",
+                "vcs": "subversion",
+                "vcs_username": "octocat",
+                "vcs_password": "secret",
+            })
+        )
+    }
+}
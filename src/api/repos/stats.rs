@@ -0,0 +1,161 @@
+use super::*;
+
+/// A client to GitHub's repository statistics API.
+///
+/// GitHub computes statistics in the background and caches them, so the first
+/// request for a repository's statistics may need to be repeated once they
+/// have been computed.
+///
+/// Created with [`RepoHandler::stats`].
+///
+/// [`RepoHandler::stats`]: ./struct.RepoHandler.html#method.stats
+pub struct StatsHandler<'octo, 'r> {
+    handler: &'r RepoHandler<'octo>,
+}
+
+impl<'octo, 'r> StatsHandler<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>) -> Self {
+        Self { handler }
+    }
+
+    /// Gets the weekly commit counts for the last year, for both everyone and
+    /// just the repository's owner. Returns `None` while GitHub is still
+    /// computing the statistics, in which case the request should be retried
+    /// later.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let participation = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .stats()
+    ///     .participation()
+    ///     .await?;
+    ///
+    /// match participation {
+    ///     Some(participation) => println!("{:?}", participation.owner),
+    ///     None => println!("still computing, try again later"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn participation(&self) -> Result<Option<models::Participation>> {
+        self.get("participation").await
+    }
+
+    /// Gets the number of lines added and deleted in each week of the
+    /// repository's history, oldest week first. Returns `None` while GitHub is
+    /// still computing the statistics.
+    ///
+    /// *Note* GitHub doesn't compute this for repositories with 10,000 or more
+    /// commits, and returns an error instead.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let code_frequency = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .stats()
+    ///     .code_frequency()
+    ///     .await?;
+    ///
+    /// for week in code_frequency.unwrap_or_default() {
+    ///     println!("{}: +{} {}", week.week, week.additions, week.deletions);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn code_frequency(&self) -> Result<Option<Vec<models::CodeFrequency>>> {
+        self.get("code_frequency").await
+    }
+
+    async fn get<R: crate::FromResponse>(&self, stat: &str) -> Result<Option<R>> {
+        let url = self.handler.crab.absolute_url(format!(
+            "/repos/{owner}/{repo}/stats/{stat}",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+            stat = stat,
+        ))?;
+        let response = self.handler.crab._get(url, None::<&()>).await?;
+
+        if response.status() == reqwest::StatusCode::ACCEPTED {
+            return Ok(None);
+        }
+
+        let response = Octocrab::map_github_error(response).await?;
+        R::from_response(response).await.map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[tokio::test]
+    async fn participation_is_computing() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/stats/participation"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(serde_json::json!({})))
+            .mount(&server)
+            .await;
+
+        let participation = crate::setup(&server)
+            .repos("octocat", "Hello-World")
+            .stats()
+            .participation()
+            .await
+            .unwrap();
+
+        assert!(participation.is_none());
+    }
+
+    #[tokio::test]
+    async fn participation() {
+        let server = MockServer::start().await;
+        let participation: serde_json::Value =
+            serde_json::from_str(include_str!("../../../tests/resources/participation.json"))
+                .unwrap();
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/stats/participation"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(participation))
+            .mount(&server)
+            .await;
+
+        let participation = crate::setup(&server)
+            .repos("octocat", "Hello-World")
+            .stats()
+            .participation()
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(participation.all.len(), 52);
+        assert_eq!(participation.owner.len(), 52);
+    }
+
+    #[tokio::test]
+    async fn code_frequency() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/stats/code_frequency"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                [1302998400, 1124, -435],
+                [1303603200, 0, 0]
+            ])))
+            .mount(&server)
+            .await;
+
+        let code_frequency = crate::setup(&server)
+            .repos("octocat", "Hello-World")
+            .stats()
+            .code_frequency()
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(code_frequency.len(), 2);
+        assert_eq!(code_frequency[0].week.timestamp(), 1302998400);
+        assert_eq!(code_frequency[0].additions, 1124);
+        assert_eq!(code_frequency[0].deletions, -435);
+    }
+}
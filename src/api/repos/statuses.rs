@@ -0,0 +1,96 @@
+use super::*;
+
+/// A builder pattern struct for creating a commit status.
+///
+/// created by [`RepoHandler::create_status`]
+///
+/// [`RepoHandler::create_status`]: ./struct.RepoHandler.html#method.create_status
+#[derive(serde::Serialize)]
+pub struct CreateStatusBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    #[serde(skip)]
+    sha: String,
+    state: models::StatusState,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    context: Option<String>,
+}
+
+impl<'octo, 'r> CreateStatusBuilder<'octo, 'r> {
+    pub(crate) fn new(
+        handler: &'r RepoHandler<'octo>,
+        sha: String,
+        state: models::StatusState,
+    ) -> Self {
+        Self {
+            handler,
+            sha,
+            state,
+            target_url: None,
+            description: None,
+            context: None,
+        }
+    }
+
+    /// The URL that the status links to, e.g. the build's output.
+    pub fn target_url(mut self, target_url: impl Into<String>) -> Self {
+        self.target_url = Some(target_url.into());
+        self
+    }
+
+    /// A short description of the status.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// The label that differentiates this status from the statuses of other
+    /// systems, e.g. `continuous-integration/jenkins`. Default: `default`.
+    pub fn context(mut self, context: impl Into<String>) -> Self {
+        self.context = Some(context.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<models::Status> {
+        let route = format!(
+            "/repos/{owner}/{repo}/statuses/{sha}",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+            sha = crate::encoding::path(&self.sha),
+        );
+
+        self.handler.crab.post(route, Some(&self)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.repos("rust-lang", "rust");
+        let create = handler
+            .create_status(
+                "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+                crate::models::StatusState::Error,
+            )
+            .target_url("https://ci.example.com/1000/output")
+            .description("The build errored")
+            .context("continuous-integration/jenkins");
+
+        assert_eq!(
+            serde_json::to_value(create).unwrap(),
+            serde_json::json!({
+                "state": "error",
+                "target_url": "https://ci.example.com/1000/output",
+                "description": "The build errored",
+                "context": "continuous-integration/jenkins",
+            })
+        )
+    }
+}
@@ -0,0 +1,91 @@
+use super::*;
+
+/// A builder pattern struct for listing a repository's tags.
+///
+/// created by [`RepoHandler::list_tags`]
+///
+/// [`RepoHandler::list_tags`]: ./struct.RepoHandler.html#method.list_tags
+#[derive(serde::Serialize)]
+pub struct ListTagsBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListTagsBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>) -> Self {
+        Self {
+            handler,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl std::convert::TryInto<i64>) -> Self {
+        self.per_page = Some(crate::page::clamp_per_page(per_page));
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<Page<models::Tag>> {
+        let route = format!(
+            "/repos/{owner}/{repo}/tags",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+        );
+
+        self.handler.crab.get(route, Some(&self)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        matchers::{method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[tokio::test]
+    async fn list_tags() {
+        let server = MockServer::start().await;
+        let tags: serde_json::Value =
+            serde_json::from_str(include_str!("../../../tests/resources/tags.json")).unwrap();
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/tags"))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(tags))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let page = octocrab
+            .repos("octocat", "Hello-World")
+            .list_tags()
+            .page(2u32)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.items[0].name, "v0.1");
+        assert_eq!(
+            page.items[0].commit.sha,
+            "c5b97d5ae6c19d5c5df71a34c7fbeeda2479ccbc"
+        );
+        assert_eq!(
+            page.items[0].tarball_url.as_str(),
+            "https://github.com/octocat/Hello-World/tarball/v0.1"
+        );
+    }
+}
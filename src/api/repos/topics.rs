@@ -0,0 +1,68 @@
+use super::*;
+
+/// A builder pattern struct for listing a repository's topics.
+///
+/// created by [`RepoHandler::list_topics`]
+///
+/// [`RepoHandler::list_topics`]: ./struct.RepoHandler.html#method.list_topics
+#[derive(serde::Serialize)]
+pub struct ListTopicsBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    per_page: Option<u8>,
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListTopicsBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>) -> Self {
+        Self {
+            handler,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl std::convert::TryInto<i64>) -> Self {
+        self.per_page = Some(crate::page::clamp_per_page(per_page));
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<Page<String>> {
+        let route = format!(
+            "/repos/{owner}/{repo}/topics",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+        );
+
+        self.handler
+            .crab
+            .get_enveloped_page(route, Some(&self), crate::preview_headers("mercy"), "names")
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.repos("rust-lang", "rust");
+        let list = handler.list_topics().per_page(100).page(2u8);
+
+        assert_eq!(
+            serde_json::to_value(list).unwrap(),
+            serde_json::json!({
+                "per_page": 100,
+                "page": 2,
+            })
+        )
+    }
+}
@@ -0,0 +1,177 @@
+use super::*;
+
+/// A builder pattern struct for updating a repository's settings. Only the
+/// settings that have been set are sent, so unset settings are left
+/// unchanged.
+///
+/// created by [`RepoHandler::update`]
+///
+/// [`RepoHandler::update`]: ./struct.RepoHandler.html#method.update
+#[derive(serde::Serialize)]
+pub struct UpdateRepoBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    homepage: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    private: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    has_issues: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    has_projects: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    has_wiki: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_branch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow_squash_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow_merge_commit: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow_rebase_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    delete_branch_on_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    archived: Option<bool>,
+}
+
+impl<'octo, 'r> UpdateRepoBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>) -> Self {
+        Self {
+            handler,
+            name: None,
+            description: None,
+            homepage: None,
+            private: None,
+            has_issues: None,
+            has_projects: None,
+            has_wiki: None,
+            default_branch: None,
+            allow_squash_merge: None,
+            allow_merge_commit: None,
+            allow_rebase_merge: None,
+            delete_branch_on_merge: None,
+            archived: None,
+        }
+    }
+
+    /// The name of the repository.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// A short description of the repository.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// The URL with more information about the repository.
+    pub fn homepage(mut self, homepage: impl Into<String>) -> Self {
+        self.homepage = Some(homepage.into());
+        self
+    }
+
+    /// Whether the repository is private.
+    pub fn private(mut self, private: bool) -> Self {
+        self.private = Some(private);
+        self
+    }
+
+    /// Whether issues are enabled.
+    pub fn has_issues(mut self, has_issues: bool) -> Self {
+        self.has_issues = Some(has_issues);
+        self
+    }
+
+    /// Whether projects are enabled.
+    pub fn has_projects(mut self, has_projects: bool) -> Self {
+        self.has_projects = Some(has_projects);
+        self
+    }
+
+    /// Whether the wiki is enabled.
+    pub fn has_wiki(mut self, has_wiki: bool) -> Self {
+        self.has_wiki = Some(has_wiki);
+        self
+    }
+
+    /// The name of the default branch.
+    pub fn default_branch(mut self, default_branch: impl Into<String>) -> Self {
+        self.default_branch = Some(default_branch.into());
+        self
+    }
+
+    /// Whether pull requests can be squash merged.
+    pub fn allow_squash_merge(mut self, allow_squash_merge: bool) -> Self {
+        self.allow_squash_merge = Some(allow_squash_merge);
+        self
+    }
+
+    /// Whether pull requests can be merged with a merge commit.
+    pub fn allow_merge_commit(mut self, allow_merge_commit: bool) -> Self {
+        self.allow_merge_commit = Some(allow_merge_commit);
+        self
+    }
+
+    /// Whether pull requests can be rebase merged.
+    pub fn allow_rebase_merge(mut self, allow_rebase_merge: bool) -> Self {
+        self.allow_rebase_merge = Some(allow_rebase_merge);
+        self
+    }
+
+    /// Whether head branches are automatically deleted when pull requests are
+    /// merged.
+    pub fn delete_branch_on_merge(mut self, delete_branch_on_merge: bool) -> Self {
+        self.delete_branch_on_merge = Some(delete_branch_on_merge);
+        self
+    }
+
+    /// Whether the repository is archived, archived repositories are read-only.
+    /// *Note* You can't unarchive repositories through the API.
+    pub fn archived(mut self, archived: bool) -> Self {
+        self.archived = Some(archived);
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<models::Repository> {
+        let route = format!(
+            "/repos/{owner}/{repo}",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+        );
+
+        self.handler.crab.patch(route, Some(&self)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn serialize() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.repos("rust-lang", "rust");
+        let update = handler
+            .update()
+            .allow_merge_commit(true)
+            .allow_squash_merge(false)
+            .allow_rebase_merge(false)
+            .delete_branch_on_merge(true);
+
+        assert_eq!(
+            serde_json::to_value(update).unwrap(),
+            serde_json::json!({
+                "allow_merge_commit": true,
+                "allow_squash_merge": false,
+                "allow_rebase_merge": false,
+                "delete_branch_on_merge": true,
+            })
+        )
+    }
+}
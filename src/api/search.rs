@@ -0,0 +1,386 @@
+//! Using GitHub's search.
+
+mod query;
+
+use std::marker::PhantomData;
+
+use crate::{models, Octocrab, Page, Result};
+
+pub use self::query::SearchQueryBuilder;
+
+/// Handler for the search API.
+///
+/// Created with [`Octocrab::search`].
+///
+/// [`Octocrab::search`]: ../struct.Octocrab.html#method.search
+pub struct SearchHandler<'octo> {
+    crab: &'octo Octocrab,
+}
+
+impl<'octo> SearchHandler<'octo> {
+    pub(crate) fn new(crab: &'octo Octocrab) -> Self {
+        Self { crab }
+    }
+
+    /// Search for issues and pull requests matching `query`. Instead of a
+    /// query string, you can use [`QueryHandler::query_builder`] to build the
+    /// query with typed qualifiers.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::search::SearchQueryBuilder;
+    ///
+    /// let page = octocrab::instance()
+    ///     .search()
+    ///     .issues_and_pull_requests("")
+    ///     .query_builder(
+    ///         SearchQueryBuilder::new()
+    ///             .repo("rust-lang", "rust")
+    ///             .is_open()
+    ///             .label("E-easy"),
+    ///     )
+    ///     .sort("comments")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`QueryHandler::query_builder`]: ./struct.QueryHandler.html#method.query_builder
+    pub fn issues_and_pull_requests(
+        self,
+        query: impl Into<String>,
+    ) -> QueryHandler<'octo, models::Issue> {
+        QueryHandler::new(self.crab, "issues", query.into())
+    }
+
+    /// Search for repositories matching `query`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params;
+    ///
+    /// let page = octocrab::instance()
+    ///     .search()
+    ///     .repositories("tetris language:assembly")
+    ///     .sort("stars")
+    ///     .order(params::Direction::Descending)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn repositories(self, query: impl Into<String>) -> QueryHandler<'octo, models::Repository> {
+        QueryHandler::new(self.crab, "repositories", query.into())
+    }
+
+    /// Search for users and organizations matching `query`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance()
+    ///     .search()
+    ///     .users("location:berlin followers:>100")
+    ///     .sort("followers")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn users(self, query: impl Into<String>) -> QueryHandler<'octo, models::User> {
+        QueryHandler::new(self.crab, "users", query.into())
+    }
+
+    /// Search for labels in the repository with the id `repository_id`, whose
+    /// names or descriptions match `query`.
+    ///
+    /// *Note* This requires the repository's id rather than its owner and
+    /// name, which you can get from [`models::Repository::id`].
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params;
+    ///
+    /// let page = octocrab::instance()
+    ///     .search()
+    ///     .labels(64778136, "bug")
+    ///     .sort("created")
+    ///     .order(params::Direction::Descending)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`models::Repository::id`]: ../models/struct.Repository.html#structfield.id
+    pub fn labels(
+        self,
+        repository_id: u64,
+        query: impl Into<String>,
+    ) -> QueryHandler<'octo, models::Label> {
+        let mut handler = QueryHandler::new(self.crab, "labels", query.into());
+        handler.repository_id = Some(repository_id);
+        handler
+    }
+}
+
+/// A handler for handling search queries to GitHub.
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct QueryHandler<'octo, T> {
+    #[serde(skip)]
+    return_type: PhantomData<T>,
+    #[serde(skip)]
+    crab: &'octo Octocrab,
+    #[serde(skip)]
+    route: &'static str,
+    #[serde(rename = "q")]
+    query: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    repository_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order: Option<crate::params::Direction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+    #[serde(skip)]
+    text_matches: bool,
+}
+
+impl<'octo, T> QueryHandler<'octo, T> {
+    pub(crate) fn new(crab: &'octo Octocrab, route: &'static str, query: String) -> Self {
+        Self {
+            return_type: PhantomData,
+            crab,
+            route,
+            query,
+            repository_id: None,
+            sort: None,
+            order: None,
+            per_page: None,
+            page: None,
+            text_matches: false,
+        }
+    }
+
+    /// Replaces the query with the one assembled by `builder`.
+    pub fn query_builder(mut self, builder: SearchQueryBuilder) -> Self {
+        self.query = builder.build();
+        self
+    }
+
+    /// Sets the `sort` parameter for the query. The exact parameters for this
+    /// method will vary based on what is being searched, e.g. labels can be
+    /// sorted by `created` or `updated`. Default: best match.
+    pub fn sort(mut self, sort: impl Into<String>) -> Self {
+        self.sort = Some(sort.into());
+        self
+    }
+
+    /// Sets the `order` parameter for the query, which determines the
+    /// direction of `sort`. Default: descending.
+    pub fn order(mut self, order: crate::params::Direction) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl std::convert::TryInto<i64>) -> Self {
+        self.per_page = Some(crate::page::clamp_per_page(per_page));
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Whether to include the `text_matches` of each result, the snippets
+    /// of the result's properties that matched the query. Default: `false`.
+    pub fn text_matches(mut self, text_matches: bool) -> Self {
+        self.text_matches = text_matches;
+        self
+    }
+}
+
+impl<'octo, T: serde::de::DeserializeOwned> QueryHandler<'octo, T> {
+    /// Sends the actual request. The total number of results is available
+    /// in [`Page::total_count`], and [`Page::incomplete_results`] is `true`
+    /// if the search timed out before finding every result.
+    ///
+    /// [`Page::total_count`]: ../struct.Page.html#structfield.total_count
+    /// [`Page::incomplete_results`]: ../struct.Page.html#structfield.incomplete_results
+    pub async fn send(self) -> Result<Page<T>> {
+        let route = format!("/search/{}", self.route);
+
+        if self.text_matches {
+            let mut headers = reqwest::header::HeaderMap::new();
+            headers.insert(
+                reqwest::header::ACCEPT,
+                reqwest::header::HeaderValue::from_static(
+                    "application/vnd.github.v3.text-match+json",
+                ),
+            );
+
+            self.crab.get_with(route, Some(&self), headers).await
+        } else {
+            self.crab.get(route, Some(&self)).await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        matchers::{header, method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[tokio::test]
+    async fn repositories() {
+        let server = MockServer::start().await;
+        let repository: serde_json::Value =
+            serde_json::from_str(include_str!("../../tests/resources/repository.json")).unwrap();
+        Mock::given(method("GET"))
+            .and(path("/search/repositories"))
+            .and(query_param("q", "hello language:rust"))
+            .and(query_param("sort", "stars"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 40,
+                "incomplete_results": true,
+                "items": [repository],
+            })))
+            .mount(&server)
+            .await;
+
+        let page = crate::setup(&server)
+            .search()
+            .repositories("hello language:rust")
+            .sort("stars")
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(page.total_count, Some(40));
+        assert_eq!(page.incomplete_results, Some(true));
+        assert_eq!(page.items[0].full_name, "octocat/Hello-World");
+    }
+
+    #[tokio::test]
+    async fn text_matches() {
+        let server = MockServer::start().await;
+        let event: serde_json::Value = serde_json::from_str(include_str!(
+            "../../tests/resources/cross_referenced_event.json"
+        ))
+        .unwrap();
+        let mut issue = event["source"]["issue"].clone();
+        issue["text_matches"] = serde_json::json!([{
+            "object_url": "https://api.github.com/repositories/215335/issues/132",
+            "object_type": "Issue",
+            "property": "body",
+            "fragment": "comprehensive windows support for the crate",
+            "matches": [{ "text": "windows", "indices": [14, 21] }]
+        }]);
+        Mock::given(method("GET"))
+            .and(path("/search/issues"))
+            .and(header(
+                "accept",
+                "application/vnd.github.v3.text-match+json",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 1,
+                "incomplete_results": false,
+                "items": [issue],
+            })))
+            .mount(&server)
+            .await;
+
+        let page = crate::setup(&server)
+            .search()
+            .issues_and_pull_requests("windows")
+            .text_matches(true)
+            .send()
+            .await
+            .unwrap();
+
+        let text_match = &page.items[0].text_matches.as_ref().unwrap()[0];
+        let [start, end] = text_match.matches[0].indices;
+        assert_eq!(text_match.property, "body");
+        assert_eq!(&text_match.fragment[start..end], text_match.matches[0].text);
+    }
+
+    #[tokio::test]
+    async fn users() {
+        let server = MockServer::start().await;
+        let mut user: serde_json::Value =
+            serde_json::from_str(include_str!("../../tests/resources/repository.json")).unwrap();
+        user = user["owner"].take();
+        user["score"] = serde_json::json!(1.0);
+        Mock::given(method("GET"))
+            .and(path("/search/users"))
+            .and(query_param("q", "octocat"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 1,
+                "incomplete_results": false,
+                "items": [user],
+            })))
+            .mount(&server)
+            .await;
+
+        let page = crate::setup(&server)
+            .search()
+            .users("octocat")
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(page.total_count, Some(1));
+        assert_eq!(page.incomplete_results, Some(false));
+        assert_eq!(page.items[0].login, "octocat");
+    }
+    #[test]
+    fn serialize_issues_and_pull_requests() {
+        let octocrab = crate::Octocrab::default();
+        let query = octocrab
+            .search()
+            .issues_and_pull_requests("ignored")
+            .query_builder(
+                super::SearchQueryBuilder::new()
+                    .repo("rust-lang", "rust")
+                    .is_open()
+                    .label("help wanted"),
+            )
+            .sort("comments");
+
+        assert_eq!(
+            serde_json::to_value(query).unwrap(),
+            serde_json::json!({
+                "q": r#"repo:rust-lang/rust is:open label:"help wanted""#,
+                "sort": "comments",
+            })
+        )
+    }
+
+    #[test]
+    fn serialize_labels() {
+        let octocrab = crate::Octocrab::default();
+        let query = octocrab
+            .search()
+            .labels(64778136, "bug defect")
+            .sort("created")
+            .order(crate::params::Direction::Ascending)
+            .per_page(100)
+            .page(1u8);
+
+        assert_eq!(
+            serde_json::to_value(query).unwrap(),
+            serde_json::json!({
+                "q": "bug defect",
+                "repository_id": 64778136,
+                "sort": "created",
+                "order": "asc",
+                "per_page": 100,
+                "page": 1,
+            })
+        )
+    }
+}
@@ -0,0 +1,160 @@
+use chrono::NaiveDate;
+
+/// A builder for assembling search queries from typed qualifiers, rather than
+/// writing the query string by hand. Values are quoted when needed, so
+/// qualifiers such as labels with spaces stay intact.
+/// ```
+/// use octocrab::search::SearchQueryBuilder;
+///
+/// let query = SearchQueryBuilder::new()
+///     .repo("rust-lang", "rust")
+///     .is_open()
+///     .label("help wanted")
+///     .build();
+///
+/// assert_eq!(query, r#"repo:rust-lang/rust is:open label:"help wanted""#);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SearchQueryBuilder {
+    terms: Vec<String>,
+}
+
+impl SearchQueryBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only match results in the repository `owner/name`.
+    pub fn repo(self, owner: impl AsRef<str>, name: impl AsRef<str>) -> Self {
+        let repo = format!("{}/{}", owner.as_ref(), name.as_ref());
+        self.qualifier("repo", &repo)
+    }
+
+    /// Only match open issues and pull requests.
+    pub fn is_open(self) -> Self {
+        self.qualifier("is", "open")
+    }
+
+    /// Only match closed issues and pull requests.
+    pub fn is_closed(self) -> Self {
+        self.qualifier("is", "closed")
+    }
+
+    /// Only match issues.
+    pub fn is_issue(self) -> Self {
+        self.qualifier("is", "issue")
+    }
+
+    /// Only match pull requests.
+    pub fn is_pr(self) -> Self {
+        self.qualifier("is", "pr")
+    }
+
+    /// Only match results with the label `label`, this can be called
+    /// multiple times to require several labels.
+    pub fn label(self, label: impl AsRef<str>) -> Self {
+        self.qualifier("label", label.as_ref())
+    }
+
+    /// Only match results created by `author`.
+    pub fn author(self, author: impl AsRef<str>) -> Self {
+        self.qualifier("author", author.as_ref())
+    }
+
+    /// Only match results assigned to `assignee`.
+    pub fn assignee(self, assignee: impl AsRef<str>) -> Self {
+        self.qualifier("assignee", assignee.as_ref())
+    }
+
+    /// Only match results created between `from` and `to` (inclusive), either
+    /// bound can be `None` for an open ended range.
+    pub fn created_range(self, from: Option<NaiveDate>, to: Option<NaiveDate>) -> Self {
+        let range = match (from, to) {
+            (Some(from), Some(to)) => format!("{}..{}", from, to),
+            (Some(from), None) => format!(">={}", from),
+            (None, Some(to)) => format!("<={}", to),
+            (None, None) => return self,
+        };
+
+        self.qualifier("created", &range)
+    }
+
+    /// Adds `raw` to the query as is, e.g. for search terms or qualifiers that
+    /// don't have a method.
+    pub fn raw(mut self, raw: impl Into<String>) -> Self {
+        self.terms.push(raw.into());
+        self
+    }
+
+    /// Assembles the query string.
+    pub fn build(&self) -> String {
+        self.terms.join(" ")
+    }
+
+    fn qualifier(mut self, key: &str, value: &str) -> Self {
+        self.terms.push(format!("{}:{}", key, quote(value)));
+        self
+    }
+}
+
+/// Quotes `value` if it contains characters that would otherwise end or
+/// change the meaning of a qualifier.
+fn quote(value: &str) -> String {
+    if value.is_empty() || value.contains(|c: char| c.is_whitespace() || c == '"' || c == ':') {
+        format!("\"{}\"", value.replace('"', "\\\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn assembles_qualifiers() {
+        let query = SearchQueryBuilder::new()
+            .raw("panic")
+            .repo("rust-lang", "rust")
+            .is_issue()
+            .is_closed()
+            .author("ferris")
+            .assignee("octocat")
+            .build();
+
+        assert_eq!(
+            query,
+            "panic repo:rust-lang/rust is:issue is:closed author:ferris assignee:octocat"
+        );
+    }
+
+    #[test]
+    fn quotes_values() {
+        let query = SearchQueryBuilder::new()
+            .label("good first issue")
+            .label(r#"say "hi""#)
+            .label("A-lint:clippy")
+            .build();
+
+        assert_eq!(
+            query,
+            r#"label:"good first issue" label:"say \"hi\"" label:"A-lint:clippy""#
+        );
+    }
+
+    #[test]
+    fn created_range() {
+        let from = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+        let to = NaiveDate::from_ymd_opt(2020, 6, 30).unwrap();
+
+        let query = |from, to| SearchQueryBuilder::new().created_range(from, to).build();
+
+        assert_eq!(
+            query(Some(from), Some(to)),
+            "created:2020-01-01..2020-06-30"
+        );
+        assert_eq!(query(Some(from), None), "created:>=2020-01-01");
+        assert_eq!(query(None, Some(to)), "created:<=2020-06-30");
+        assert_eq!(query(None, None), "");
+    }
+}
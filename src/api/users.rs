@@ -0,0 +1,294 @@
+//! The user API.
+
+use std::marker::PhantomData;
+
+use crate::{models, Octocrab, Page, Result};
+
+/// Handler for GitHub's user API.
+///
+/// Created with [`Octocrab::users`].
+///
+/// [`Octocrab::users`]: ../struct.Octocrab.html#method.users
+pub struct UsersHandler<'octo> {
+    crab: &'octo Octocrab,
+    user: String,
+}
+
+impl<'octo> UsersHandler<'octo> {
+    pub(crate) fn new(crab: &'octo Octocrab, user: String) -> Self {
+        Self {
+            crab,
+            user: crate::encoding::segment(&user),
+        }
+    }
+
+    /// Lists the repositories the user has starred. Use
+    /// [`ListStarredReposBuilder::with_timestamps`] to also get when each
+    /// repository was starred.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance()
+    ///     .users("octocat")
+    ///     .starred()
+    ///     .with_timestamps()
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    ///
+    /// for starred in page {
+    ///     println!("{} starred {}", starred.starred_at, starred.repo.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ListStarredReposBuilder::with_timestamps`]: ./struct.ListStarredReposBuilder.html#method.with_timestamps
+    pub fn starred(&self) -> ListStarredReposBuilder<'octo, '_> {
+        ListStarredReposBuilder::new(self)
+    }
+}
+
+/// A builder pattern struct for listing every user on GitHub, in the order
+/// they signed up.
+///
+/// created by [`Octocrab::users_list`]
+///
+/// [`Octocrab::users_list`]: ../struct.Octocrab.html#method.users_list
+#[derive(serde::Serialize)]
+pub struct ListUsersBuilder<'octo> {
+    #[serde(skip)]
+    crab: &'octo Octocrab,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    since: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+}
+
+impl<'octo> ListUsersBuilder<'octo> {
+    pub(crate) fn new(crab: &'octo Octocrab) -> Self {
+        Self {
+            crab,
+            since: None,
+            per_page: None,
+        }
+    }
+
+    /// Only list users whose id is greater than `since`. This endpoint is
+    /// paginated by id rather than by page number, pass the
+    /// [`Page::last_seen_id`] of a previous page to resume from it.
+    ///
+    /// [`Page::last_seen_id`]: ../struct.Page.html#method.last_seen_id
+    pub fn since(mut self, since: impl Into<u64>) -> Self {
+        self.since = Some(since.into());
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl std::convert::TryInto<i64>) -> Self {
+        self.per_page = Some(crate::page::clamp_per_page(per_page));
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<Page<models::User>> {
+        self.crab.get("/users", Some(&self)).await
+    }
+}
+
+impl Page<models::User> {
+    /// The id of the last user on the page, which can be passed to
+    /// [`ListUsersBuilder::since`] to list the users after this page.
+    ///
+    /// [`ListUsersBuilder::since`]: ./users/struct.ListUsersBuilder.html#method.since
+    pub fn last_seen_id(&self) -> Option<u64> {
+        use std::convert::TryFrom;
+
+        self.items
+            .last()
+            .and_then(|user| u64::try_from(user.id).ok())
+    }
+}
+
+/// A builder pattern struct for listing the repositories a user has starred.
+///
+/// created by [`UsersHandler::starred`]
+///
+/// [`UsersHandler::starred`]: ./struct.UsersHandler.html#method.starred
+#[derive(serde::Serialize)]
+pub struct ListStarredReposBuilder<'octo, 'r, T = models::Repository> {
+    #[serde(skip)]
+    return_type: PhantomData<T>,
+    #[serde(skip)]
+    handler: &'r UsersHandler<'octo>,
+    #[serde(skip)]
+    media_type: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    direction: Option<crate::params::Direction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListStarredReposBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r UsersHandler<'octo>) -> Self {
+        Self {
+            return_type: PhantomData,
+            handler,
+            media_type: None,
+            direction: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Include when each repository was starred, returning
+    /// [`models::StarredRepo`]s instead of repositories.
+    ///
+    /// [`models::StarredRepo`]: ../models/struct.StarredRepo.html
+    pub fn with_timestamps(self) -> ListStarredReposBuilder<'octo, 'r, models::StarredRepo> {
+        ListStarredReposBuilder {
+            return_type: PhantomData,
+            handler: self.handler,
+            media_type: Some("application/vnd.github.v3.star+json"),
+            direction: self.direction,
+            per_page: self.per_page,
+            page: self.page,
+        }
+    }
+}
+
+impl<'octo, 'r, T> ListStarredReposBuilder<'octo, 'r, T> {
+    /// The direction of the sort, by when the repository was starred.
+    /// Default: descending.
+    pub fn direction(mut self, direction: impl Into<crate::params::Direction>) -> Self {
+        self.direction = Some(direction.into());
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl std::convert::TryInto<i64>) -> Self {
+        self.per_page = Some(crate::page::clamp_per_page(per_page));
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+impl<'octo, 'r, T: serde::de::DeserializeOwned> ListStarredReposBuilder<'octo, 'r, T> {
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<Page<T>> {
+        let route = format!("/users/{user}/starred", user = self.handler.user);
+        let mut headers = reqwest::header::HeaderMap::new();
+
+        if let Some(media_type) = self.media_type {
+            headers.insert(reqwest::header::ACCEPT, media_type.parse().unwrap());
+        }
+
+        self.handler
+            .crab
+            .get_with(route, Some(&self), headers)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        matchers::{header, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    fn repository() -> serde_json::Value {
+        serde_json::from_str(include_str!("../../tests/resources/repository.json")).unwrap()
+    }
+
+    #[test]
+    fn serialize_users_list() {
+        let octocrab = crate::Octocrab::default();
+        let list = octocrab.users_list().since(135u64).per_page(100);
+
+        assert_eq!(
+            serde_json::to_value(list).unwrap(),
+            serde_json::json!({
+                "since": 135,
+                "per_page": 100,
+            })
+        )
+    }
+
+    #[test]
+    fn last_seen_id() {
+        let user: crate::models::User =
+            serde_json::from_value(repository()["owner"].clone()).unwrap();
+        let page = crate::Page {
+            items: vec![user],
+            ..crate::Page::default()
+        };
+
+        assert_eq!(page.last_seen_id(), Some(1));
+        assert_eq!(
+            crate::Page::<crate::models::User>::default().last_seen_id(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn starred() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/users/octocat/starred"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!([repository()])),
+            )
+            .mount(&server)
+            .await;
+
+        let page = crate::setup(&server)
+            .users("octocat")
+            .starred()
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.items[0].name, "Hello-World");
+    }
+
+    #[tokio::test]
+    async fn starred_with_timestamps() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/users/octocat/starred"))
+            .and(header("accept", "application/vnd.github.v3.star+json"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                    "starred_at": "2011-01-16T19:06:43Z",
+                    "repo": repository(),
+                }])),
+            )
+            .mount(&server)
+            .await;
+
+        let page = crate::setup(&server)
+            .users("octocat")
+            .starred()
+            .with_timestamps()
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(
+            page.items[0].starred_at,
+            "2011-01-16T19:06:43Z"
+                .parse::<chrono::DateTime<chrono::Utc>>()
+                .unwrap()
+        );
+        assert_eq!(page.items[0].repo.name, "Hello-World");
+    }
+}
@@ -1,10 +1,145 @@
+use std::{fmt, sync::Arc};
+
+use chrono::{DateTime, Duration, Utc};
+use snafu::ResultExt;
+
+/// The authentication method used by `Octocrab`.
+#[derive(Clone)]
 pub enum Auth {
     None,
     PersonalToken(String),
+    /// An installation access token for a GitHub App, which unlike a personal
+    /// token is scoped to the repositories the app is installed on.
+    InstallationToken(String),
+    /// A GitHub App, authenticating with JWTs signed by its private key, or
+    /// with installation tokens exchanged for them if it has an installation.
+    App(Arc<AppAuth>),
 }
 
+// Deriving this needs `#[default]`, which requires Rust 1.62.
+#[allow(clippy::derivable_impls)]
 impl Default for Auth {
     fn default() -> Self {
         Self::None
     }
 }
+
+impl fmt::Debug for Auth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::None => f.write_str("None"),
+            Self::PersonalToken(_) => f.write_str("PersonalToken(..)"),
+            Self::InstallationToken(_) => f.write_str("InstallationToken(..)"),
+            Self::App(app) => f
+                .debug_struct("App")
+                .field("app_id", &app.app_id)
+                .field("installation_id", &app.installation_id)
+                .finish(),
+        }
+    }
+}
+
+/// The credentials of a GitHub App, along with the installation token for
+/// its installation, if any, and when that token expires.
+pub struct AppAuth {
+    app_id: u64,
+    key: jsonwebtoken::EncodingKey,
+    installation_id: Option<u64>,
+    token: tokio::sync::Mutex<Option<(String, DateTime<Utc>)>>,
+}
+
+#[derive(serde::Serialize)]
+struct Claims {
+    iat: i64,
+    exp: i64,
+    iss: u64,
+}
+
+#[derive(serde::Deserialize)]
+struct InstallationToken {
+    token: String,
+    expires_at: DateTime<Utc>,
+}
+
+impl AppAuth {
+    /// How long before an installation token expires that it's refreshed,
+    /// so that it doesn't expire while a request is in flight.
+    const REFRESH_MARGIN_MINUTES: i64 = 5;
+
+    pub(crate) fn new(
+        app_id: u64,
+        key: jsonwebtoken::EncodingKey,
+        installation_id: Option<u64>,
+    ) -> Self {
+        Self {
+            app_id,
+            key,
+            installation_id,
+            token: Default::default(),
+        }
+    }
+
+    pub(crate) fn has_installation(&self) -> bool {
+        self.installation_id.is_some()
+    }
+
+    /// A JWT authenticating as the app itself. GitHub rejects JWTs that
+    /// expire more than ten minutes in the future, and `iat` is backdated to
+    /// allow for clock drift.
+    fn jwt(&self) -> crate::Result<String> {
+        let now = Utc::now();
+        let claims = Claims {
+            iat: (now - Duration::seconds(60)).timestamp(),
+            exp: (now + Duration::minutes(9)).timestamp(),
+            iss: self.app_id,
+        };
+
+        jsonwebtoken::encode(
+            &jsonwebtoken::Header::new(jsonwebtoken::Algorithm::RS256),
+            &claims,
+            &self.key,
+        )
+        .map_err(|error| Box::new(error) as Box<dyn std::error::Error + Send + Sync>)
+        .context(crate::error::Other)
+    }
+
+    /// The token to send as `Bearer` in the `Authorization` header. For an
+    /// installation, this is its installation token, which is exchanged for a
+    /// new one when it's about to expire.
+    pub(crate) async fn token(&self, crab: &crate::Octocrab) -> crate::Result<String> {
+        let installation_id = match self.installation_id {
+            Some(installation_id) => installation_id,
+            None => return self.jwt(),
+        };
+
+        let mut token = self.token.lock().await;
+        let refresh_at = Utc::now() + Duration::minutes(Self::REFRESH_MARGIN_MINUTES);
+
+        match &*token {
+            Some((token, expires_at)) if *expires_at > refresh_at => Ok(token.clone()),
+            _ => {
+                let url = crab.absolute_url(format!(
+                    "/app/installations/{installation_id}/access_tokens",
+                    installation_id = installation_id,
+                ))?;
+                let request = crab
+                    .client
+                    .post(url)
+                    .bearer_auth(self.jwt()?)
+                    .build()
+                    .context(crate::error::Http)?;
+                let response = crab.execute_without_app_auth(request).await?;
+                let installation_token: InstallationToken = crate::FromResponse::from_response(
+                    crate::Octocrab::map_github_error(response).await?,
+                )
+                .await?;
+                *token = Some((
+                    installation_token.token.clone(),
+                    installation_token.expires_at,
+                ));
+
+                Ok(installation_token.token)
+            }
+        }
+    }
+}
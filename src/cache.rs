@@ -0,0 +1,30 @@
+//! An in-memory cache of `GET` responses, used to make conditional requests.
+
+use std::{collections::HashMap, sync::Mutex};
+
+use reqwest::{header::HeaderValue, Url};
+
+/// Caches the body of responses along with their `ETag`, keyed by URL. GitHub
+/// responds with `304 Not Modified` to a request whose `If-None-Match` header
+/// matches the current `ETag`, and these responses don't count against the
+/// rate limit.
+#[derive(Debug, Default)]
+pub(crate) struct EtagCache {
+    entries: Mutex<HashMap<Url, CachedResponse>>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct CachedResponse {
+    pub(crate) etag: HeaderValue,
+    pub(crate) body: String,
+}
+
+impl EtagCache {
+    pub(crate) fn get(&self, url: &Url) -> Option<CachedResponse> {
+        self.entries.lock().unwrap().get(url).cloned()
+    }
+
+    pub(crate) fn insert(&self, url: Url, response: CachedResponse) {
+        self.entries.lock().unwrap().insert(url, response);
+    }
+}
@@ -0,0 +1,62 @@
+use url::Url;
+
+/// A newly created resource, along with the URL GitHub returned in the
+/// response's `Location` header.
+///
+/// For asynchronous operations, such as forking a gist, the body may not yet
+/// be complete, while `location` points at the eventual resource. Currently
+/// this is returned by [`GistsHandler::fork`] and
+/// [`StartImportBuilder::send`].
+/// ```no_run
+/// # async fn run() -> octocrab::Result<()> {
+/// let fork = octocrab::instance().gists().fork("aa5a315d61ae9438b18d").await?;
+///
+/// if let Some(location) = &fork.location {
+///     println!("Fork will be available at {}", location);
+/// }
+///
+/// let gist = fork.into_inner();
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`GistsHandler::fork`]: ./gists/struct.GistsHandler.html#method.fork
+/// [`StartImportBuilder::send`]: ./repos/struct.StartImportBuilder.html#method.send
+#[non_exhaustive]
+#[derive(Clone, Debug)]
+pub struct Created<T> {
+    /// The URL of the created resource, if GitHub provided one.
+    pub location: Option<Url>,
+    pub value: T,
+}
+
+impl<T> Created<T> {
+    /// Returns the created resource, discarding its location.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> std::ops::Deref for Created<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: serde::de::DeserializeOwned> crate::FromResponse for Created<T> {
+    async fn from_response(response: reqwest::Response) -> crate::Result<Self> {
+        let location = response
+            .headers()
+            .get(reqwest::header::LOCATION)
+            .and_then(|location| location.to_str().ok())
+            .and_then(|location| Url::parse(location).ok());
+
+        Ok(Self {
+            location,
+            value: crate::FromResponse::from_response(response).await?,
+        })
+    }
+}
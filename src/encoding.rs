@@ -0,0 +1,48 @@
+//! Percent-encoding of user provided values in routes.
+
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+
+/// The characters that need to be encoded in a path segment, see
+/// <https://url.spec.whatwg.org/#path-percent-encode-set>. `%` is also
+/// included so that values are never decoded by GitHub.
+const PATH_SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'/')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+
+/// Encodes `value` as a single path segment, e.g. an owner, a repository, or
+/// a label name. This includes any `/` in `value`.
+pub(crate) fn segment(value: &str) -> String {
+    utf8_percent_encode(value, PATH_SEGMENT).to_string()
+}
+
+/// Encodes `value` as a sequence of path segments, leaving any `/` intact.
+/// This is for values that may legitimately contain slashes, such as refs
+/// (e.g. `feature/foo`) and file paths.
+pub(crate) fn path(value: &str) -> String {
+    value.split('/').map(segment).collect::<Vec<_>>().join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn segment() {
+        assert_eq!(super::segment("feature/foo bar"), "feature%2Ffoo%20bar");
+        assert_eq!(super::segment("C#?100%"), "C%23%3F100%25");
+        assert_eq!(super::segment("octocrab"), "octocrab");
+    }
+
+    #[test]
+    fn path() {
+        assert_eq!(super::path("feature/foo bar"), "feature/foo%20bar");
+        assert_eq!(super::path("docs/#1.md"), "docs/%231.md");
+    }
+}
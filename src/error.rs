@@ -1,4 +1,4 @@
-use snafu::{Backtrace, Snafu};
+use snafu::{Backtrace, ResultExt, Snafu};
 use std::fmt;
 
 /// An error that could have occurred while using `Octocrab`.
@@ -9,6 +9,22 @@ pub enum Error {
         source: GitHubError,
         backtrace: Backtrace,
     },
+    /// GitHub responded with `503 Service Unavailable`, e.g. during a
+    /// maintenance window. `retry_after` is how long GitHub asked clients to
+    /// back off for, from the `Retry-After` header.
+    #[snafu(display("Service Unavailable: {}", source))]
+    ServiceUnavailable {
+        retry_after: Option<std::time::Duration>,
+        source: GitHubError,
+        backtrace: Backtrace,
+    },
+    /// The GraphQL API responded with `errors`, see
+    /// [`Octocrab::graphql_query`](../struct.Octocrab.html#method.graphql_query).
+    #[snafu(display("GraphQL Error: {}\n\nFound at {}", messages(errors), backtrace))]
+    GraphQL {
+        errors: Vec<GraphQLError>,
+        backtrace: Backtrace,
+    },
     Url {
         source: url::ParseError,
         backtrace: Backtrace,
@@ -24,27 +40,472 @@ pub enum Error {
         json: serde_json::Value,
         backtrace: Backtrace,
     },
+    /// The response wasn't JSON, e.g. an HTML error page from a misconfigured
+    /// proxy in front of GitHub. `body_snippet` is the start of the body.
+    #[snafu(display(
+        "Unexpected Response: {} ({}): {}",
+        status,
+        content_type.as_deref().unwrap_or("no content type"),
+        body_snippet
+    ))]
+    UnexpectedResponse {
+        status: reqwest::StatusCode,
+        content_type: Option<String>,
+        body_snippet: String,
+        backtrace: Backtrace,
+    },
     Other {
         source: Box<dyn std::error::Error + Send + Sync>,
         backtrace: Backtrace,
     },
 }
 
-/// An error returned from GitHub's API.
+/// The maximum length of a non-JSON body (e.g. an HTML error page from a
+/// proxy) that is kept in an error.
+const MAX_SNIPPET_LEN: usize = 500;
+
+/// Truncates `body` to a readable length.
+pub(crate) fn snippet(body: &str) -> &str {
+    let body = body.trim();
+    let end = body
+        .char_indices()
+        .nth(MAX_SNIPPET_LEN)
+        .map_or(body.len(), |(index, _)| index);
+
+    &body[..end]
+}
+
+/// Returns the `Content-Type` of a response, if it's set to something other
+/// than JSON.
+pub(crate) fn non_json_content_type(headers: &reqwest::header::HeaderMap) -> Option<String> {
+    let content_type = headers.get(reqwest::header::CONTENT_TYPE)?.to_str().ok()?;
+    let mime = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+
+    if mime == "application/json" || mime.ends_with("+json") {
+        None
+    } else {
+        Some(content_type.to_owned())
+    }
+}
+
+impl Error {
+    /// Returns the error returned by GitHub, if this is a `GitHub` or
+    /// `ServiceUnavailable` error.
+    pub fn as_github(&self) -> Option<&GitHubError> {
+        match self {
+            Self::GitHub { source, .. } | Self::ServiceUnavailable { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+
+    /// The `X-GitHub-Request-Id` of the failed request, if this is a `GitHub`
+    /// error. Include this when contacting GitHub support about the error.
+    pub fn request_id(&self) -> Option<&str> {
+        self.as_github()?.request_id()
+    }
+
+    /// The HTTP status code of the response, if this is a `GitHub` or an
+    /// `UnexpectedResponse` error.
+    pub fn status_code(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::UnexpectedResponse { status, .. } => Some(*status),
+            _ => self.as_github().map(|error| error.status_code),
+        }
+    }
+
+    /// Whether GitHub responded with `404 Not Found`. *Note* GitHub also
+    /// responds with `404` to requests for private resources that the client
+    /// isn't authorized to access.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::instance();
+    /// let repo = match octocrab.repos("owner", "repo").get().await {
+    ///     Ok(repo) => Some(repo),
+    ///     Err(error) if error.is_not_found() => None,
+    ///     Err(error) => return Err(error),
+    /// };
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_not_found(&self) -> bool {
+        self.status_code() == Some(reqwest::StatusCode::NOT_FOUND)
+    }
+
+    /// Whether GitHub responded with `422 Unprocessable Entity`, i.e. the
+    /// request's parameters were invalid. The invalid fields are available in
+    /// [`GitHubError::errors`].
+    ///
+    /// [`GitHubError::errors`]: ./struct.GitHubError.html#structfield.errors
+    pub fn is_validation_failed(&self) -> bool {
+        self.status_code() == Some(reqwest::StatusCode::UNPROCESSABLE_ENTITY)
+    }
+
+    /// Returns the underlying `reqwest` error, if this is an `Http` error.
+    pub fn as_http(&self) -> Option<&reqwest::Error> {
+        match self {
+            Self::Http { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+
+    /// Whether the request timed out, see [`OctocrabBuilder::timeout`].
+    ///
+    /// [`OctocrabBuilder::timeout`]: ./struct.OctocrabBuilder.html#method.timeout
+    pub fn is_timeout(&self) -> bool {
+        self.as_http()
+            .map(reqwest::Error::is_timeout)
+            .unwrap_or(false)
+    }
+
+    /// Returns the underlying `serde_json` error, if this is a `Json` error.
+    pub fn as_json(&self) -> Option<&serde_json::Error> {
+        match self {
+            Self::Json { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// An error returned from GitHub's API, along with the status code and headers
+/// of the response.
 #[derive(serde::Deserialize, Debug, Clone)]
+#[non_exhaustive]
 pub struct GitHubError {
-    documentation_url: String,
-    message: String,
+    pub documentation_url: Option<String>,
+    pub message: String,
+    /// The details of why the request was invalid, usually only present for
+    /// `422 Unprocessable Entity` responses.
+    pub errors: Option<Vec<GitHubValidationError>>,
+    /// The HTTP status code of the response.
+    #[serde(skip)]
+    pub status_code: reqwest::StatusCode,
+    #[serde(skip)]
+    headers: Box<reqwest::header::HeaderMap>,
+}
+
+impl GitHubError {
+    /// Creates a `GitHubError` from an unsuccessful response. If the body
+    /// isn't a JSON error from GitHub, the (truncated) body is used as
+    /// the message instead.
+    pub(crate) async fn from_response(response: reqwest::Response) -> crate::Result<Self> {
+        let status_code = response.status();
+        let headers = Box::new(response.headers().clone());
+        let text = response.text().await.context(Http)?;
+
+        let error = serde_json::from_str::<Self>(&text).unwrap_or_else(|_| {
+            let body = snippet(&text);
+            let message = if body.is_empty() {
+                status_code.to_string()
+            } else {
+                format!("{}: {}", status_code, body)
+            };
+
+            Self {
+                documentation_url: None,
+                message,
+                errors: None,
+                status_code,
+                headers: Default::default(),
+            }
+        });
+
+        Ok(Self {
+            status_code,
+            headers,
+            ..error
+        })
+    }
+
+    /// The headers of the response.
+    pub fn headers(&self) -> &reqwest::header::HeaderMap {
+        &self.headers
+    }
+
+    /// The ID GitHub assigned to the request, from the `X-GitHub-Request-Id`
+    /// header.
+    pub fn request_id(&self) -> Option<&str> {
+        self.header("x-github-request-id")
+    }
+
+    /// How long to wait before retrying the request, from the `Retry-After`
+    /// header. GitHub sets this when you hit a secondary rate limit.
+    pub fn retry_after(&self) -> Option<std::time::Duration> {
+        let value = self
+            .headers
+            .get(reqwest::header::RETRY_AFTER)?
+            .to_str()
+            .ok()?;
+
+        match value.parse() {
+            Ok(seconds) => Some(std::time::Duration::from_secs(seconds)),
+            Err(_) => {
+                let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+                (date.with_timezone(&chrono::Utc) - chrono::Utc::now())
+                    .to_std()
+                    .ok()
+            }
+        }
+    }
+
+    /// The number of requests remaining in the current rate limit window,
+    /// from the `X-RateLimit-Remaining` header.
+    pub fn rate_limit_remaining(&self) -> Option<u64> {
+        self.header("x-ratelimit-remaining")?.parse().ok()
+    }
+
+    /// When the current rate limit window resets, from the
+    /// `X-RateLimit-Reset` header.
+    pub fn rate_limit_reset(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        use chrono::TimeZone;
+
+        let timestamp = self.header("x-ratelimit-reset")?.parse().ok()?;
+        chrono::Utc.timestamp_opt(timestamp, 0).single()
+    }
+
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name)?.to_str().ok()
+    }
+}
+
+/// Why a field of a request was invalid. GitHub doesn't always include every
+/// field, and sometimes only sends a `message`.
+#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct GitHubValidationError {
+    /// The kind of resource that was invalid, e.g. `Issue`.
+    pub resource: Option<String>,
+    pub field: Option<String>,
+    /// What was wrong with the field, e.g. `missing_field`, `invalid` or
+    /// `already_exists`.
+    pub code: Option<String>,
+    pub message: Option<String>,
 }
 
 impl fmt::Display for GitHubError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "Error: {}\nDocumentation URL: {}",
-            self.message, self.documentation_url
-        )
+        write!(f, "Error: {}", self.message)?;
+
+        for error in self.errors.iter().flatten() {
+            write!(f, "\n- {}", error)?;
+        }
+
+        if let Some(documentation_url) = &self.documentation_url {
+            write!(f, "\nDocumentation URL: {}", documentation_url)?;
+        }
+
+        if let Some(request_id) = self.request_id() {
+            write!(f, "\nRequest ID: {}", request_id)?;
+        }
+
+        Ok(())
     }
 }
 
 impl std::error::Error for GitHubError {}
+
+/// Formats the error as e.g. `Issue.title: missing_field`, leaving out the
+/// fields GitHub didn't send.
+impl fmt::Display for GitHubValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let location = match (&self.resource, &self.field) {
+            (Some(resource), Some(field)) => format!("{}.{}", resource, field),
+            (Some(location), None) | (None, Some(location)) => location.clone(),
+            (None, None) => String::new(),
+        };
+        let details = [self.code.as_deref(), self.message.as_deref()]
+            .iter()
+            .flatten()
+            .copied()
+            .collect::<Vec<_>>()
+            .join(": ");
+
+        match (location.is_empty(), details.is_empty()) {
+            (false, false) => write!(f, "{}: {}", location, details),
+            (false, true) => f.write_str(&location),
+            (true, _) => f.write_str(&details),
+        }
+    }
+}
+
+/// An error returned from GitHub's GraphQL API, e.g. because a field doesn't
+/// exist or a node wasn't found.
+#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct GraphQLError {
+    pub message: String,
+    /// The kind of error, e.g. `NOT_FOUND`.
+    #[serde(rename = "type")]
+    pub error_type: Option<String>,
+    /// The path of the field that caused the error, made of field names and
+    /// list indices.
+    #[serde(default)]
+    pub path: Vec<serde_json::Value>,
+}
+
+fn messages(errors: &[GraphQLError]) -> String {
+    errors
+        .iter()
+        .map(|error| error.message.as_str())
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn github_error() -> Error {
+        Err::<(), _>(serde_json::from_str::<GitHubError>(r#"{"message":"Not Found"}"#).unwrap())
+            .context(GitHub)
+            .unwrap_err()
+    }
+
+    fn http_error() -> Error {
+        reqwest::Client::new()
+            .get("http://")
+            .build()
+            .context(Http)
+            .unwrap_err()
+    }
+
+    fn json_error() -> Error {
+        serde_json::from_str::<u64>("null")
+            .context(Json {
+                json: serde_json::Value::Null,
+            })
+            .unwrap_err()
+    }
+
+    #[test]
+    fn as_github() {
+        assert_eq!(github_error().as_github().unwrap().message, "Not Found");
+        assert!(http_error().as_github().is_none());
+        assert!(json_error().as_github().is_none());
+    }
+
+    #[tokio::test]
+    async fn status_code() {
+        use wiremock::{matchers::path, Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(path("/repos/owner/missing"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "message": "Not Found",
+                "documentation_url": "https://docs.github.com/rest/reference/repos#get-a-repository"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(path("/repos/owner/repo/issues"))
+            .respond_with(ResponseTemplate::new(422).set_body_json(serde_json::json!({
+                "message": "Validation Failed",
+                "errors": [
+                    { "resource": "Issue", "field": "title", "code": "missing_field" }
+                ],
+                "documentation_url": "https://docs.github.com/rest/reference/issues#create-an-issue"
+            })))
+            .mount(&server)
+            .await;
+        let octocrab = crate::setup(&server);
+
+        let error = octocrab.repos("owner", "missing").get().await.unwrap_err();
+        assert_eq!(error.status_code(), Some(reqwest::StatusCode::NOT_FOUND));
+        assert!(error.is_not_found());
+        assert!(!error.is_validation_failed());
+        assert!(error.as_github().unwrap().errors.is_none());
+
+        let error = octocrab
+            .issues("owner", "repo")
+            .create("")
+            .send()
+            .await
+            .unwrap_err();
+        assert!(error.is_validation_failed());
+        assert!(!error.is_not_found());
+        let errors = error.as_github().unwrap().errors.as_ref().unwrap();
+        assert_eq!(errors[0].field.as_deref(), Some("title"));
+        assert_eq!(errors[0].code.as_deref(), Some("missing_field"));
+        assert!(errors[0].message.is_none());
+        assert_eq!(
+            error.as_github().unwrap().to_string(),
+            "Error: Validation Failed\n\
+             - Issue.title: missing_field\n\
+             Documentation URL: https://docs.github.com/rest/reference/issues#create-an-issue"
+        );
+
+        assert!(http_error().status_code().is_none());
+        assert!(!http_error().is_not_found());
+    }
+
+    #[test]
+    fn display_validation_error() {
+        let error = |json| serde_json::from_value::<GitHubValidationError>(json).unwrap();
+
+        assert_eq!(
+            error(serde_json::json!({
+                "resource": "Label",
+                "field": "name",
+                "code": "already_exists"
+            }))
+            .to_string(),
+            "Label.name: already_exists"
+        );
+        assert_eq!(
+            error(serde_json::json!({
+                "resource": "PullRequest",
+                "code": "custom",
+                "message": "No commits between main and main"
+            }))
+            .to_string(),
+            "PullRequest: custom: No commits between main and main"
+        );
+        assert_eq!(
+            error(serde_json::json!({ "message": "Invalid request." })).to_string(),
+            "Invalid request."
+        );
+    }
+
+    #[test]
+    fn as_http() {
+        assert!(http_error().as_http().unwrap().is_builder());
+        assert!(github_error().as_http().is_none());
+        assert!(json_error().as_http().is_none());
+    }
+
+    #[tokio::test]
+    async fn request_id() {
+        use wiremock::{matchers::path, Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(path("/repos/owner/repo"))
+            .respond_with(
+                ResponseTemplate::new(500)
+                    .insert_header("x-github-request-id", "CE7A:3F0B:1A2B3C:4D5E6F:5F4E3D2C")
+                    .set_body_json(serde_json::json!({ "message": "Server Error" })),
+            )
+            .mount(&server)
+            .await;
+        let octocrab = crate::setup(&server);
+
+        let error = octocrab.repos("owner", "repo").get().await.unwrap_err();
+
+        assert_eq!(error.request_id(), Some("CE7A:3F0B:1A2B3C:4D5E6F:5F4E3D2C"));
+        assert_eq!(
+            error.to_string(),
+            "GitHub: Error: Server Error\nRequest ID: CE7A:3F0B:1A2B3C:4D5E6F:5F4E3D2C"
+        );
+        assert!(github_error().request_id().is_none());
+        assert!(http_error().request_id().is_none());
+    }
+
+    #[test]
+    fn as_json() {
+        assert!(json_error().as_json().unwrap().is_data());
+        assert!(github_error().as_json().is_none());
+        assert!(http_error().as_json().is_none());
+    }
+}
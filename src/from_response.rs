@@ -9,10 +9,22 @@ pub trait FromResponse: Sized {
 #[async_trait::async_trait]
 impl<T: serde::de::DeserializeOwned> FromResponse for T {
     async fn from_response(response: reqwest::Response) -> crate::Result<Self> {
+        let status = response.status();
+        let content_type = crate::error::non_json_content_type(response.headers());
         let text = response.text().await.context(crate::error::Http)?;
 
-        serde_json::from_str(&text).with_context(|| crate::error::Json {
-            json: serde_json::from_str::<serde_json::Value>(&text).unwrap(),
+        serde_json::from_str(&text).or_else(|source| {
+            match serde_json::from_str::<serde_json::Value>(&text) {
+                Ok(json) if content_type.is_none() => {
+                    Err(source).context(crate::error::Json { json })
+                }
+                _ => crate::error::UnexpectedResponse {
+                    status,
+                    content_type,
+                    body_snippet: crate::error::snippet(&text),
+                }
+                .fail(),
+            }
         })
     }
 }
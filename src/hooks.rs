@@ -0,0 +1,51 @@
+//! Hooks that are run around every request sent by `Octocrab`.
+
+use std::{fmt, sync::Arc};
+
+type RequestInterceptor = Arc<dyn Fn(&mut reqwest::Request) + Send + Sync>;
+type ResponseObserver = Arc<dyn Fn(&reqwest::Response) + Send + Sync>;
+
+/// The request interceptors and response observers registered with
+/// `OctocrabBuilder`, run in the order they were added.
+#[derive(Clone, Default)]
+pub(crate) struct Hooks {
+    request_interceptors: Vec<RequestInterceptor>,
+    response_observers: Vec<ResponseObserver>,
+}
+
+impl Hooks {
+    pub(crate) fn add_request_interceptor(
+        &mut self,
+        interceptor: impl Fn(&mut reqwest::Request) + Send + Sync + 'static,
+    ) {
+        self.request_interceptors.push(Arc::new(interceptor));
+    }
+
+    pub(crate) fn add_response_observer(
+        &mut self,
+        observer: impl Fn(&reqwest::Response) + Send + Sync + 'static,
+    ) {
+        self.response_observers.push(Arc::new(observer));
+    }
+
+    pub(crate) fn intercept(&self, request: &mut reqwest::Request) {
+        for interceptor in &self.request_interceptors {
+            interceptor(request);
+        }
+    }
+
+    pub(crate) fn observe(&self, response: &reqwest::Response) {
+        for observer in &self.response_observers {
+            observer(response);
+        }
+    }
+}
+
+impl fmt::Debug for Hooks {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Hooks")
+            .field("request_interceptors", &self.request_interceptors.len())
+            .field("response_observers", &self.response_observers.len())
+            .finish()
+    }
+}
@@ -9,14 +9,22 @@
 //! set of [`models`] that maps to GitHub's types. Currently the following
 //! modules are available.
 //!
+//! - [`gists`] Gists and their comments
 //! - [`issues`] Issues and related items, e.g. comments, labels, etc.
 //! - [`pulls`] Pull Requests
 //! - [`orgs`] GitHub Organisations
+//! - [`repos`] Repositories
+//! - [`search`] Using GitHub's search.
+//! - [`users`] Users
 //!
 //! [`models`]: ./models/index.html
+//! [`gists`]: ./gists/struct.GistsHandler.html
 //! [`issues`]: ./issues/struct.IssueHandler.html
 //! [`pulls`]: ./pulls/struct.PullRequestHandler.html
 //! [`orgs`]: ./orgs/struct.OrgHandler.html
+//! [`repos`]: ./repos/struct.RepoHandler.html
+//! [`search`]: ./search/struct.SearchHandler.html
+//! [`users`]: ./users/struct.UsersHandler.html
 //!
 //! #### Getting a Pull Request
 //! ```no_run
@@ -48,10 +56,9 @@
 //!
 //! // Go through every page of issues. Warning: There's no rate limiting so
 //! // be careful.
-//! while let Some(page) = octocrab.get_page::<models::Issue>(&page.next).await? {
-//!     for issue in page {
-//!         println!("{}", issue.title);
-//!     }
+//! let issues: Vec<models::Issue> = octocrab.all_pages(page).await?;
+//! for issue in issues {
+//!     println!("{}", issue.title);
 //! }
 //! # Ok(())
 //! # }
@@ -141,9 +148,15 @@
 
 mod api;
 mod auth;
+mod cache;
+mod created;
+mod encoding;
 mod error;
 mod from_response;
+mod hooks;
 mod page;
+mod rate_limit;
+mod retry;
 
 pub mod models;
 pub mod params;
@@ -158,16 +171,24 @@ use snafu::*;
 use auth::Auth;
 
 pub use self::{
-    api::{issues, orgs, pulls},
-    error::{Error, GitHubError},
+    api::{current, gists, issues, orgs, pulls, repos, search, users},
+    created::Created,
+    error::{Error, GitHubError, GitHubValidationError, GraphQLError},
     from_response::FromResponse,
-    page::Page,
+    page::{Page, PaginationCursor},
+    retry::RetryPolicy,
 };
 
+/// The private key of a GitHub App, see [`OctocrabBuilder::app`].
+///
+/// [`OctocrabBuilder::app`]: ./struct.OctocrabBuilder.html#method.app
+pub use jsonwebtoken::EncodingKey;
+
 /// A convenience type with a default error type of `Octocrab::Error`.
 pub type Result<T, E = error::Error> = std::result::Result<T, E>;
 
 const GITHUB_BASE_URL: &str = "https://api.github.com";
+const DEFAULT_USER_AGENT: &str = concat!("octocrab/", env!("CARGO_PKG_VERSION"));
 
 static STATIC_INSTANCE: Lazy<arc_swap::ArcSwap<Octocrab>> =
     Lazy::new(|| arc_swap::ArcSwap::from_pointee(Octocrab::default()));
@@ -181,6 +202,17 @@ pub fn format_preview(preview: impl AsRef<str>) -> String {
     format!("application/vnd.github.{}-preview", preview.as_ref())
 }
 
+/// Creates a `HeaderMap` with the `Accept` header set to `preview`, for
+/// endpoints that require a specific preview.
+pub(crate) fn preview_headers(preview: impl AsRef<str>) -> reqwest::header::HeaderMap {
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert(
+        reqwest::header::ACCEPT,
+        format_preview(preview).parse().unwrap(),
+    );
+    headers
+}
+
 /// Initialises the static instance using the configuration set by
 /// `builder`.
 /// ```
@@ -208,6 +240,19 @@ pub struct OctocrabBuilder {
     auth: Auth,
     previews: Vec<&'static str>,
     base_url: Option<Url>,
+    request_compression: bool,
+    #[cfg(feature = "gzip")]
+    accept_compression: Option<bool>,
+    retry_policy: Option<RetryPolicy>,
+    server_error_retry: Option<retry::ServerErrorRetry>,
+    hooks: hooks::Hooks,
+    app: Option<(u64, jsonwebtoken::EncodingKey)>,
+    installation_id: Option<u64>,
+    redirect_policy: Option<reqwest::redirect::Policy>,
+    timeout: Option<std::time::Duration>,
+    max_concurrent_requests: Option<usize>,
+    client: Option<reqwest::Client>,
+    user_agent: Option<String>,
 }
 
 /// A builder struct for `Octocrab`, allowing you to configure the client, such
@@ -238,41 +283,354 @@ impl OctocrabBuilder {
         self
     }
 
-    /// Set the base url for `Octocrab`.
+    /// Add an installation access token for a GitHub App to use for
+    /// authentication, required by endpoints such as
+    /// [`Octocrab::installation_repos`].
+    ///
+    /// [`Octocrab::installation_repos`]: ./struct.Octocrab.html#method.installation_repos
+    pub fn installation_token(mut self, token: String) -> Self {
+        self.auth = Auth::InstallationToken(token);
+        self
+    }
+
+    /// Authenticate as the GitHub App `app_id`, signing JWTs with its private
+    /// `key`. Combine with [`installation`] to act as one of the app's
+    /// installations instead, which most endpoints require.
+    /// ```no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let key = octocrab::EncodingKey::from_rsa_pem(&std::fs::read("app.pem")?)?;
+    /// let octocrab = octocrab::Octocrab::builder()
+    ///     .app(12345, key)
+    ///     .installation(67890)
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`installation`]: #method.installation
+    pub fn app(mut self, app_id: u64, key: jsonwebtoken::EncodingKey) -> Self {
+        self.app = Some((app_id, key));
+        self
+    }
+
+    /// Act as the installation `installation_id` of the app set with
+    /// [`app`]. The app's JWT is exchanged for an installation token, which
+    /// expires after an hour and is transparently refreshed shortly before
+    /// it does. Building the client fails if no app was set.
+    ///
+    /// [`app`]: #method.app
+    pub fn installation(mut self, installation_id: u64) -> Self {
+        self.installation_id = Some(installation_id);
+        self
+    }
+
+    /// Set the base url for `Octocrab`, e.g. to use a GitHub Enterprise
+    /// instance, or to point the client at a local mock server in tests.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// // e.g. the address of a `wiremock::MockServer`
+    /// let octocrab = octocrab::Octocrab::builder()
+    ///     .base_url("http://127.0.0.1:8080")?
+    ///     .build()?;
+    /// let issues = octocrab.issues("owner", "repo").list().send().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn base_url(mut self, base_url: impl reqwest::IntoUrl) -> Result<Self> {
         self.base_url = Some(base_url.into_url().context(crate::error::Http)?);
         Ok(self)
     }
 
+    /// Compress the bodies of requests (e.g. `POST` or `PATCH` requests) with
+    /// gzip, and set the `Content-Encoding: gzip` header. This can
+    /// significantly reduce the size of large payloads. Default: `false`.
+    pub fn request_compression(mut self, request_compression: bool) -> Self {
+        self.request_compression = request_compression;
+        self
+    }
+
+    /// Whether to advertise gzip support with `Accept-Encoding: gzip` and
+    /// transparently decompress gzip responses. Disabling this allows you to
+    /// pass through the raw body of a response, e.g. by sending your own
+    /// `Accept-Encoding` header with [`Octocrab::get_with`] and reading the
+    /// body with [`Octocrab::_get_with`]. Default: `true`.
+    ///
+    /// *Note* This requires the `gzip` feature, which is enabled by default.
+    ///
+    /// [`Octocrab::get_with`]: ./struct.Octocrab.html#method.get_with
+    /// [`Octocrab::_get_with`]: ./struct.Octocrab.html#method._get_with
+    #[cfg(feature = "gzip")]
+    pub fn accept_compression(mut self, accept_compression: bool) -> Self {
+        self.accept_compression = Some(accept_compression);
+        self
+    }
+
+    /// Retry requests that were rejected by GitHub's rate limits according
+    /// to `retry_policy`, waiting until the limit resets before each retry.
+    /// Default: requests aren't retried.
+    pub fn retry(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Retry `GET` requests up to `max_retries` times if GitHub responds
+    /// with a server error (e.g. `502 Bad Gateway`), or if the connection
+    /// fails. Before each retry the client waits for an exponentially
+    /// increasing, randomised delay, starting at `base_delay`. Other
+    /// requests aren't retried, as that could repeat their side effects.
+    /// Default: requests aren't retried.
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// # fn main() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::Octocrab::builder()
+    ///     .retry_on_server_error(3, Duration::from_millis(500))
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn retry_on_server_error(
+        mut self,
+        max_retries: u32,
+        base_delay: std::time::Duration,
+    ) -> Self {
+        self.server_error_retry = Some(retry::ServerErrorRetry::new(max_retries, base_delay));
+        self
+    }
+
+    /// Run `interceptor` on every request just before it's sent, e.g. to add
+    /// headers such as a correlation ID. Interceptors run in the order they
+    /// were added, after `Octocrab` has set its own headers, and only once
+    /// per request even if it's retried.
+    /// ```
+    /// # fn main() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::Octocrab::builder()
+    ///     .with_request_interceptor(|request| {
+    ///         request
+    ///             .headers_mut()
+    ///             .insert("x-correlation-id", "4a5b6c".parse().unwrap());
+    ///     })
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_request_interceptor(
+        mut self,
+        interceptor: impl Fn(&mut reqwest::Request) + Send + Sync + 'static,
+    ) -> Self {
+        self.hooks.add_request_interceptor(interceptor);
+        self
+    }
+
+    /// Run `observer` on every response as soon as it's received, including
+    /// error responses and responses to requests that will be retried.
+    /// Observers only get a reference to the response, so they can inspect
+    /// its status and headers but not consume its body.
+    /// ```
+    /// # fn main() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::Octocrab::builder()
+    ///     .with_response_observer(|response| {
+    ///         println!("{} {}", response.status(), response.url());
+    ///     })
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_response_observer(
+        mut self,
+        observer: impl Fn(&reqwest::Response) + Send + Sync + 'static,
+    ) -> Self {
+        self.hooks.add_response_observer(observer);
+        self
+    }
+
+    /// How to follow redirects, e.g. `Policy::none()` to return redirect
+    /// responses as they are. Whatever the policy, the `Authorization` header
+    /// is never sent to a host other than the one that issued the redirect,
+    /// so credentials don't leak to e.g. the storage hosts that downloads are
+    /// redirected to. Default: follow up to 10 redirects.
+    /// ```
+    /// # fn main() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::Octocrab::builder()
+    ///     .redirect_policy(reqwest::redirect::Policy::limited(3))
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn redirect_policy(mut self, redirect_policy: reqwest::redirect::Policy) -> Self {
+        self.redirect_policy = Some(redirect_policy);
+        self
+    }
+
+    /// The maximum time a request can take, from sending it until its
+    /// response has been read. Use [`Octocrab::with_timeout`] to override it
+    /// for some requests. A request that times out fails with an
+    /// `Error::Http`, for which [`Error::is_timeout`] returns `true`.
+    /// Default: no timeout.
+    ///
+    /// [`Octocrab::with_timeout`]: ./struct.Octocrab.html#method.with_timeout
+    /// [`Error::is_timeout`]: ./enum.Error.html#method.is_timeout
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// The maximum number of requests that can be in flight at once, across
+    /// every handler and every clone of the client. Further requests wait
+    /// until an earlier request has received its response. This keeps
+    /// crawlers issuing many concurrent requests from triggering GitHub's
+    /// abuse detection or running out of file descriptors. Building the
+    /// client fails if this is `0`. Default: no limit.
+    ///
+    /// *Note* Only the round-trip until a response's headers arrive counts
+    /// towards the limit, reading the response's body doesn't.
+    /// ```
+    /// # fn main() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::Octocrab::builder()
+    ///     .max_concurrent_requests(8)
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn max_concurrent_requests(mut self, max_concurrent_requests: usize) -> Self {
+        self.max_concurrent_requests = Some(max_concurrent_requests);
+        self
+    }
+
+    /// The `User-Agent` header to send with every request, GitHub rejects
+    /// requests without one. Identifying your application makes it easier
+    /// for GitHub to contact you about problems with its requests.
+    /// Default: `octocrab/{version}`.
+    /// ```
+    /// # fn main() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::Octocrab::builder()
+    ///     .user_agent("my-app/1.0")
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Send requests with `client` instead of a client built by Octocrab,
+    /// e.g. to configure proxies, root certificates or connection pooling.
+    /// The `User-Agent`, `Accept` and `Authorization` headers Octocrab
+    /// would set are added to each request, unless `client` sets them
+    /// itself. The [`timeout`](#method.timeout) is applied to each request
+    /// too, but [`accept_compression`](#method.accept_compression) and
+    /// [`redirect_policy`](#method.redirect_policy) are ignored, configure
+    /// them on `client` instead.
+    /// ```
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = reqwest::Client::builder()
+    ///     .proxy(reqwest::Proxy::https("http://proxy.example.com:8080")?)
+    ///     .build()?;
+    /// let octocrab = octocrab::Octocrab::builder()
+    ///     .with_client(client)
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_client(mut self, client: reqwest::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
     /// Create the `Octocrab` client.
-    pub fn build(self) -> Result<Octocrab> {
+    pub fn build(mut self) -> Result<Octocrab> {
         let mut hmap = reqwest::header::HeaderMap::new();
 
+        match (self.app.take(), self.installation_id) {
+            (Some((app_id, key)), installation_id) => {
+                self.auth = Auth::App(Arc::new(auth::AppAuth::new(app_id, key, installation_id)));
+            }
+            (None, Some(_)) => {
+                return Err(Error::Other {
+                    source: "an installation requires setting the app with `app`".into(),
+                    backtrace: Backtrace::generate(),
+                });
+            }
+            (None, None) => {}
+        }
+
         for preview in &self.previews {
             hmap.append(
                 reqwest::header::ACCEPT,
-                crate::format_preview(&preview).parse().unwrap(),
+                crate::format_preview(preview).parse().unwrap(),
             );
         }
 
-        if let Auth::PersonalToken(token) = self.auth {
+        if let Auth::PersonalToken(token) | Auth::InstallationToken(token) = &self.auth {
             hmap.append(
                 reqwest::header::AUTHORIZATION,
                 format!("Bearer {}", token).parse().unwrap(),
             );
         }
 
-        let client = reqwest::Client::builder()
-            .user_agent("octocrab")
-            .default_headers(hmap)
-            .build()
-            .context(crate::error::Http)?;
+        if self.max_concurrent_requests == Some(0) {
+            return Err(Error::Other {
+                source: "`max_concurrent_requests` must be at least 1".into(),
+                backtrace: Backtrace::generate(),
+            });
+        }
+
+        let user_agent = reqwest::header::HeaderValue::from_str(
+            self.user_agent.as_deref().unwrap_or(DEFAULT_USER_AGENT),
+        )
+        .map_err(|source| Error::Other {
+            source: source.into(),
+            backtrace: Backtrace::generate(),
+        })?;
+
+        let (client, default_headers, request_timeout) = match self.client.take() {
+            Some(client) => {
+                hmap.insert(reqwest::header::USER_AGENT, user_agent);
+
+                (client, hmap, self.timeout)
+            }
+            None => {
+                let mut client = reqwest::Client::builder();
+
+                if let Some(timeout) = self.timeout {
+                    client = client.timeout(timeout);
+                }
+
+                #[cfg(feature = "gzip")]
+                {
+                    client = client.gzip(self.accept_compression.unwrap_or(true));
+                }
+
+                let client = client
+                    .user_agent(user_agent)
+                    .default_headers(hmap)
+                    .redirect(self.redirect_policy.unwrap_or_default())
+                    .build()
+                    .context(crate::error::Http)?;
+
+                (client, reqwest::header::HeaderMap::new(), None)
+            }
+        };
 
         Ok(Octocrab {
             client,
             base_url: self
                 .base_url
                 .unwrap_or_else(|| Url::parse(GITHUB_BASE_URL).unwrap()),
+            request_compression: self.request_compression,
+            retry_policy: self.retry_policy,
+            server_error_retry: self.server_error_retry,
+            hooks: self.hooks,
+            default_headers,
+            request_timeout,
+            auth: self.auth,
+            previews: self.previews,
+            etag_cache: Arc::default(),
+            rate_limit: Arc::default(),
+            concurrency: self
+                .max_concurrent_requests
+                .map(|permits| Arc::new(tokio::sync::Semaphore::new(permits))),
         })
     }
 }
@@ -282,21 +640,32 @@ impl OctocrabBuilder {
 pub struct Octocrab {
     client: reqwest::Client,
     pub base_url: Url,
+    request_compression: bool,
+    retry_policy: Option<RetryPolicy>,
+    server_error_retry: Option<retry::ServerErrorRetry>,
+    hooks: hooks::Hooks,
+    /// The headers to add to each request, when using a client that wasn't
+    /// built by Octocrab.
+    default_headers: reqwest::header::HeaderMap,
+    request_timeout: Option<std::time::Duration>,
+    auth: Auth,
+    previews: Vec<&'static str>,
+    etag_cache: Arc<cache::EtagCache>,
+    rate_limit: Arc<rate_limit::RateLimitTracker>,
+    concurrency: Option<Arc<tokio::sync::Semaphore>>,
 }
 
 /// Defaults for Octocrab:
 /// - `base_url`: `https://api.github.com`
 /// - `auth`: `None`
-/// - `client`: reqwest client with the `octocrab` user agent.
+/// - `client`: reqwest client with the `octocrab/{version}` user agent.
+/// - `request_compression`: `false`
+/// - `retry_policy`: `None`
+/// - `server_error_retry`: `None`
+/// - `concurrency`: `None`
 impl Default for Octocrab {
     fn default() -> Self {
-        Self {
-            base_url: Url::parse(GITHUB_BASE_URL).unwrap(),
-            client: reqwest::ClientBuilder::new()
-                .user_agent("octocrab")
-                .build()
-                .unwrap(),
-        }
+        Self::builder().build().unwrap()
     }
 }
 
@@ -305,6 +674,31 @@ impl Octocrab {
     pub fn builder() -> OctocrabBuilder {
         OctocrabBuilder::default()
     }
+
+    /// Returns a copy of the client whose requests time out after `timeout`,
+    /// instead of the timeout set with [`OctocrabBuilder::timeout`]. This is
+    /// cheap, and the copy shares its caches and rate limit state with the
+    /// original client.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use std::time::Duration;
+    ///
+    /// let tarball = octocrab::instance()
+    ///     .with_timeout(Duration::from_secs(300))
+    ///     .repos("owner", "repo")
+    ///     .download_tarball("master")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`OctocrabBuilder::timeout`]: ./struct.OctocrabBuilder.html#method.timeout
+    pub fn with_timeout(&self, timeout: std::time::Duration) -> Self {
+        Self {
+            request_timeout: Some(timeout),
+            ..self.clone()
+        }
+    }
 }
 
 /// GitHub API Methods
@@ -315,25 +709,306 @@ impl Octocrab {
         &self,
         owner: impl Into<String>,
         repo: impl Into<String>,
-    ) -> api::pulls::PullRequestHandler {
+    ) -> api::pulls::PullRequestHandler<'_> {
         api::pulls::PullRequestHandler::new(self, owner.into(), repo.into())
     }
 
+    /// Creates a `CurrentAuthHandler` that allows you to access the parts of
+    /// GitHub's API that act on the authenticated user.
+    pub fn current(&self) -> api::current::CurrentAuthHandler<'_> {
+        api::current::CurrentAuthHandler::new(self)
+    }
+
+    /// Creates a `GistsHandler` that allows you to access GitHub's gist API.
+    pub fn gists(&self) -> api::gists::GistsHandler<'_> {
+        api::gists::GistsHandler::new(self)
+    }
+
     /// Creates a `IssueHandler` for the repo specified at `owner/repo`,
     /// that allows you to access GitHub's issues API.
     pub fn issues(
         &self,
         owner: impl Into<String>,
         repo: impl Into<String>,
-    ) -> api::issues::IssueHandler {
+    ) -> api::issues::IssueHandler<'_> {
         api::issues::IssueHandler::new(self, owner.into(), repo.into())
     }
 
     /// Creates a `IssueHandler` for the repo specified at `owner/repo`,
     /// that allows you to access GitHub's issues API.
-    pub fn orgs(&self, owner: impl Into<String>) -> api::orgs::OrgHandler {
+    pub fn orgs(&self, owner: impl Into<String>) -> api::orgs::OrgHandler<'_> {
         api::orgs::OrgHandler::new(self, owner.into())
     }
+
+    /// Creates a `RepoHandler` for the repo specified at `owner/repo`,
+    /// that allows you to access GitHub's repository API.
+    pub fn repos(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+    ) -> api::repos::RepoHandler<'_> {
+        api::repos::RepoHandler::new(self, owner.into(), repo.into())
+    }
+
+    /// Creates a `SearchHandler` that allows you to use GitHub's search API.
+    pub fn search(&self) -> api::search::SearchHandler<'_> {
+        api::search::SearchHandler::new(self)
+    }
+
+    /// Creates a `ListUsersBuilder` that lists every user on GitHub, in the
+    /// order they signed up.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::instance();
+    /// let page = octocrab.users_list().per_page(100).send().await?;
+    ///
+    /// if let Some(since) = page.last_seen_id() {
+    ///     let next = octocrab.users_list().since(since).per_page(100).send().await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn users_list(&self) -> api::users::ListUsersBuilder<'_> {
+        api::users::ListUsersBuilder::new(self)
+    }
+
+    /// Creates a `UsersHandler` for the user specified at `user`, that allows
+    /// you to access GitHub's user API.
+    pub fn users(&self, user: impl Into<String>) -> api::users::UsersHandler<'_> {
+        api::users::UsersHandler::new(self, user.into())
+    }
+
+    /// When the rate limit resets, if GitHub reported that no requests were
+    /// remaining in the last response that included rate limit headers.
+    /// Returns `None` if requests were remaining, or no response has been
+    /// received yet. This is shared between clones of the client.
+    ///
+    /// Only responses counting against the `core` rate limit are tracked,
+    /// the `search` and `graphql` resources have separate limits.
+    ///
+    /// *Note* This only reflects the last observed response, so it may be
+    /// stale, e.g. if the window has already reset, or if other clients
+    /// using the same credentials have since made requests.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// if let Some(reset) = octocrab::instance().rate_limited_until() {
+    ///     let wait = (reset - chrono::Utc::now()).to_std().unwrap_or_default();
+    ///     tokio::time::delay_for(wait).await;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rate_limited_until(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.rate_limit.limited_until()
+    }
+
+    /// The `X-RateLimit-Remaining` header of the last response that included
+    /// rate limit headers, or `None` if no response has been received yet.
+    /// This is shared between clones of the client.
+    ///
+    /// *Note* Like [`Octocrab::rate_limited_until`] this only reflects the
+    /// last observed response, use [`Octocrab::ratelimit`] for an up to date
+    /// count.
+    ///
+    /// [`Octocrab::rate_limited_until`]: ./struct.Octocrab.html#method.rate_limited_until
+    /// [`Octocrab::ratelimit`]: ./struct.Octocrab.html#method.ratelimit
+    pub fn rate_limit_remaining(&self) -> Option<u64> {
+        self.rate_limit.remaining()
+    }
+
+    /// Gets the current rate limit status of the REST and search APIs for
+    /// the authenticated user. Fetching the rate limit doesn't count against
+    /// it.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let ratelimit = octocrab::instance().ratelimit().await?;
+    /// if ratelimit.resources.core.remaining == 0 {
+    ///     println!("rate limited until {}", ratelimit.resources.core.reset);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn ratelimit(&self) -> Result<models::RateLimit> {
+        self.get("/rate_limit", None::<&()>).await
+    }
+
+    /// Lists the codes of conduct that are available on GitHub.
+    ///
+    /// *Note* This sets the `scarlet-witch` preview automatically.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let codes_of_conduct = octocrab::instance().codes_of_conduct().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn codes_of_conduct(&self) -> Result<Vec<models::CodeOfConduct>> {
+        self.get_with(
+            "/codes_of_conduct",
+            None::<&()>,
+            preview_headers("scarlet-witch"),
+        )
+        .await
+    }
+
+    /// Lists the repositories that the current GitHub App installation can
+    /// access. The total number of repositories is available in
+    /// [`Page::total_count`].
+    ///
+    /// *Note* This requires authenticating as an installation (see
+    /// [`OctocrabBuilder::installation_token`] and
+    /// [`OctocrabBuilder::installation`]), and returns an error otherwise.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance().installation_repos().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Page::total_count`]: ./struct.Page.html#structfield.total_count
+    /// [`OctocrabBuilder::installation_token`]: ./struct.OctocrabBuilder.html#method.installation_token
+    /// [`OctocrabBuilder::installation`]: ./struct.OctocrabBuilder.html#method.installation
+    pub async fn installation_repos(&self) -> Result<Page<models::Repository>> {
+        let is_installation = match &self.auth {
+            Auth::InstallationToken(_) => true,
+            Auth::App(app) => app.has_installation(),
+            _ => false,
+        };
+
+        if !is_installation {
+            return Err(Error::Other {
+                source: "listing installation repositories requires an installation token".into(),
+                backtrace: Backtrace::generate(),
+            });
+        }
+
+        self.get_enveloped_page(
+            "/installation/repositories",
+            None::<&()>,
+            reqwest::header::HeaderMap::new(),
+            "repositories",
+        )
+        .await
+    }
+
+    /// Sends a GraphQL request with the given `body`, usually an object
+    /// containing the `query` and its `variables`, returning the body of the
+    /// response.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let response: serde_json::Value = octocrab::instance()
+    ///     .graphql(&serde_json::json!({ "query": "{ viewer { login } }" }))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn graphql<R: FromResponse>(&self, body: &(impl Serialize + ?Sized)) -> Result<R> {
+        self.graphql_with_previews(body, &[]).await
+    }
+
+    /// Sends the GraphQL `query` with its `variables`, returning the `data`
+    /// of the response. If GitHub responds with any `errors`, they're
+    /// returned as an [`Error::GraphQL`] instead, even if some of the data
+    /// could be fetched.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// #[derive(serde::Deserialize)]
+    /// struct Data {
+    ///     repository: Repository,
+    /// }
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// #[serde(rename_all = "camelCase")]
+    /// struct Repository {
+    ///     stargazer_count: u64,
+    /// }
+    ///
+    /// let data: Data = octocrab::instance()
+    ///     .graphql_query(
+    ///         "query($owner: String!, $name: String!) {
+    ///             repository(owner: $owner, name: $name) { stargazerCount }
+    ///         }",
+    ///         serde_json::json!({ "owner": "rust-lang", "name": "rust" }),
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Error::GraphQL`]: ./enum.Error.html#variant.GraphQL
+    pub async fn graphql_query<R: serde::de::DeserializeOwned>(
+        &self,
+        query: &str,
+        variables: serde_json::Value,
+    ) -> Result<R> {
+        #[derive(serde::Deserialize)]
+        struct Response {
+            #[serde(default)]
+            data: serde_json::Value,
+            #[serde(default)]
+            errors: Vec<GraphQLError>,
+        }
+
+        let response: Response = self
+            .graphql(&serde_json::json!({ "query": query, "variables": variables }))
+            .await?;
+
+        if !response.errors.is_empty() {
+            return Err(Error::GraphQL {
+                errors: response.errors,
+                backtrace: Backtrace::generate(),
+            });
+        }
+
+        serde_json::from_value(response.data.clone()).context(error::Json {
+            json: response.data,
+        })
+    }
+
+    /// Sends a GraphQL request like [`Octocrab::graphql`], additionally
+    /// opting into `previews` for this request only. Some GraphQL fields and
+    /// mutations are only available behind a preview, e.g. `bane` for label
+    /// mutations, `merge-info` for a pull request's `mergeStateStatus`, or
+    /// `flash` for deployment statuses. The previews set with
+    /// [`OctocrabBuilder::add_preview`] are still sent.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let response: serde_json::Value = octocrab::instance()
+    ///     .graphql_with_previews(
+    ///         &serde_json::json!({
+    ///             "query": "mutation { createLabel(input: { repositoryId: \"MDEwOlJlcG9zaXRvcnkxMjk2MjY5\", name: \"bug\", color: \"d73a4a\" }) { label { id } } }"
+    ///         }),
+    ///         &["bane"],
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Octocrab::graphql`]: ./struct.Octocrab.html#method.graphql
+    /// [`OctocrabBuilder::add_preview`]: ./struct.OctocrabBuilder.html#method.add_preview
+    pub async fn graphql_with_previews<R: FromResponse>(
+        &self,
+        body: &(impl Serialize + ?Sized),
+        previews: &[&str],
+    ) -> Result<R> {
+        let mut request = self.client.post(self.absolute_url("/graphql")?).json(body);
+
+        // Setting `Accept` on the request replaces the client's default
+        // previews, so they have to be sent again alongside the new ones.
+        if !previews.is_empty() {
+            for preview in self
+                .previews
+                .iter()
+                .copied()
+                .chain(previews.iter().copied())
+            {
+                request = request.header(reqwest::header::ACCEPT, format_preview(preview));
+            }
+        }
+
+        let response = self.execute(request).await?;
+        R::from_response(Self::map_github_error(response).await?).await
+    }
 }
 
 /// # HTTP Methods
@@ -386,13 +1061,265 @@ impl Octocrab {
         R::from_response(Self::map_github_error(response).await?).await
     }
 
+    /// Send a `GET` request to `route` with optional query parameters,
+    /// returning the body of the response as untyped JSON. This is useful for
+    /// endpoints that aren't modelled by `Octocrab` yet. An empty body, e.g.
+    /// of a `204 No Content` response, is returned as `Value::Null`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let runs = octocrab::instance()
+    ///     .get_value("/repos/owner/repo/actions/runs", Some(&[("status", "failure")]))
+    ///     .await?;
+    ///
+    /// println!("{} failed runs", runs["total_count"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_value<A, P>(
+        &self,
+        route: A,
+        parameters: Option<&P>,
+    ) -> Result<serde_json::Value>
+    where
+        A: AsRef<str>,
+        P: Serialize + ?Sized,
+    {
+        let response = self._get(self.absolute_url(route)?, parameters).await?;
+        Self::value_from_response(Self::map_github_error(response).await?).await
+    }
+
+    /// Send a `POST` request to `route` with an optional body, returning the
+    /// body of the response as untyped JSON, like [`Octocrab::get_value`].
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .post_value(
+    ///         "/repos/owner/repo/actions/workflows/ci.yml/dispatches",
+    ///         Some(&serde_json::json!({ "ref": "main" })),
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Octocrab::get_value`]: #method.get_value
+    pub async fn post_value<P: Serialize + ?Sized>(
+        &self,
+        route: impl AsRef<str>,
+        body: Option<&P>,
+    ) -> Result<serde_json::Value> {
+        let response = self._post(self.absolute_url(route)?, body).await?;
+        Self::value_from_response(Self::map_github_error(response).await?).await
+    }
+
+    async fn value_from_response(response: reqwest::Response) -> Result<serde_json::Value> {
+        let text = response.text().await.context(error::Http)?;
+
+        if text.trim().is_empty() {
+            return Ok(serde_json::Value::Null);
+        }
+
+        serde_json::from_str(&text).with_context(|| error::Json {
+            json: serde_json::Value::String(text.clone()),
+        })
+    }
+
+    /// Send a `GET` request to `route` with optional query parameters,
+    /// returning the raw bytes of the body of the response. This lets you
+    /// deserialize the response yourself, e.g. into types that borrow from
+    /// the body with `&str` or `Cow<str>` fields instead of allocating a
+    /// `String` for every field.
+    ///
+    /// *Note* Borrowed values can't outlive the `Bytes` they were
+    /// deserialized from, so keep the body alive for as long as you use
+    /// them. Strings that contain JSON escapes (e.g. `\n` or `\"`) can't
+    /// be borrowed, so prefer `#[serde(borrow)] Cow<str>` over `&str`, which
+    /// only allocates for those strings rather than failing.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use std::borrow::Cow;
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// struct Issue<'a> {
+    ///     number: u64,
+    ///     #[serde(borrow)]
+    ///     title: Cow<'a, str>,
+    /// }
+    ///
+    /// let body = octocrab::instance()
+    ///     .get_bytes("/repos/owner/repo/issues", None::<&()>)
+    ///     .await?;
+    /// let issues: Vec<Issue<'_>> = serde_json::from_slice(&body).unwrap();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_bytes<A, P>(&self, route: A, parameters: Option<&P>) -> Result<bytes::Bytes>
+    where
+        A: AsRef<str>,
+        P: Serialize + ?Sized,
+    {
+        let response = self._get(self.absolute_url(route)?, parameters).await?;
+        Self::map_github_error(response)
+            .await?
+            .bytes()
+            .await
+            .context(error::Http)
+    }
+
+    /// Send a conditional `GET` request to `route`, using the `ETag` of the
+    /// previous response to `route` if there is one. If GitHub responds with
+    /// `304 Not Modified`, the previous response's body is returned instead,
+    /// without counting against the rate limit.
+    pub(crate) async fn get_cached<R, A>(&self, route: A) -> Result<R>
+    where
+        A: AsRef<str>,
+        R: serde::de::DeserializeOwned,
+    {
+        let url = self.absolute_url(route)?;
+        let cached = self.etag_cache.get(&url);
+        let etag = cached.as_ref().map(|cached| cached.etag.clone());
+
+        let response = self
+            .get_if_none_match(url.clone(), None::<&()>, etag)
+            .await?;
+        let body = match cached {
+            Some(cached) if response.status() == reqwest::StatusCode::NOT_MODIFIED => cached.body,
+            _ => {
+                let etag = response.headers().get(reqwest::header::ETAG).cloned();
+                let body = response.text().await.context(error::Http)?;
+
+                if let Some(etag) = etag {
+                    self.etag_cache.insert(
+                        url,
+                        cache::CachedResponse {
+                            etag,
+                            body: body.clone(),
+                        },
+                    );
+                }
+
+                body
+            }
+        };
+
+        serde_json::from_str(&body).with_context(|| error::Json {
+            json: serde_json::from_str::<serde_json::Value>(&body).unwrap_or_default(),
+        })
+    }
+
+    /// Send a `GET` request for a page of results to `route`. If `etag` is
+    /// set, it's sent as `If-None-Match`, and if GitHub responds with
+    /// `304 Not Modified` an empty page with [`Page::not_modified`] set is
+    /// returned, without counting against the rate limit.
+    ///
+    /// [`Page::not_modified`]: ./struct.Page.html#structfield.not_modified
+    pub(crate) async fn get_page_if_none_match<T, A, P>(
+        &self,
+        route: A,
+        parameters: Option<&P>,
+        etag: Option<&str>,
+    ) -> Result<Page<T>>
+    where
+        A: AsRef<str>,
+        P: Serialize + ?Sized,
+        T: serde::de::DeserializeOwned,
+    {
+        let etag = etag
+            .map(reqwest::header::HeaderValue::from_str)
+            .transpose()
+            .map_err(|error| Box::new(error) as Box<_>)
+            .context(error::Other)?;
+        let response = self
+            .get_if_none_match(self.absolute_url(route)?, parameters, etag)
+            .await?;
+
+        Page::from_response(response).await
+    }
+
+    /// Send a `GET` request to `url` with `etag` as its `If-None-Match`
+    /// header, if there is one. Returns the response if GitHub responded with
+    /// `304 Not Modified`, and otherwise maps it like
+    /// [`Octocrab::map_github_error`].
+    async fn get_if_none_match<P: Serialize + ?Sized>(
+        &self,
+        url: Url,
+        parameters: Option<&P>,
+        etag: Option<reqwest::header::HeaderValue>,
+    ) -> Result<reqwest::Response> {
+        let mut headers = reqwest::header::HeaderMap::new();
+
+        if let Some(etag) = etag {
+            headers.insert(reqwest::header::IF_NONE_MATCH, etag);
+        }
+
+        let response = self._get_with(url, parameters, headers).await?;
+
+        if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+            Ok(response)
+        } else {
+            Self::map_github_error(response).await
+        }
+    }
+
     /// Send a `GET` request with no additional post-processing.
     pub async fn _get<P: Serialize + ?Sized>(
         &self,
         url: impl reqwest::IntoUrl,
         parameters: Option<&P>,
     ) -> Result<reqwest::Response> {
-        let mut request = self.client.get(url);
+        self._get_with(url, parameters, reqwest::header::HeaderMap::new())
+            .await
+    }
+
+    /// Send a `GET` request to `route` with optional query parameters and
+    /// additional `headers`, returning the body of the response. This is
+    /// useful for endpoints that need headers not yet modelled by `Octocrab`,
+    /// such as previews, custom media types, or `If-None-Match`.
+    ///
+    /// *Note* The `Authorization` and `User-Agent` headers are always set by
+    /// `Octocrab` and will be removed from `headers`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use reqwest::header::{HeaderMap, ACCEPT};
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert(ACCEPT, "application/vnd.github.v3.raw".parse().unwrap());
+    ///
+    /// let readme: serde_json::Value = octocrab::instance()
+    ///     .get_with("/repos/owner/repo/readme", None::<&()>, headers)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_with<R, A, P>(
+        &self,
+        route: A,
+        parameters: Option<&P>,
+        headers: reqwest::header::HeaderMap,
+    ) -> Result<R>
+    where
+        A: AsRef<str>,
+        P: Serialize + ?Sized,
+        R: FromResponse,
+    {
+        let response = self
+            ._get_with(self.absolute_url(route)?, parameters, headers)
+            .await?;
+        R::from_response(Self::map_github_error(response).await?).await
+    }
+
+    /// Send a `GET` request with additional `headers` and no additional
+    /// post-processing.
+    pub async fn _get_with<P: Serialize + ?Sized>(
+        &self,
+        url: impl reqwest::IntoUrl,
+        parameters: Option<&P>,
+        mut headers: reqwest::header::HeaderMap,
+    ) -> Result<reqwest::Response> {
+        headers.remove(reqwest::header::AUTHORIZATION);
+        headers.remove(reqwest::header::USER_AGENT);
+
+        let mut request = self.client.get(url).headers(headers);
 
         if let Some(parameters) = parameters {
             request = request.query(parameters);
@@ -482,9 +1409,117 @@ impl Octocrab {
         self.execute(request).await
     }
 
-    /// Execute the given `request` octocrab's Client.
+    /// Execute the given `request` octocrab's Client. If a [`RetryPolicy`] is
+    /// set, requests rejected by a rate limit are retried after waiting for
+    /// the limit to reset.
+    ///
+    /// [`RetryPolicy`]: ./struct.RetryPolicy.html
     pub async fn execute(&self, request: reqwest::RequestBuilder) -> Result<reqwest::Response> {
-        request.send().await.context(error::Http)
+        let mut request = request.build().context(error::Http)?;
+
+        if let Auth::App(app) = &self.auth {
+            let token = app.token(self).await?;
+            request.headers_mut().insert(
+                reqwest::header::AUTHORIZATION,
+                format!("Bearer {}", token).parse().unwrap(),
+            );
+        }
+
+        self.execute_without_app_auth(request).await
+    }
+
+    /// Like [`Octocrab::execute`], but without authenticating as a GitHub
+    /// App, which is used to exchange the App's JWT for an installation token.
+    pub(crate) async fn execute_without_app_auth(
+        &self,
+        mut request: reqwest::Request,
+    ) -> Result<reqwest::Response> {
+        for name in self.default_headers.keys() {
+            if let reqwest::header::Entry::Vacant(entry) = request.headers_mut().entry(name) {
+                let mut values = self.default_headers.get_all(name).iter().cloned();
+                let mut entry = entry.insert_entry(values.next().unwrap());
+
+                for value in values {
+                    entry.append(value);
+                }
+            }
+        }
+
+        if self.request_compression {
+            compress_body(&mut request)?;
+        }
+
+        if request.timeout().is_none() {
+            *request.timeout_mut() = self.request_timeout;
+        }
+
+        self.hooks.intercept(&mut request);
+
+        let method = request.method().clone();
+        let mut retries = 0;
+        let mut waited = std::time::Duration::default();
+
+        loop {
+            let retry = if self.retry_policy.is_some() || self.server_error_retry.is_some() {
+                request.try_clone()
+            } else {
+                None
+            };
+            let permit = match &self.concurrency {
+                Some(semaphore) => Some(semaphore.acquire().await),
+                None => None,
+            };
+            let result = self.client.execute(request).await.context(error::Http);
+
+            if let Ok(response) = &result {
+                self.rate_limit.record(response.headers());
+                self.hooks.observe(response);
+            }
+
+            drop(permit);
+
+            match (retry, self.retry_wait(retries, waited, &method, &result)) {
+                (Some(retry), Some(wait)) => {
+                    tokio::time::delay_for(wait).await;
+                    request = retry;
+                    retries += 1;
+                    waited += wait;
+                }
+                _ => return result,
+            }
+        }
+    }
+
+    /// How long to wait before retrying a `method` request that produced
+    /// `result`, or `None` if it shouldn't be retried. The rate limit retry
+    /// policy takes precedence, and both count towards the same `retries`.
+    fn retry_wait(
+        &self,
+        retries: u32,
+        waited: std::time::Duration,
+        method: &reqwest::Method,
+        result: &Result<reqwest::Response>,
+    ) -> Option<std::time::Duration> {
+        let wait = match (self.retry_policy, result) {
+            (Some(policy), Ok(response)) => policy.wait(
+                retries,
+                waited,
+                method,
+                response.status(),
+                response.headers(),
+            ),
+            _ => None,
+        };
+
+        wait.or_else(|| {
+            let server_error_retry = self.server_error_retry?;
+
+            if server_error_retry.should_retry(retries, method, result) {
+                Some(server_error_retry.delay(retries))
+            } else {
+                None
+            }
+        })
     }
 }
 
@@ -493,10 +1528,39 @@ impl Octocrab {
     /// Returns an absolute url version of `url` using the `base_url` (default:
     /// `https://api.github.com`)
     pub fn absolute_url(&self, url: impl AsRef<str>) -> Result<Url> {
-        Ok(self
-            .base_url
+        self.base_url
             .join(url.as_ref())
-            .context(crate::error::Url)?)
+            .context(crate::error::Url)
+    }
+
+    /// Maps a `204 No Content` response to `Ok(())`, and any other response
+    /// into a GitHub error.
+    pub(crate) async fn map_no_content(response: reqwest::Response) -> Result<()> {
+        if response.status() == 204 {
+            Ok(())
+        } else {
+            Self::map_github_error(response).await.map(drop)
+        }
+    }
+
+    /// Maps a `204 No Content` response to `Ok(true)` and a `404 Not Found`
+    /// response to `Ok(false)`, as returned by endpoints that check whether
+    /// something is the case. Any other response is an error.
+    pub(crate) async fn map_boolean_status(response: reqwest::Response) -> Result<bool> {
+        let status = response.status();
+
+        if status == 204 {
+            Ok(true)
+        } else if status == 404 {
+            Ok(false)
+        } else {
+            Self::map_github_error(response).await?;
+
+            Err(Error::Other {
+                source: format!("unexpected status {}", status).into(),
+                backtrace: Backtrace::generate(),
+            })
+        }
     }
 
     /// Maps a GitHub error response into and `Err()` variant if the status is
@@ -504,31 +1568,1585 @@ impl Octocrab {
     pub async fn map_github_error(response: reqwest::Response) -> Result<reqwest::Response> {
         if response.status().is_success() {
             Ok(response)
+        } else if response.status() == reqwest::StatusCode::SERVICE_UNAVAILABLE {
+            let source = error::GitHubError::from_response(response).await?;
+
+            Err(error::Error::ServiceUnavailable {
+                retry_after: source.retry_after(),
+                source,
+                backtrace: Backtrace::generate(),
+            })
+        } else if let Some(content_type) = error::non_json_content_type(response.headers()) {
+            let status = response.status();
+            let text = response.text().await.context(error::Http)?;
+
+            error::UnexpectedResponse {
+                status,
+                content_type,
+                body_snippet: error::snippet(&text),
+            }
+            .fail()
         } else {
             Err(error::Error::GitHub {
-                source: response
-                    .json::<error::GitHubError>()
-                    .await
-                    .context(error::Http)?,
+                source: error::GitHubError::from_response(response).await?,
                 backtrace: Backtrace::generate(),
             })
         }
     }
 
+    /// Collects the items of `page` and of every page after it, following
+    /// the `next` links until the last page.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::instance();
+    /// let page = octocrab.pulls("owner", "repo").list().per_page(100).send().await?;
+    /// let pulls = octocrab.all_pages(page).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn all_pages<R: serde::de::DeserializeOwned>(
+        &self,
+        mut page: Page<R>,
+    ) -> crate::Result<Vec<R>> {
+        let mut items = page.take_items();
+
+        while let Some(mut next) = page.next(self).await? {
+            items.append(&mut next.items);
+            page = next;
+        }
+
+        Ok(items)
+    }
+
+    /// Creates a stream over the items of `page` and of every page after it.
+    /// The next page is only requested once the items of the current page
+    /// have been consumed, and any error is returned as the last item of the
+    /// stream.
+    ///
+    /// *Note* This requires the `stream` feature.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use futures_util::TryStreamExt;
+    ///
+    /// let octocrab = octocrab::instance();
+    /// let page = octocrab.issues("owner", "repo").list().send().await?;
+    /// let mut issues = Box::pin(octocrab.into_stream(page));
+    ///
+    /// while let Some(issue) = issues.try_next().await? {
+    ///     println!("{}", issue.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "stream")]
+    pub fn into_stream<'octo, R: serde::de::DeserializeOwned + 'octo>(
+        &'octo self,
+        page: Page<R>,
+    ) -> impl futures_util::Stream<Item = crate::Result<R>> + 'octo {
+        let envelope_key = page.envelope_key;
+
+        futures_util::stream::try_unfold(
+            (page.items.into_iter(), page.next),
+            move |(mut items, mut next)| async move {
+                loop {
+                    if let Some(item) = items.next() {
+                        return Ok(Some((item, (items, next))));
+                    }
+
+                    match self.get_page_in::<R>(&next, envelope_key).await? {
+                        Some(page) => {
+                            items = page.items.into_iter();
+                            next = page.next;
+                        }
+                        None => return Ok(None),
+                    }
+                }
+            },
+        )
+    }
+
+    /// Like [`into_stream`], but requests up to `prefetch` of the following
+    /// pages concurrently, while still yielding the items in order. This can
+    /// considerably speed up crawling large lists.
+    ///
+    /// The pages are derived from the `last` link GitHub returns with `page`,
+    /// so if there's no such link this behaves like [`into_stream`]. At most
+    /// `prefetch` pages are buffered in memory at once, and no more pages
+    /// are prefetched than requests remain in the current rate limit window.
+    ///
+    /// *Note* This requires the `stream` feature.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use futures_util::TryStreamExt;
+    ///
+    /// let octocrab = octocrab::instance();
+    /// let page = octocrab
+    ///     .issues("owner", "repo")
+    ///     .list()
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// let mut issues = Box::pin(octocrab.into_stream_with_prefetch(page, 4));
+    ///
+    /// while let Some(issue) = issues.try_next().await? {
+    ///     println!("{}", issue.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`into_stream`]: #method.into_stream
+    #[cfg(feature = "stream")]
+    pub fn into_stream_with_prefetch<'octo, R: serde::de::DeserializeOwned + 'octo>(
+        &'octo self,
+        page: Page<R>,
+        prefetch: usize,
+    ) -> impl futures_util::Stream<Item = crate::Result<R>> + 'octo {
+        use futures_util::{future::Either, StreamExt, TryStreamExt};
+
+        let prefetch = self
+            .rate_limit_remaining()
+            .map_or(prefetch, |remaining| prefetch.min(remaining as usize));
+        let urls = match page.remaining_page_urls() {
+            Some(urls) if prefetch > 1 => urls,
+            _ => return Either::Right(self.into_stream(page)),
+        };
+
+        let envelope_key = page.envelope_key;
+        let pages = futures_util::stream::iter(urls)
+            .map(move |url| async move {
+                self.fetch_page::<R, ()>(url, None, reqwest::header::HeaderMap::new(), envelope_key)
+                    .await
+            })
+            .buffered(prefetch)
+            .map_ok(|page| futures_util::stream::iter(page.items).map(Ok))
+            .try_flatten()
+            // Like `into_stream`, end the stream after the first error.
+            .scan(false, |failed, item| {
+                let item = if *failed { None } else { Some(item) };
+                *failed = matches!(item, Some(Err(_)));
+                futures_util::future::ready(item)
+            });
+
+        Either::Left(futures_util::stream::iter(page.items).map(Ok).chain(pages))
+    }
+
     /// A convience method to get the a page of results (if present).
     pub async fn get_page<R: serde::de::DeserializeOwned>(
         &self,
         url: &Option<Url>,
+    ) -> crate::Result<Option<Page<R>>> {
+        self.get_page_in(url, None).await
+    }
+
+    /// Like [`Octocrab::get_page`], for results wrapped in an object under
+    /// `envelope_key`, see [`Octocrab::get_enveloped_page`].
+    pub(crate) async fn get_page_in<R: serde::de::DeserializeOwned>(
+        &self,
+        url: &Option<Url>,
+        envelope_key: Option<&'static str>,
     ) -> crate::Result<Option<Page<R>>> {
         match url {
-            Some(url) => self.get(url, None::<&()>).await.map(Some),
+            Some(url) => self
+                .fetch_page(
+                    url.clone(),
+                    None::<&()>,
+                    reqwest::header::HeaderMap::new(),
+                    envelope_key,
+                )
+                .await
+                .map(Some),
             None => Ok(None),
         }
     }
+
+    /// Send a `GET` request to `route` for a page of results that GitHub
+    /// wraps in an object under `envelope_key`, e.g. `check_runs` for
+    /// `{ "total_count": 1, "check_runs": [...] }`.
+    pub(crate) async fn get_enveloped_page<R, A, P>(
+        &self,
+        route: A,
+        parameters: Option<&P>,
+        headers: reqwest::header::HeaderMap,
+        envelope_key: &'static str,
+    ) -> Result<Page<R>>
+    where
+        A: AsRef<str>,
+        P: Serialize + ?Sized,
+        R: serde::de::DeserializeOwned,
+    {
+        self.fetch_page(
+            self.absolute_url(route)?,
+            parameters,
+            headers,
+            Some(envelope_key),
+        )
+        .await
+    }
+
+    async fn fetch_page<R, P>(
+        &self,
+        url: Url,
+        parameters: Option<&P>,
+        headers: reqwest::header::HeaderMap,
+        envelope_key: Option<&'static str>,
+    ) -> Result<Page<R>>
+    where
+        P: Serialize + ?Sized,
+        R: serde::de::DeserializeOwned,
+    {
+        let response = self._get_with(url, parameters, headers).await?;
+
+        Page::from_response_in(Self::map_github_error(response).await?, envelope_key).await
+    }
+}
+
+/// Replaces the body of `request` (if present) with a gzip compressed version.
+fn compress_body(request: &mut reqwest::Request) -> Result<()> {
+    use std::io::Write;
+
+    let body = match request.body().and_then(reqwest::Body::as_bytes) {
+        Some(body) => body,
+        None => return Ok(()),
+    };
+
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    let compressed = encoder
+        .write_all(body)
+        .and_then(|_| encoder.finish())
+        .map_err(|error| Box::new(error) as Box<_>)
+        .context(error::Other)?;
+
+    *request.body_mut() = Some(compressed.into());
+    request.headers_mut().insert(
+        reqwest::header::CONTENT_ENCODING,
+        reqwest::header::HeaderValue::from_static("gzip"),
+    );
+
+    Ok(())
+}
+
+/// Builds a client that sends its requests to `server`.
+#[cfg(test)]
+pub(crate) fn setup(server: &wiremock::MockServer) -> Octocrab {
+    Octocrab::builder()
+        .base_url(&server.uri())
+        .unwrap()
+        .build()
+        .unwrap()
 }
 
 #[cfg(test)]
 mod tests {
+    use reqwest::header::{HeaderMap, AUTHORIZATION};
+    use wiremock::{
+        matchers::{body_json, header, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[tokio::test]
+    async fn rate_limited_until() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/exhausted"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("x-ratelimit-remaining", "0")
+                    .insert_header("x-ratelimit-reset", "1600000000")
+                    .set_body_json(serde_json::json!({})),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/available"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("x-ratelimit-remaining", "4999")
+                    .insert_header("x-ratelimit-reset", "1600003600")
+                    .set_body_json(serde_json::json!({})),
+            )
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        assert!(octocrab.rate_limited_until().is_none());
+
+        let _: serde_json::Value = octocrab.get("/exhausted", None::<&()>).await.unwrap();
+        assert_eq!(
+            octocrab.clone().rate_limited_until(),
+            Some("2020-09-13T12:26:40Z".parse().unwrap())
+        );
+
+        let _: serde_json::Value = octocrab.get("/available", None::<&()>).await.unwrap();
+        assert!(octocrab.rate_limited_until().is_none());
+    }
+
+    #[tokio::test]
+    async fn rate_limit_ignores_other_resources() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/core"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("x-ratelimit-remaining", "4999")
+                    .insert_header("x-ratelimit-reset", "1600003600")
+                    .insert_header("x-ratelimit-resource", "core")
+                    .set_body_json(serde_json::json!({})),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/search"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("x-ratelimit-remaining", "0")
+                    .insert_header("x-ratelimit-reset", "1600000060")
+                    .insert_header("x-ratelimit-resource", "search")
+                    .set_body_json(serde_json::json!({})),
+            )
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let _: serde_json::Value = octocrab.get("/core", None::<&()>).await.unwrap();
+        let _: serde_json::Value = octocrab.get("/search", None::<&()>).await.unwrap();
+
+        assert!(octocrab.rate_limited_until().is_none());
+        assert_eq!(octocrab.rate_limit_remaining(), Some(4999));
+    }
+
+    #[tokio::test]
+    async fn ratelimit() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rate_limit"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("x-ratelimit-remaining", "4999")
+                    .insert_header("x-ratelimit-reset", "1372700873")
+                    .set_body_json(serde_json::json!({
+                        "resources": {
+                            "core": {
+                                "limit": 5000,
+                                "remaining": 4999,
+                                "reset": 1372700873,
+                                "used": 1
+                            },
+                            "search": {
+                                "limit": 30,
+                                "remaining": 18,
+                                "reset": 1372697452,
+                                "used": 12
+                            }
+                        },
+                        "rate": {
+                            "limit": 5000,
+                            "remaining": 4999,
+                            "reset": 1372700873,
+                            "used": 1
+                        }
+                    })),
+            )
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        assert!(octocrab.rate_limit_remaining().is_none());
+
+        let ratelimit = octocrab.ratelimit().await.unwrap();
+        assert_eq!(ratelimit.resources.core.limit, 5000);
+        assert_eq!(ratelimit.resources.core.used, 1);
+        assert_eq!(ratelimit.resources.core.reset.timestamp(), 1372700873);
+        assert_eq!(ratelimit.resources.search.remaining, 18);
+        assert_eq!(octocrab.rate_limit_remaining(), Some(4999));
+    }
+
+    #[tokio::test]
+    async fn retries_rate_limited_requests() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/limited"))
+            .respond_with(
+                ResponseTemplate::new(403)
+                    .insert_header("retry-after", "0")
+                    .set_body_json(serde_json::json!({
+                        "message": "You have exceeded a secondary rate limit."
+                    })),
+            )
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/limited"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::Octocrab::builder()
+            .base_url(&server.uri())
+            .unwrap()
+            .retry(crate::RetryPolicy::new(2))
+            .build()
+            .unwrap();
+
+        let _: serde_json::Value = octocrab.get("/limited", None::<&()>).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn retry_on_server_error() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/flaky"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/flaky"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/missing"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/flaky"))
+            .respond_with(ResponseTemplate::new(502))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::Octocrab::builder()
+            .base_url(&server.uri())
+            .unwrap()
+            .retry_on_server_error(2, std::time::Duration::from_millis(1))
+            .build()
+            .unwrap();
+
+        let _: serde_json::Value = octocrab.get("/flaky", None::<&()>).await.unwrap();
+        let error = octocrab
+            .get::<serde_json::Value, _, _>("/missing", None::<&()>)
+            .await
+            .unwrap_err();
+        assert!(error.is_not_found());
+        let error = octocrab
+            .post::<(), serde_json::Value>("/flaky", None)
+            .await
+            .unwrap_err();
+        assert_eq!(error.status_code(), Some(reqwest::StatusCode::BAD_GATEWAY));
+    }
+
+    #[tokio::test]
+    async fn retries_share_one_limit() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/unavailable"))
+            .respond_with(ResponseTemplate::new(503).insert_header("retry-after", "0"))
+            .expect(4)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/unavailable"))
+            .respond_with(ResponseTemplate::new(503).insert_header("retry-after", "0"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::Octocrab::builder()
+            .base_url(&server.uri())
+            .unwrap()
+            .retry(crate::RetryPolicy::new(2))
+            .retry_on_server_error(3, std::time::Duration::from_millis(1))
+            .build()
+            .unwrap();
+
+        let error = octocrab
+            .get::<serde_json::Value, _, _>("/unavailable", None::<&()>)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error.status_code(),
+            Some(reqwest::StatusCode::SERVICE_UNAVAILABLE)
+        );
+        let error = octocrab
+            .post::<(), serde_json::Value>("/unavailable", None)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error.status_code(),
+            Some(reqwest::StatusCode::SERVICE_UNAVAILABLE)
+        );
+    }
+
+    #[tokio::test]
+    async fn with_client() {
+        use wiremock::matchers::header;
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/custom"))
+            .and(header("user-agent", crate::DEFAULT_USER_AGENT))
+            .and(header("authorization", "Bearer secret"))
+            .and(header(
+                "accept",
+                "application/vnd.github.machine-man-preview",
+            ))
+            .and(header("x-custom", "yes"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/preview"))
+            .and(header("accept", "application/vnd.github.mercy-preview"))
+            .and(header("authorization", "Bearer secret"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-custom", "yes".parse().unwrap());
+        let client = reqwest::Client::builder()
+            .default_headers(headers)
+            .build()
+            .unwrap();
+        let octocrab = crate::Octocrab::builder()
+            .base_url(&server.uri())
+            .unwrap()
+            .personal_token("secret".into())
+            .add_preview("machine-man")
+            .with_client(client)
+            .build()
+            .unwrap();
+
+        let _: serde_json::Value = octocrab.get("/custom", None::<&()>).await.unwrap();
+        let _: serde_json::Value = octocrab
+            .get_with("/preview", None::<&()>, crate::preview_headers("mercy"))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn max_concurrent_requests() {
+        use std::sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        };
+
+        /// Counts the requests that arrived but whose response hasn't been
+        /// observed by the client yet.
+        struct InFlight {
+            current: Arc<AtomicUsize>,
+            max: Arc<AtomicUsize>,
+        }
+
+        impl wiremock::Respond for InFlight {
+            fn respond(&self, _: &wiremock::Request) -> ResponseTemplate {
+                let current = self.current.fetch_add(1, Ordering::SeqCst) + 1;
+                self.max.fetch_max(current, Ordering::SeqCst);
+                // Delay the response so that requests overlap if they can.
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({}))
+                    .set_delay(std::time::Duration::from_millis(50))
+            }
+        }
+
+        let current = Arc::new(AtomicUsize::new(0));
+        let max = Arc::new(AtomicUsize::new(0));
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/slow"))
+            .respond_with(InFlight {
+                current: current.clone(),
+                max: max.clone(),
+            })
+            .expect(6)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::Octocrab::builder()
+            .base_url(&server.uri())
+            .unwrap()
+            .max_concurrent_requests(2)
+            .with_response_observer(move |_| {
+                current.fetch_sub(1, Ordering::SeqCst);
+            })
+            .build()
+            .unwrap();
+        let tasks = (0..6)
+            .map(|_| {
+                let octocrab = octocrab.clone();
+                tokio::spawn(async move {
+                    octocrab
+                        .get::<serde_json::Value, _, _>("/slow", None::<&()>)
+                        .await
+                        .unwrap()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        assert!(max.load(Ordering::SeqCst) <= 2);
+        assert!(crate::Octocrab::builder()
+            .max_concurrent_requests(0)
+            .build()
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn service_unavailable() {
+        let server = MockServer::start().await;
+        let unavailable = |retry_after| {
+            ResponseTemplate::new(503)
+                .insert_header("retry-after", retry_after)
+                .set_body_json(serde_json::json!({
+                    "message": "GitHub is down for scheduled maintenance."
+                }))
+        };
+        Mock::given(method("GET"))
+            .and(path("/maintenance"))
+            .respond_with(unavailable("600"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/flaky"))
+            .respond_with(unavailable("0"))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/flaky"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::Octocrab::builder()
+            .base_url(&server.uri())
+            .unwrap()
+            .retry(crate::RetryPolicy::new(1))
+            .build()
+            .unwrap();
+
+        // Waiting 10 minutes exceeds the policy's `max_wait`.
+        match octocrab
+            .get::<serde_json::Value, _, _>("/maintenance", None::<&()>)
+            .await
+        {
+            Err(crate::Error::ServiceUnavailable { retry_after, .. }) => {
+                assert_eq!(retry_after, Some(std::time::Duration::from_secs(600)))
+            }
+            result => panic!("expected `ServiceUnavailable`, got {:?}", result),
+        }
+
+        let _: serde_json::Value = octocrab.get("/flaky", None::<&()>).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn rate_limited_requests_are_not_retried_by_default() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/limited"))
+            .respond_with(
+                ResponseTemplate::new(429)
+                    .insert_header("retry-after", "0")
+                    .set_body_json(serde_json::json!({ "message": "Too many requests" })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+
+        let error = octocrab
+            .get::<serde_json::Value, _, _>("/limited", None::<&()>)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error.as_github().unwrap().status_code,
+            reqwest::StatusCode::TOO_MANY_REQUESTS
+        );
+    }
+
+    #[tokio::test]
+    async fn get_bytes_deserializes_without_copying() {
+        use std::borrow::Cow;
+
+        #[derive(serde::Deserialize)]
+        struct Issue<'a> {
+            number: u64,
+            #[serde(borrow)]
+            title: Cow<'a, str>,
+        }
+
+        let issues: Vec<_> = (0..1000)
+            .map(|number| serde_json::json!({ "number": number, "title": "A title" }))
+            .collect();
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/issues"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(issues))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/missing/issues"))
+            .respond_with(
+                ResponseTemplate::new(404)
+                    .set_body_json(serde_json::json!({ "message": "Not Found" })),
+            )
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let body = octocrab
+            .get_bytes("/repos/owner/repo/issues", None::<&()>)
+            .await
+            .unwrap();
+        let issues: Vec<Issue<'_>> = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(issues.len(), 1000);
+        assert_eq!(issues[999].number, 999);
+        let body_range = body.as_ptr_range();
+        assert!(issues.iter().all(|issue| match &issue.title {
+            Cow::Borrowed(title) => body_range.contains(&title.as_ptr()),
+            Cow::Owned(_) => false,
+        }));
+
+        let error = octocrab
+            .get_bytes("/repos/owner/missing/issues", None::<&()>)
+            .await
+            .unwrap_err();
+        assert!(error.is_not_found());
+    }
+
+    #[tokio::test]
+    async fn hooks() {
+        use std::sync::{Arc, Mutex};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/pulls/1"))
+            .and(header("x-correlation-id", "4a5b6c"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/plain")
+                    .set_body_string("diff --git a/README b/README\n"),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let statuses = Arc::new(Mutex::new(Vec::new()));
+        let octocrab = crate::Octocrab::builder()
+            .base_url(&server.uri())
+            .unwrap()
+            .with_request_interceptor(|request| {
+                request
+                    .headers_mut()
+                    .insert("x-correlation-id", "4a5b6c".parse().unwrap());
+            })
+            .with_response_observer({
+                let statuses = statuses.clone();
+                move |response| statuses.lock().unwrap().push(response.status())
+            })
+            .build()
+            .unwrap();
+
+        let diff = octocrab.pulls("owner", "repo").diff(1).await.unwrap();
+        assert_eq!(diff, "diff --git a/README b/README\n");
+        let _ = octocrab.repos("owner", "missing").get().await.unwrap_err();
+
+        assert_eq!(
+            *statuses.lock().unwrap(),
+            [reqwest::StatusCode::OK, reqwest::StatusCode::NOT_FOUND]
+        );
+    }
+
+    #[tokio::test]
+    async fn redirects_only_send_authorization_to_the_same_host() {
+        let server = MockServer::start().await;
+        let storage = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/moved"))
+            .respond_with(ResponseTemplate::new(301).insert_header("location", "/assets/1"))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/assets/1"))
+            .and(header("authorization", "Bearer secret"))
+            .respond_with(
+                ResponseTemplate::new(302)
+                    .insert_header("location", &*format!("{}/signed/1", storage.uri())),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/signed/1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&storage)
+            .await;
+
+        let octocrab = crate::Octocrab::builder()
+            .personal_token(String::from("secret"))
+            .base_url(&server.uri())
+            .unwrap()
+            .build()
+            .unwrap();
+        let _: serde_json::Value = octocrab.get("/moved", None::<&()>).await.unwrap();
+
+        let requests = storage.received_requests().await.unwrap();
+        assert!(!requests[0].headers.contains_key(&"authorization".into()));
+
+        let octocrab = crate::Octocrab::builder()
+            .base_url(&server.uri())
+            .unwrap()
+            .redirect_policy(reqwest::redirect::Policy::none())
+            .build()
+            .unwrap();
+        let response = octocrab
+            ._get(octocrab.absolute_url("/moved").unwrap(), None::<&()>)
+            .await
+            .unwrap();
+        assert_eq!(response.status(), reqwest::StatusCode::MOVED_PERMANENTLY);
+    }
+
+    #[tokio::test]
+    async fn timeout() {
+        use std::time::Duration;
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/slow"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({}))
+                    .set_delay(Duration::from_millis(500)),
+            )
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::Octocrab::builder()
+            .base_url(&server.uri())
+            .unwrap()
+            .timeout(Duration::from_millis(50))
+            .build()
+            .unwrap();
+
+        let error = octocrab
+            .get::<serde_json::Value, _, _>("/slow", None::<&()>)
+            .await
+            .unwrap_err();
+        assert!(error.is_timeout());
+
+        let _: serde_json::Value = octocrab
+            .with_timeout(Duration::from_secs(5))
+            .get("/slow", None::<&()>)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn codes_of_conduct() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/codes_of_conduct"))
+            .and(header(
+                "accept",
+                "application/vnd.github.scarlet-witch-preview",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {
+                    "key": "citizen_code_of_conduct",
+                    "name": "Citizen Code of Conduct",
+                    "url": "https://api.github.com/codes_of_conduct/citizen_code_of_conduct",
+                    "html_url": "http://citizencodeofconduct.org/"
+                },
+                {
+                    "key": "contributor_covenant",
+                    "name": "Contributor Covenant",
+                    "url": "https://api.github.com/codes_of_conduct/contributor_covenant",
+                    "html_url": "https://www.contributor-covenant.org/version/2/0/code_of_conduct/"
+                }
+            ])))
+            .mount(&server)
+            .await;
+
+        let codes_of_conduct = crate::setup(&server).codes_of_conduct().await.unwrap();
+
+        let keys: Vec<_> = codes_of_conduct
+            .iter()
+            .map(|code_of_conduct| code_of_conduct.key.as_str())
+            .collect();
+        assert_eq!(keys, ["citizen_code_of_conduct", "contributor_covenant"]);
+        assert!(codes_of_conduct[0].body.is_none());
+    }
+
+    #[tokio::test]
+    async fn untyped_requests() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/actions/runs"))
+            .and(wiremock::matchers::query_param("status", "failure"))
+            .and(header("authorization", "Bearer secret"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "total_count": 2, "workflow_runs": [] })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path(
+                "/repos/owner/repo/actions/workflows/ci.yml/dispatches",
+            ))
+            .and(body_json(serde_json::json!({ "ref": "main" })))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::Octocrab::builder()
+            .base_url(&server.uri())
+            .unwrap()
+            .personal_token("secret".to_owned())
+            .build()
+            .unwrap();
+
+        let runs = octocrab
+            .get_value(
+                "repos/owner/repo/actions/runs",
+                Some(&[("status", "failure")]),
+            )
+            .await
+            .unwrap();
+        assert_eq!(runs["total_count"], 2);
+
+        let dispatched = octocrab
+            .post_value(
+                "/repos/owner/repo/actions/workflows/ci.yml/dispatches",
+                Some(&serde_json::json!({ "ref": "main" })),
+            )
+            .await
+            .unwrap();
+        assert_eq!(dispatched, serde_json::Value::Null);
+    }
+
+    #[tokio::test]
+    async fn graphql_query() {
+        let server = MockServer::start().await;
+        let query = "query($login: String!) { user(login: $login) { name } }";
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_json(serde_json::json!({
+                "query": query,
+                "variables": { "login": "octocat" }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "user": { "name": "The Octocat" } }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "user": null },
+                "errors": [{
+                    "type": "NOT_FOUND",
+                    "path": ["user"],
+                    "locations": [{ "line": 1, "column": 27 }],
+                    "message": "Could not resolve to a User with the login of 'ghost'."
+                }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+
+        let data: serde_json::Value = octocrab
+            .graphql_query(query, serde_json::json!({ "login": "octocat" }))
+            .await
+            .unwrap();
+        assert_eq!(data["user"]["name"], "The Octocat");
+
+        match octocrab
+            .graphql_query::<serde_json::Value>(query, serde_json::json!({ "login": "ghost" }))
+            .await
+        {
+            Err(crate::Error::GraphQL { errors, .. }) => {
+                assert_eq!(errors.len(), 1);
+                assert_eq!(errors[0].error_type.as_deref(), Some("NOT_FOUND"));
+                assert_eq!(errors[0].path, [serde_json::json!("user")]);
+            }
+            result => panic!("expected a GraphQL error, got {:?}", result),
+        }
+    }
+
+    #[tokio::test]
+    async fn graphql_sends_previews() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(|request: &wiremock::Request| {
+                let accept = request.headers.get(&"accept".into()).map(|values| {
+                    values
+                        .iter()
+                        .map(|value| value.as_str())
+                        .collect::<Vec<_>>()
+                });
+
+                accept
+                    == Some(vec![
+                        "application/vnd.github.machine-man-preview",
+                        "application/vnd.github.bane-preview",
+                    ])
+            })
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "data": {} })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::Octocrab::builder()
+            .add_preview("machine-man")
+            .base_url(&server.uri())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let response: serde_json::Value = octocrab
+            .graphql_with_previews(
+                &serde_json::json!({ "query": "{ viewer { login } }" }),
+                &["bane"],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response, serde_json::json!({ "data": {} }));
+    }
+
+    #[tokio::test]
+    async fn user_agent() {
+        use wiremock::matchers::header;
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/default"))
+            .and(header(
+                "user-agent",
+                &*format!("octocrab/{}", env!("CARGO_PKG_VERSION")),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/custom"))
+            .and(header("user-agent", "my-app/1.0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let _: serde_json::Value = octocrab.get("/default", None::<&()>).await.unwrap();
+
+        let octocrab = crate::Octocrab::builder()
+            .base_url(&server.uri())
+            .unwrap()
+            .user_agent("my-app/1.0")
+            .build()
+            .unwrap();
+        let _: serde_json::Value = octocrab.post("/custom", None::<&()>).await.unwrap();
+
+        assert!(crate::Octocrab::builder()
+            .user_agent("my-app\n")
+            .build()
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn get_with_sends_custom_headers() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/custom"))
+            .and(header("x-custom", "octocrab"))
+            .and(header("authorization", "Bearer secret"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::Octocrab::builder()
+            .personal_token(String::from("secret"))
+            .base_url(&server.uri())
+            .unwrap()
+            .build()
+            .unwrap();
+        let mut headers = HeaderMap::new();
+        headers.insert("x-custom", "octocrab".parse().unwrap());
+        headers.insert(AUTHORIZATION, "Bearer overridden".parse().unwrap());
+
+        let _: serde_json::Value = octocrab
+            .get_with("/custom", None::<&()>, headers)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn request_compression() {
+        use std::io::Read;
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/compressed"))
+            .and(header("content-encoding", "gzip"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::Octocrab::builder()
+            .base_url(&server.uri())
+            .unwrap()
+            .request_compression(true)
+            .build()
+            .unwrap();
+        let body = serde_json::json!({ "body": "A very long issue body" });
+
+        let _: serde_json::Value = octocrab.post("/compressed", Some(&body)).await.unwrap();
+
+        let requests = server.received_requests().await.unwrap();
+        let mut decompressed = String::new();
+        flate2::read::GzDecoder::new(&*requests[0].body)
+            .read_to_string(&mut decompressed)
+            .unwrap();
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&decompressed).unwrap(),
+            body
+        );
+    }
+
+    #[cfg(feature = "gzip")]
+    #[tokio::test]
+    async fn accept_compression() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .mount(&server)
+            .await;
+
+        for &enabled in &[true, false] {
+            let octocrab = crate::Octocrab::builder()
+                .base_url(&server.uri())
+                .unwrap()
+                .accept_compression(enabled)
+                .build()
+                .unwrap();
+            let _: serde_json::Value = octocrab.get("/", None::<&()>).await.unwrap();
+        }
+        let _: serde_json::Value = crate::setup(&server).get("/", None::<&()>).await.unwrap();
+
+        let requests = server.received_requests().await.unwrap();
+        let accepts_gzip = |request: &wiremock::Request| {
+            request
+                .headers
+                .get(&"accept-encoding".into())
+                .map(|values| values.iter().any(|value| value.as_str().contains("gzip")))
+                .unwrap_or(false)
+        };
+        assert!(accepts_gzip(&requests[0]));
+        assert!(!accepts_gzip(&requests[1]));
+        assert!(accepts_gzip(&requests[2]), "compression is on by default");
+    }
+
+    #[cfg(feature = "gzip")]
+    #[tokio::test]
+    async fn gzip_response() {
+        use std::io::Write;
+
+        let event: serde_json::Value = serde_json::from_str(include_str!(
+            "../tests/resources/cross_referenced_event.json"
+        ))
+        .unwrap();
+        let issues = serde_json::json!([event["source"]["issue"]]);
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(issues.to_string().as_bytes()).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/octocat/Hello-World/issues"))
+            .and(wiremock::matchers::header("accept-encoding", "gzip"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-encoding", "gzip")
+                    .set_body_raw(compressed, "application/json"),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        let octocrab = crate::setup(&server);
+
+        let page = octocrab
+            .issues("octocat", "Hello-World")
+            .list()
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.items[0].number, 42);
+        assert_eq!(page.items[0].title, "Fix the Hello-World bug");
+    }
+
+    #[tokio::test]
+    async fn installation_repos() {
+        let server = MockServer::start().await;
+        let repository: serde_json::Value =
+            serde_json::from_str(include_str!("../tests/resources/repository.json")).unwrap();
+        Mock::given(method("GET"))
+            .and(path("/installation/repositories"))
+            .and(header("authorization", "Bearer ghs_installation"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 1,
+                "repository_selection": "selected",
+                "repositories": [repository],
+            })))
+            .mount(&server)
+            .await;
+
+        let page = crate::Octocrab::builder()
+            .base_url(&server.uri())
+            .unwrap()
+            .installation_token("ghs_installation".to_owned())
+            .build()
+            .unwrap()
+            .installation_repos()
+            .await
+            .unwrap();
+
+        assert_eq!(page.total_count, Some(1));
+        assert_eq!(page.items[0].full_name, "octocat/Hello-World");
+    }
+
+    fn app_key() -> crate::EncodingKey {
+        crate::EncodingKey::from_rsa_pem(include_bytes!("../tests/resources/github_app_key.pem"))
+            .unwrap()
+    }
+
+    /// Matches requests authenticated with a valid JWT for the app `12345`.
+    fn app_jwt(request: &wiremock::Request) -> bool {
+        #[derive(serde::Deserialize)]
+        struct Claims {
+            iss: u64,
+        }
+
+        let token = request
+            .headers
+            .get(&"authorization".into())
+            .and_then(|values| values.last().as_str().strip_prefix("Bearer "));
+        let key = jsonwebtoken::DecodingKey::from_rsa_pem(include_bytes!(
+            "../tests/resources/github_app_key.pub.pem"
+        ))
+        .unwrap();
+
+        let data = match token {
+            Some(token) => jsonwebtoken::decode::<Claims>(
+                token,
+                &key,
+                &jsonwebtoken::Validation::new(jsonwebtoken::Algorithm::RS256),
+            ),
+            None => return false,
+        };
+        matches!(data, Ok(data) if data.claims.iss == 12345)
+    }
+
+    async fn mount_installation_token(server: &MockServer, expires_in: i64, expected: u64) {
+        Mock::given(method("POST"))
+            .and(path("/app/installations/67890/access_tokens"))
+            .and(app_jwt)
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "token": "ghs_installation",
+                "expires_at": chrono::Utc::now() + chrono::Duration::seconds(expires_in),
+                "permissions": { "metadata": "read" },
+                "repository_selection": "all"
+            })))
+            .expect(expected)
+            .mount(server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/installation/repositories"))
+            .and(header("authorization", "Bearer ghs_installation"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 0,
+                "repositories": [],
+            })))
+            .expect(2)
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn app_installation_token_is_reused() {
+        let server = MockServer::start().await;
+        mount_installation_token(&server, 3600, 1).await;
+
+        let octocrab = crate::Octocrab::builder()
+            .base_url(&server.uri())
+            .unwrap()
+            .app(12345, app_key())
+            .installation(67890)
+            .build()
+            .unwrap();
+
+        octocrab.installation_repos().await.unwrap();
+        octocrab.installation_repos().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn app_installation_token_is_refreshed_before_expiry() {
+        let server = MockServer::start().await;
+        mount_installation_token(&server, 60, 2).await;
+
+        let octocrab = crate::Octocrab::builder()
+            .base_url(&server.uri())
+            .unwrap()
+            .app(12345, app_key())
+            .installation(67890)
+            .build()
+            .unwrap();
+
+        octocrab.installation_repos().await.unwrap();
+        octocrab.installation_repos().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn app_installation_token_with_client() {
+        let server = MockServer::start().await;
+        // GitHub rejects requests without a `User-Agent`.
+        Mock::given(method("POST"))
+            .and(path("/app/installations/67890/access_tokens"))
+            .and(|request: &wiremock::Request| !request.headers.contains_key(&"user-agent".into()))
+            .respond_with(ResponseTemplate::new(403))
+            .expect(0)
+            .mount(&server)
+            .await;
+        mount_installation_token(&server, 3600, 1).await;
+
+        let octocrab = crate::Octocrab::builder()
+            .base_url(&server.uri())
+            .unwrap()
+            .app(12345, app_key())
+            .installation(67890)
+            .with_client(reqwest::Client::new())
+            .build()
+            .unwrap();
+
+        octocrab.installation_repos().await.unwrap();
+        octocrab.installation_repos().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn app_authenticates_with_jwt() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/app"))
+            .and(app_jwt)
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::Octocrab::builder()
+            .base_url(&server.uri())
+            .unwrap()
+            .app(12345, app_key())
+            .build()
+            .unwrap();
+
+        let _: serde_json::Value = octocrab.get("/app", None::<&()>).await.unwrap();
+        assert!(matches!(
+            octocrab.installation_repos().await,
+            Err(crate::Error::Other { .. })
+        ));
+        assert!(crate::Octocrab::builder()
+            .installation(67890)
+            .build()
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn installation_repos_requires_installation_token() {
+        let octocrab = crate::Octocrab::builder()
+            .personal_token("ghp_personal".to_owned())
+            .build()
+            .unwrap();
+
+        assert!(matches!(
+            octocrab.installation_repos().await,
+            Err(crate::Error::Other { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn github_error_keeps_status_and_headers() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/rate-limited"))
+            .respond_with(
+                ResponseTemplate::new(403)
+                    .insert_header("retry-after", "30")
+                    .insert_header("x-ratelimit-remaining", "0")
+                    .insert_header("x-ratelimit-reset", "1372700873")
+                    .set_body_json(serde_json::json!({
+                        "message": "API rate limit exceeded",
+                        "documentation_url": "https://docs.github.com/rest",
+                    })),
+            )
+            .mount(&server)
+            .await;
+        let octocrab = crate::setup(&server);
+
+        let error = match octocrab
+            .get::<serde_json::Value, _, _>("/rate-limited", None::<&()>)
+            .await
+        {
+            Err(crate::Error::GitHub { source, .. }) => source,
+            result => panic!("expected a GitHub error, got {:?}", result),
+        };
+
+        assert_eq!(error.status_code, 403);
+        assert_eq!(error.message, "API rate limit exceeded");
+        assert_eq!(
+            error.retry_after(),
+            Some(std::time::Duration::from_secs(30))
+        );
+        assert_eq!(error.rate_limit_remaining(), Some(0));
+        assert_eq!(error.rate_limit_reset().unwrap().timestamp(), 1372700873);
+    }
+
+    #[tokio::test]
+    async fn error_from_non_json_body() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/proxy"))
+            .respond_with(
+                ResponseTemplate::new(502).set_body_string("<html><body>Bad Gateway</body></html>"),
+            )
+            .mount(&server)
+            .await;
+        let octocrab = crate::setup(&server);
+
+        let error = octocrab
+            .get::<serde_json::Value, _, _>("/proxy", None::<&()>)
+            .await
+            .unwrap_err();
+
+        match &error {
+            crate::Error::UnexpectedResponse {
+                status,
+                content_type,
+                body_snippet,
+                ..
+            } => {
+                assert_eq!(*status, 502);
+                assert_eq!(content_type.as_deref(), Some("text/plain"));
+                assert_eq!(body_snippet, "<html><body>Bad Gateway</body></html>");
+            }
+            error => panic!("expected an unexpected response, got {:?}", error),
+        }
+    }
+
+    #[tokio::test]
+    async fn github_error_from_invalid_json() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/proxy"))
+            .respond_with(
+                ResponseTemplate::new(502).set_body_raw("Bad Gateway", "application/json"),
+            )
+            .mount(&server)
+            .await;
+        let octocrab = crate::setup(&server);
+
+        let error = match octocrab
+            .get::<serde_json::Value, _, _>("/proxy", None::<&()>)
+            .await
+        {
+            Err(crate::Error::GitHub { source, .. }) => source,
+            result => panic!("expected a GitHub error, got {:?}", result),
+        };
+
+        assert_eq!(error.status_code, 502);
+        assert_eq!(error.documentation_url, None);
+        assert_eq!(error.message, "502 Bad Gateway: Bad Gateway");
+    }
+
+    #[tokio::test]
+    async fn unexpected_response() {
+        let server = MockServer::start().await;
+        let page = format!("<html><body>{}</body></html>", "Bad Gateway ".repeat(100));
+        Mock::given(method("GET"))
+            .and(path("/proxy"))
+            .respond_with(ResponseTemplate::new(502).set_body_raw(page.clone(), "text/html"))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/login"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(page, "text/html"))
+            .mount(&server)
+            .await;
+        let octocrab = crate::setup(&server);
+
+        for route in &["/proxy", "/login"] {
+            let error = octocrab
+                .get::<serde_json::Value, _, _>(route, None::<&()>)
+                .await
+                .unwrap_err();
+
+            match &error {
+                crate::Error::UnexpectedResponse {
+                    content_type,
+                    body_snippet,
+                    ..
+                } => {
+                    assert_eq!(content_type.as_deref(), Some("text/html"));
+                    assert_eq!(body_snippet.chars().count(), 500);
+                    assert!(body_snippet.starts_with("<html><body>Bad Gateway"));
+                }
+                error => panic!("expected an unexpected response, got {:?}", error),
+            }
+        }
+
+        let error = octocrab
+            .get::<serde_json::Value, _, _>("/proxy", None::<&()>)
+            .await
+            .unwrap_err();
+        assert_eq!(error.status_code(), Some(reqwest::StatusCode::BAD_GATEWAY));
+        assert!(error
+            .to_string()
+            .starts_with("Unexpected Response: 502 Bad Gateway (text/html): <html>"));
+    }
+
     #[test]
     fn absolute_url_escapes() {
         assert_eq!(
@@ -1,23 +1,135 @@
 //! Serde mappings from GitHub's JSON to structs.
+//!
+//! Models are `#[non_exhaustive]` so that fields can be added as GitHub adds
+//! them, which means they can't be built with struct literals outside of
+//! this crate. To construct one, e.g. as a fixture in your tests, start from
+//! its `Default` and assign the fields you care about. URL fields default to
+//! `https://api.github.com/` and timestamps to the Unix epoch.
+//! ```
+//! use octocrab::models::{Issue, Label};
+//!
+//! let mut label = Label::default();
+//! label.name = "bug".into();
+//!
+//! let mut issue = Issue::default();
+//! issue.number = 1.into();
+//! issue.title = "Crash on startup".into();
+//! issue.labels.push(label);
+//!
+//! assert!(issue.is_open());
+//! assert!(issue.has_label("bug"));
+//! ```
 use reqwest::Url;
 use serde::{Deserialize, Serialize};
 
+/// The value of URL fields in the models' `Default` implementations.
+fn placeholder_url() -> Url {
+    Url::parse("https://api.github.com/").unwrap()
+}
+
+fn unix_epoch() -> chrono::DateTime<chrono::Utc> {
+    std::time::UNIX_EPOCH.into()
+}
+
+macro_rules! id_type {
+    ($(#[$attr:meta])* $name:ident) => {
+        $(#[$attr])*
+        #[derive(
+            Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+        )]
+        #[serde(transparent)]
+        pub struct $name(pub u64);
+
+        impl From<u64> for $name {
+            fn from(value: u64) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<$name> for u64 {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl PartialEq<u64> for $name {
+            fn eq(&self, other: &u64) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    };
+}
+
+id_type!(
+    /// The number of an issue within its repository, as in `#42`, which is
+    /// what the issue routes expect.
+    IssueNumber
+);
+id_type!(
+    /// The globally unique id of an issue, which the issue routes do *not*
+    /// accept; see [`IssueNumber`](./struct.IssueNumber.html).
+    /// ```compile_fail
+    /// # async fn run(issue: octocrab::models::Issue) -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .issues("owner", "repo")
+    ///     .get(issue.id)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    IssueId
+);
+id_type!(
+    /// The number of a pull request within its repository, which is what the
+    /// pull request routes expect.
+    PullRequestNumber
+);
+id_type!(
+    /// The globally unique id of a pull request, which the pull request
+    /// routes do *not* accept; see
+    /// [`PullRequestNumber`](./struct.PullRequestNumber.html).
+    PullRequestId
+);
+id_type!(
+    /// The id of an issue or pull request comment.
+    CommentId
+);
+
+/// Pull requests are issues too, so their number can be used with the issue
+/// routes, e.g. to label a pull request.
+impl From<PullRequestNumber> for IssueNumber {
+    fn from(number: PullRequestNumber) -> Self {
+        Self(number.0)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub struct PullRequest {
     pub url: String,
-    pub id: i64,
+    pub id: PullRequestId,
     pub node_id: String,
     pub html_url: Url,
     pub diff_url: Url,
     pub patch_url: Url,
+    /// The API URL of the pull request's issue, this and the other API URLs
+    /// below can be passed directly to `Octocrab::get` to fetch the related
+    /// resources.
     pub issue_url: Url,
     pub commits_url: Url,
     pub review_comments_url: Url,
+    /// A URL template for a single review comment, with the comment's number
+    /// as `{/number}`.
     pub review_comment_url: Url,
     pub comments_url: Url,
     pub statuses_url: Url,
-    pub number: i64,
+    pub number: PullRequestNumber,
     pub state: IssueState,
     pub locked: bool,
     pub title: String,
@@ -29,8 +141,13 @@ pub struct PullRequest {
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
     pub closed_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Whether the pull request can be merged without conflicts. GitHub
+    /// computes this in the background, so it's `None` until it's known,
+    /// see [`PullRequestHandler::poll_mergeability`].
+    ///
+    /// [`PullRequestHandler::poll_mergeability`]: ../pulls/struct.PullRequestHandler.html#method.poll_mergeability
     pub mergeable: Option<bool>,
-    pub merged_at: Option<String>,
+    pub merged_at: Option<chrono::DateTime<chrono::Utc>>,
     pub merge_commit_sha: String,
     pub assignee: Option<User>,
     pub assignees: Vec<User>,
@@ -43,6 +160,82 @@ pub struct PullRequest {
     pub author_association: String,
     pub draft: bool,
     pub repo: Option<Repository>,
+    /// Only present when getting a single pull request, see
+    /// [`PullRequest::is_detail_loaded`].
+    ///
+    /// [`PullRequest::is_detail_loaded`]: #method.is_detail_loaded
+    pub additions: Option<u64>,
+    /// Only present when getting a single pull request.
+    pub deletions: Option<u64>,
+    /// Only present when getting a single pull request.
+    pub changed_files: Option<u64>,
+    /// Only present when getting a single pull request.
+    pub commits: Option<u64>,
+    /// Only present when getting a single pull request.
+    pub comments: Option<u64>,
+    /// Only present when getting a single pull request.
+    pub review_comments: Option<u64>,
+    /// Only present when getting a single pull request.
+    pub merged: Option<bool>,
+    /// Only present when getting a single pull request. One of `clean`
+    /// (mergeable, with passing checks), `unstable` (mergeable, with failing
+    /// checks), `has_hooks` (mergeable, with passing checks and pre-receive
+    /// hooks), `blocked` (e.g. missing required reviews), `behind` (the head
+    /// branch is out of date), `dirty` (there are conflicts), `draft`, or
+    /// `unknown` while GitHub computes [`mergeable`](#structfield.mergeable).
+    pub mergeable_state: Option<String>,
+}
+
+impl PullRequest {
+    /// Whether the pull request is open, this includes draft pull requests.
+    pub fn is_open(&self) -> bool {
+        self.state == IssueState::Open
+    }
+
+    /// Whether the pull request is a draft.
+    pub fn is_draft(&self) -> bool {
+        self.draft
+    }
+
+    /// The key to order pull requests by, their `number` and then their
+    /// globally unique `id`, which breaks ties between pull requests from
+    /// different repositories.
+    /// ```
+    /// # let mut pull_requests: Vec<octocrab::models::PullRequest> = Vec::new();
+    /// pull_requests.sort_by_key(octocrab::models::PullRequest::sort_key);
+    /// ```
+    pub fn sort_key(&self) -> (u64, u64) {
+        (self.number.0, self.id.0)
+    }
+
+    /// Whether the fields only returned when getting a single pull request
+    /// (e.g. `additions`, `deletions`, `changed_files`) are present. These
+    /// are missing when the pull request comes from a list of pull requests.
+    pub fn is_detail_loaded(&self) -> bool {
+        self.additions.is_some() && self.deletions.is_some() && self.changed_files.is_some()
+    }
+
+    /// The number of added lines, or `0` if the detail fields weren't loaded.
+    pub fn additions_or_zero(&self) -> u64 {
+        self.additions.unwrap_or_default()
+    }
+
+    /// The number of deleted lines, or `0` if the detail fields weren't
+    /// loaded.
+    pub fn deletions_or_zero(&self) -> u64 {
+        self.deletions.unwrap_or_default()
+    }
+
+    /// The number of changed files, or `0` if the detail fields weren't
+    /// loaded.
+    pub fn changed_files_or_zero(&self) -> u64 {
+        self.changed_files.unwrap_or_default()
+    }
+
+    /// The number of commits, or `0` if the detail fields weren't loaded.
+    pub fn commits_or_zero(&self) -> u64 {
+        self.commits.unwrap_or_default()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -108,6 +301,200 @@ pub struct Contents {
     pub download_url: Url,
 }
 
+/// The contents of a path in a repository, which is either a file or the
+/// entries of a directory.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+#[non_exhaustive]
+pub enum ContentResponse {
+    File(Box<Contents>),
+    Dir(Vec<ContentEntry>),
+}
+
+impl ContentResponse {
+    /// The file, if the path is a file.
+    pub fn as_file(&self) -> Option<&Contents> {
+        match self {
+            Self::File(contents) => Some(contents),
+            _ => None,
+        }
+    }
+
+    /// The entries of the directory, if the path is a directory.
+    pub fn as_dir(&self) -> Option<&[ContentEntry]> {
+        match self {
+            Self::Dir(entries) => Some(entries),
+            _ => None,
+        }
+    }
+}
+
+/// An entry of a directory, without its content.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ContentEntry {
+    /// One of `file`, `dir`, `symlink` or `submodule`.
+    #[serde(rename = "type")]
+    pub entry_type: String,
+    pub size: u64,
+    pub name: String,
+    pub path: String,
+    pub sha: String,
+    pub url: Url,
+    /// `None` for submodules.
+    pub git_url: Option<Url>,
+    pub html_url: Option<Url>,
+    /// `None` for directories and submodules.
+    pub download_url: Option<Url>,
+}
+
+/// A webhook configured on a repository.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Hook {
+    pub id: u64,
+    /// Always `web` for repository webhooks.
+    pub name: String,
+    pub active: bool,
+    pub events: Vec<String>,
+    pub config: HookConfig,
+    pub url: Url,
+    pub test_url: Option<Url>,
+    pub ping_url: Option<Url>,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// A single attempt at delivering a webhook's payload.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct HookDelivery {
+    pub id: u64,
+    /// Shared by all redeliveries of the same event.
+    pub guid: String,
+    pub delivered_at: chrono::DateTime<chrono::Utc>,
+    pub redelivery: bool,
+    /// How long the delivery took, in seconds.
+    pub duration: f64,
+    /// A description of the response's status, e.g. `OK`.
+    pub status: String,
+    pub status_code: u16,
+    pub event: String,
+    pub action: Option<String>,
+    pub installation_id: Option<u64>,
+    pub repository_id: Option<u64>,
+    /// The URL the payload was delivered to. Only included when getting a
+    /// single delivery.
+    pub url: Option<String>,
+    /// Only included when getting a single delivery.
+    pub request: Option<HookDeliveryRequest>,
+    /// Only included when getting a single delivery.
+    pub response: Option<HookDeliveryResponse>,
+}
+
+/// The request sent to a webhook's URL.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct HookDeliveryRequest {
+    pub headers: Option<std::collections::HashMap<String, String>>,
+    pub payload: Option<serde_json::Value>,
+}
+
+/// The response a webhook's URL replied with.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct HookDeliveryResponse {
+    pub headers: Option<std::collections::HashMap<String, String>>,
+    pub payload: Option<String>,
+}
+
+/// Where and how a webhook delivers its payloads. The hook's secret is
+/// write-only, and is never returned by GitHub.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct HookConfig {
+    pub url: Option<String>,
+    /// Either `json` or `form`.
+    pub content_type: Option<String>,
+    /// `"0"` when SSL certificates are verified, `"1"` when they aren't.
+    pub insecure_ssl: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Autolink {
+    pub id: u64,
+    pub key_prefix: String,
+    pub url_template: String,
+    pub is_alphanumeric: bool,
+}
+
+/// A pattern that only admins and maintainers can create or delete matching
+/// tags for.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct TagProtection {
+    pub id: u64,
+    pub pattern: String,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct GitBlob {
+    pub sha: String,
+    pub node_id: String,
+    pub size: u64,
+    pub url: Url,
+    pub content: String,
+    pub encoding: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct GitTree {
+    pub sha: String,
+    pub url: Url,
+    pub tree: Vec<GitTreeEntry>,
+    /// Whether GitHub truncated the tree because it exceeded the maximum
+    /// number of entries for a single request.
+    pub truncated: bool,
+}
+
+impl GitTree {
+    /// Returns the paths of every blob (file) in the tree that starts
+    /// with `prefix`.
+    pub fn blob_paths<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.tree
+            .iter()
+            .filter(|entry| entry.r#type == GitTreeEntryType::Blob)
+            .map(|entry| &*entry.path)
+            .filter(move |path| path.starts_with(prefix))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct GitTreeEntry {
+    pub path: String,
+    pub mode: String,
+    pub r#type: GitTreeEntryType,
+    pub sha: String,
+    pub size: Option<u64>,
+    pub url: Option<Url>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum GitTreeEntryType {
+    Blob,
+    Tree,
+    Commit,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
@@ -116,6 +503,8 @@ pub enum Event {
     Assigned,
     Closed,
     ConvertedNoteToIssue,
+    #[serde(rename = "cross-referenced")]
+    CrossReferenced,
     Demilestoned,
     HeadRefDeleted,
     HeadRefForcePushed,
@@ -146,7 +535,7 @@ pub enum Event {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub struct Issue {
-    pub id: i64,
+    pub id: IssueId,
     pub node_id: String,
     pub url: Url,
     pub repository_url: Url,
@@ -154,7 +543,7 @@ pub struct Issue {
     pub comments_url: Url,
     pub events_url: Url,
     pub html_url: Url,
-    pub number: i64,
+    pub number: IssueNumber,
     pub state: String,
     pub title: String,
     pub body: String,
@@ -170,6 +559,89 @@ pub struct Issue {
     pub closed_at: Option<chrono::DateTime<chrono::Utc>>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
+    pub reactions: Option<ReactionRollup>,
+    /// Only present in search results, when requested with
+    /// [`QueryHandler::text_matches`].
+    ///
+    /// [`QueryHandler::text_matches`]: ../search/struct.QueryHandler.html#method.text_matches
+    pub text_matches: Option<Vec<TextMatch>>,
+}
+
+impl Default for Issue {
+    /// An open issue with empty or placeholder fields.
+    fn default() -> Self {
+        Self {
+            id: IssueId::default(),
+            node_id: String::new(),
+            url: placeholder_url(),
+            repository_url: placeholder_url(),
+            labels_url: placeholder_url(),
+            comments_url: placeholder_url(),
+            events_url: placeholder_url(),
+            html_url: placeholder_url(),
+            number: IssueNumber::default(),
+            state: "open".into(),
+            title: String::new(),
+            body: String::new(),
+            user: User::default(),
+            labels: Vec::new(),
+            assignee: None,
+            assignees: Vec::new(),
+            milestone: None,
+            locked: false,
+            active_lock_reason: None,
+            comments: 0,
+            pull_request: None,
+            closed_at: None,
+            created_at: unix_epoch(),
+            updated_at: unix_epoch(),
+            reactions: None,
+            text_matches: None,
+        }
+    }
+}
+
+impl Issue {
+    /// Whether the issue is open.
+    pub fn is_open(&self) -> bool {
+        self.state == "open"
+    }
+
+    /// Whether the issue is closed.
+    pub fn is_closed(&self) -> bool {
+        self.state == "closed"
+    }
+
+    /// The key to order issues by, their `number` and then their globally
+    /// unique `id`, which breaks ties between issues from different
+    /// repositories.
+    /// ```
+    /// # let mut issues: Vec<octocrab::models::Issue> = Vec::new();
+    /// issues.sort_by_key(octocrab::models::Issue::sort_key);
+    /// ```
+    pub fn sort_key(&self) -> (u64, u64) {
+        (self.number.0, self.id.0)
+    }
+
+    /// Whether the issue has a label called `name`. Like on GitHub, label
+    /// names are compared case insensitively.
+    pub fn has_label(&self, name: impl AsRef<str>) -> bool {
+        self.labels
+            .iter()
+            .any(|label| label.name.eq_ignore_ascii_case(name.as_ref()))
+    }
+}
+
+/// Formats the issue as `#{number} {title}`, use the `html_url` field to
+/// also link to it.
+/// ```
+/// # let issue = octocrab::models::Issue::default();
+/// println!("{} ({})", issue, issue.html_url);
+/// ```
+impl std::fmt::Display for Issue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{} {}", self.number, self.title)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -205,9 +677,22 @@ pub struct IssueEvent {
     pub event: Option<Event>,
     pub commit_id: Option<String>,
     pub commit_url: Option<String>,
+    /// The issue or pull request that referenced this issue, only present
+    /// for `cross-referenced` events. The source may be in another repository.
+    pub source: Option<EventSource>,
+    /// The issue the event happened on, only present when listing the events
+    /// of every issue in a repository.
+    pub issue: Option<Issue>,
     pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct EventSource {
+    pub r#type: String,
+    pub issue: Issue,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MembershipInvitation {
     pub url: Url,
@@ -218,17 +703,109 @@ pub struct MembershipInvitation {
     pub user: User,
 }
 
+/// The result of merging a pull request.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
-pub struct Comment {
+pub struct MergeResult {
+    /// The SHA of the commit that was created or fast-forwarded to.
+    pub sha: Option<String>,
+    pub merged: bool,
+    pub message: String,
+}
+
+/// A pending invitation to join an organization.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct OrgInvitation {
     pub id: u64,
     pub node_id: String,
+    /// The login of the invited user, `None` if they were invited by an
+    /// email address that isn't linked to a GitHub account.
+    pub login: Option<String>,
+    pub email: Option<String>,
+    /// The invited user's role, e.g. `direct_member` or `admin`.
+    pub role: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub failed_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub failed_reason: Option<String>,
+    pub inviter: User,
+    pub team_count: u64,
+    pub invitation_teams_url: Url,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Comment {
+    pub id: CommentId,
+    pub node_id: String,
     pub url: Url,
     pub html_url: Url,
+    /// The API URL of the issue the comment belongs to, this can be passed
+    /// directly to `Octocrab::get` to fetch the issue.
+    pub issue_url: Option<Url>,
     pub body: Option<String>,
     pub user: User,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub reactions: Option<ReactionRollup>,
+}
+
+impl Default for Comment {
+    /// A comment with empty or placeholder fields.
+    fn default() -> Self {
+        Self {
+            id: CommentId::default(),
+            node_id: String::new(),
+            url: placeholder_url(),
+            html_url: placeholder_url(),
+            issue_url: None,
+            body: None,
+            user: User::default(),
+            created_at: unix_epoch(),
+            updated_at: None,
+            reactions: None,
+        }
+    }
+}
+
+/// A comment on a commit, either on the commit as a whole or on one of its
+/// lines.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct CommitComment {
+    pub id: CommentId,
+    pub node_id: String,
+    pub url: Url,
+    pub html_url: Url,
+    pub body: String,
+    /// The file the comment is on, `None` for comments on the whole commit.
+    pub path: Option<String>,
+    pub position: Option<u64>,
+    pub line: Option<u64>,
+    pub commit_id: String,
+    pub user: Option<User>,
+    pub author_association: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+    pub reactions: Option<ReactionRollup>,
+}
+
+/// The number of each reaction on an issue or a comment.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ReactionRollup {
+    pub url: Option<Url>,
+    pub total_count: u64,
+    #[serde(rename = "+1")]
+    pub plus_one: u64,
+    #[serde(rename = "-1")]
+    pub minus_one: u64,
+    pub laugh: u64,
+    pub hooray: u64,
+    pub confused: u64,
+    pub heart: u64,
+    pub rocket: u64,
+    pub eyes: u64,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -290,16 +867,21 @@ pub struct Team {
     pub html_url: Url,
     pub name: String,
     pub slug: String,
-    pub description: String,
+    pub description: Option<String>,
+    /// Either `secret` or `closed`.
     pub privacy: String,
+    /// The team's default permission on the organization's repositories,
+    /// e.g. `pull`, `push` or `admin`.
     pub permission: String,
     pub members_url: Url,
     pub repositories_url: Url,
-    pub members_count: i64,
-    pub repos_count: i64,
-    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// Only present when getting a single team, not when listing teams.
+    pub members_count: Option<i64>,
+    /// Only present when getting a single team, not when listing teams.
+    pub repos_count: Option<i64>,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
-    pub organization: Organization,
+    pub organization: Option<Organization>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -326,7 +908,9 @@ pub struct Head {
     #[serde(rename = "ref")]
     pub ref_field: String,
     pub sha: String,
-    pub user: User,
+    /// `None` if the owner of the head repository has been deleted.
+    pub user: Option<User>,
+    /// `None` if the head repository (e.g. a fork) has been deleted.
     pub repo: Option<Repository>,
 }
 
@@ -337,7 +921,9 @@ pub struct Base {
     #[serde(rename = "ref")]
     pub ref_field: String,
     pub sha: String,
-    pub user: User,
+    /// `None` if the owner of the base repository has been deleted.
+    pub user: Option<User>,
+    /// `None` if the base repository (e.g. a fork) has been deleted.
     pub repo: Option<Repository>,
 }
 
@@ -387,6 +973,32 @@ pub struct User {
     pub site_admin: bool,
 }
 
+impl Default for User {
+    /// A `User` type account with empty or placeholder fields.
+    fn default() -> Self {
+        Self {
+            login: String::new(),
+            id: 0,
+            node_id: String::new(),
+            avatar_url: placeholder_url(),
+            gravatar_id: String::new(),
+            url: placeholder_url(),
+            html_url: placeholder_url(),
+            followers_url: placeholder_url(),
+            following_url: placeholder_url(),
+            gists_url: placeholder_url(),
+            starred_url: placeholder_url(),
+            subscriptions_url: placeholder_url(),
+            organizations_url: placeholder_url(),
+            repos_url: placeholder_url(),
+            events_url: placeholder_url(),
+            received_events_url: placeholder_url(),
+            r#type: "User".into(),
+            site_admin: false,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub struct Label {
@@ -399,6 +1011,21 @@ pub struct Label {
     pub default: bool,
 }
 
+impl Default for Label {
+    /// A label with empty or placeholder fields.
+    fn default() -> Self {
+        Self {
+            id: 0,
+            node_id: String::new(),
+            url: placeholder_url(),
+            name: String::new(),
+            description: None,
+            color: String::new(),
+            default: false,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub struct Milestone {
@@ -414,10 +1041,10 @@ pub struct Milestone {
     pub creator: Option<User>,
     pub open_issues: Option<i64>,
     pub closed_issues: Option<i64>,
-    pub created_at: Option<String>,
-    pub updated_at: Option<String>,
-    pub closed_at: Option<String>,
-    pub due_on: Option<String>,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub closed_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub due_on: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -517,9 +1144,67 @@ pub struct Repository {
     pub template_repository: Option<Box<Repository>>,
     pub allow_squash_merge: Option<bool>,
     pub allow_merge_commit: Option<bool>,
-    pub subscribers_count: Option<i64>,
-    pub network_count: Option<i64>,
+    /// Only present when getting a single repository.
+    pub subscribers_count: Option<u64>,
+    /// Only present when getting a single repository.
+    pub network_count: Option<u64>,
     pub license: Option<License>,
+    /// Only present in search results, when requested with
+    /// [`QueryHandler::text_matches`].
+    ///
+    /// [`QueryHandler::text_matches`]: ../search/struct.QueryHandler.html#method.text_matches
+    pub text_matches: Option<Vec<TextMatch>>,
+}
+
+/// A snippet of a search result's property that matched the query, used to
+/// highlight the search terms.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct TextMatch {
+    /// The API URL of the object containing the match.
+    pub object_url: Option<Url>,
+    /// The type of the object containing the match, e.g. `Issue`.
+    pub object_type: Option<String>,
+    /// The name of the property that matched, e.g. `body`.
+    pub property: String,
+    /// The part of the property surrounding the matches.
+    pub fragment: String,
+    pub matches: Vec<TextMatchHighlight>,
+}
+
+/// A single occurrence of a search term in [`TextMatch::fragment`].
+///
+/// [`TextMatch::fragment`]: ./struct.TextMatch.html#structfield.fragment
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct TextMatchHighlight {
+    /// The matched text.
+    pub text: String,
+    /// The start and end of the match, as byte offsets into the fragment, so
+    /// `&fragment[indices[0]..indices[1]] == text`.
+    pub indices: [usize; 2],
+}
+
+impl Repository {
+    /// Whether the repository hasn't been pushed to within `threshold` of now.
+    /// Repositories that have never been pushed to (`pushed_at` is `None`)
+    /// are stale if they were created before `threshold`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance().installation_repos().await?;
+    /// let six_months = chrono::Duration::days(6 * 30);
+    ///
+    /// for repo in page.items.iter().filter(|repo| repo.is_stale(six_months)) {
+    ///     println!("{} is stale", repo.full_name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_stale(&self, threshold: chrono::Duration) -> bool {
+        let last_activity = self.pushed_at.unwrap_or(self.created_at);
+
+        chrono::Utc::now() - last_activity > threshold
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -540,100 +1225,640 @@ pub struct Commit {
     pub node_id: Option<String>,
     pub html_url: Option<String>,
     pub comments_url: Option<String>,
-    pub author: User,
-    pub committer: User,
+    /// `None` if the commit's author email isn't linked to a GitHub user.
+    pub author: Option<User>,
+    /// `None` if the commit's committer email isn't linked to a GitHub user.
+    pub committer: Option<User>,
+    /// Only present when getting a single commit.
+    pub stats: Option<CommitStats>,
+    /// Only present when getting a single commit.
+    pub files: Option<Vec<CommitFile>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
-pub struct Permissions {
-    admin: bool,
-    push: bool,
-    pull: bool,
+pub struct CommitStats {
+    pub additions: u64,
+    pub deletions: u64,
+    pub total: u64,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
-pub struct CheckRuns {
-    pub total_count: i32,
-    pub check_runs: Vec<CheckRun>,
+pub struct CommitFile {
+    pub sha: Option<String>,
+    pub filename: String,
+    pub status: String,
+    pub additions: u64,
+    pub deletions: u64,
+    pub changes: u64,
+    pub blob_url: Option<Url>,
+    pub raw_url: Option<Url>,
+    pub contents_url: Option<Url>,
+    /// Not present for binary files, or if the diff is too large.
+    pub patch: Option<String>,
+    pub previous_filename: Option<String>,
 }
 
+/// The difference between two commits.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
-pub struct CheckRun {
-    pub id: Option<i64>,
-    pub head_sha: Option<String>,
-    pub node_id: Option<String>,
-    pub external_id: Option<String>,
-    pub url: Option<String>,
-    pub html_url: Option<String>,
-    pub details_url: Option<String>,
-    pub status: Option<CheckStatus>,
-    pub conclusion: Option<String>,
-    pub started_at: Option<chrono::DateTime<chrono::Utc>>,
-    pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
+pub struct CommitComparison {
+    pub url: Url,
+    pub html_url: Url,
+    pub permalink_url: Url,
+    pub diff_url: Url,
+    pub patch_url: Url,
+    pub base_commit: Commit,
+    /// The most recent common ancestor of the two commits.
+    pub merge_base_commit: Commit,
+    /// Either `ahead`, `behind`, `identical`, or `diverged`, describing the
+    /// head relative to the base.
+    pub status: String,
+    pub ahead_by: u64,
+    pub behind_by: u64,
+    /// The number of commits in the comparison, which may be more than the
+    /// number of `commits` if they were paginated.
+    pub total_commits: u64,
+    pub commits: Vec<Commit>,
+    /// At most 300 files, not present if the diff is too large.
+    pub files: Option<Vec<DiffEntry>>,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
+/// A file changed in a pull request, GitHub uses the same schema as for the
+/// files changed in a commit.
+pub type DiffEntry = CommitFile;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
-pub enum CheckStatus {
-    Queued,
-    Completed,
-    InProgress,
+pub struct Branch {
+    pub name: String,
+    pub commit: CommitRef,
+    pub protected: bool,
 }
 
+/// The commit a branch or tag points to.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
-pub struct CombinedStatus {
-    pub state: StatusState,
+pub struct CommitRef {
     pub sha: String,
-    pub total_count: i64,
-    pub statuses: Vec<Status>,
+    pub url: Url,
 }
 
+/// The status checks that must pass before merging into a protected branch.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
-pub struct Status {
-    pub id: Option<i64>,
-    pub node_id: Option<String>,
-    pub avatar_url: Option<String>,
-    pub url: Option<String>,
-    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
-    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
-    pub state: StatusState,
-    pub creator: Option<User>,
+pub struct RequiredStatusChecks {
+    pub url: Option<Url>,
+    /// Whether branches must be up to date with the protected branch before
+    /// merging.
+    pub strict: bool,
+    /// The names of the required checks, regardless of which app sets them.
+    pub contexts: Vec<String>,
+    #[serde(default)]
+    pub checks: Vec<RequiredStatusCheck>,
+    pub contexts_url: Option<Url>,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
-pub enum StatusState {
-    Failure,
-    Pending,
-    Success,
+pub struct RequiredStatusCheck {
+    pub context: String,
+    /// The app that must set the check, `None` if any app can.
+    pub app_id: Option<u64>,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
-pub enum ReviewState {
-    Approved,
-    Pending,
-    ChangesRequested,
+pub struct Tag {
+    pub name: String,
+    pub commit: CommitRef,
+    pub zipball_url: Url,
+    pub tarball_url: Url,
+    pub node_id: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
-pub struct Links {
-    #[serde(rename = "self")]
-    pub self_link: Option<SelfLink>,
-    pub html_link: Option<HtmlLink>,
-    pub issue_link: Option<IssueLink>,
-    pub comments_link: Option<CommentsLink>,
-    pub review_comments_link: Option<ReviewCommentsLink>,
-    pub review_comment_link: Option<ReviewCommentLink>,
+pub struct Deployment {
+    pub id: u64,
+    pub node_id: String,
+    pub url: Url,
+    pub sha: String,
+    #[serde(rename = "ref")]
+    pub r#ref: String,
+    pub task: String,
+    pub environment: String,
+    pub original_environment: Option<String>,
+    pub description: Option<String>,
+    pub creator: Option<User>,
+    pub payload: Option<serde_json::Value>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+    pub statuses_url: Url,
+    pub repository_url: Url,
+    pub transient_environment: Option<bool>,
+    pub production_environment: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Gist {
+    pub url: Url,
+    pub forks_url: Url,
+    pub commits_url: Url,
+    pub id: String,
+    pub node_id: String,
+    pub git_pull_url: Url,
+    pub git_push_url: Url,
+    pub html_url: Url,
+    pub files: std::collections::BTreeMap<String, GistFile>,
+    pub public: bool,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+    pub description: Option<String>,
+    pub comments: u64,
+    pub comments_url: Url,
+    pub owner: Option<User>,
+    pub truncated: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct GistFile {
+    pub filename: String,
+    pub r#type: String,
+    pub language: Option<String>,
+    pub raw_url: Url,
+    pub size: u64,
+    pub truncated: Option<bool>,
+    pub content: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct GistComment {
+    pub id: u64,
+    pub node_id: String,
+    pub url: Url,
+    pub body: String,
+    pub user: User,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// The progress of importing a repository from another version control
+/// system.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Import {
+    pub vcs: Option<String>,
+    pub use_lfs: Option<String>,
+    pub vcs_url: String,
+    pub status: ImportStatus,
+    pub status_text: Option<String>,
+    pub failed_step: Option<String>,
+    pub error_message: Option<String>,
+    pub has_large_files: Option<bool>,
+    pub large_files_size: Option<u64>,
+    pub large_files_count: Option<u64>,
+    pub authors_count: Option<u64>,
+    pub commit_count: Option<u64>,
+    pub percent: Option<u64>,
+    pub url: Url,
+    pub html_url: Url,
+    pub authors_url: Url,
+    pub repository_url: Url,
+}
+
+/// The state of a source import, imports move from `Detecting` to
+/// `Importing` and finish with either `Complete` or `Error`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ImportStatus {
+    Detecting,
+    Importing,
+    Mapping,
+    Pushing,
+    Complete,
+    Error,
+    Auth,
+    AuthFailed,
+    Choose,
+    DetectionFoundMultiple,
+    DetectionFoundNothing,
+    DetectionNeedsAuth,
+    WaitingToPush,
+    Setup,
+    None,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct CodeOfConduct {
+    pub key: String,
+    pub name: String,
+    pub url: Url,
+    /// The text of the code of conduct, not present when listing all codes of
+    /// conduct.
+    pub body: Option<String>,
+    pub html_url: Option<Url>,
+}
+
+/// A deployment environment, such as `production` or `staging`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Environment {
+    pub id: u64,
+    pub node_id: String,
+    pub name: String,
+    pub url: Url,
+    pub html_url: Url,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+    #[serde(default)]
+    pub protection_rules: Vec<EnvironmentProtectionRule>,
+    /// Which branches can deploy to the environment, `None` if any branch
+    /// can.
+    pub deployment_branch_policy: Option<DeploymentBranchPolicy>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct EnvironmentProtectionRule {
+    pub id: u64,
+    pub node_id: String,
+    /// The kind of rule, e.g. `wait_timer`, `required_reviewers`, or
+    /// `branch_policy`.
+    pub r#type: String,
+    /// The number of minutes to wait before deploying, only present for
+    /// `wait_timer` rules.
+    pub wait_timer: Option<u64>,
+    /// The users or teams that must review deployments, only present for
+    /// `required_reviewers` rules.
+    pub reviewers: Option<Vec<serde_json::Value>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct DeploymentBranchPolicy {
+    pub protected_branches: bool,
+    pub custom_branch_policies: bool,
+}
+
+/// A pattern matching the branches that can deploy to an environment, when
+/// the environment uses custom branch policies.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct EnvironmentBranchPolicy {
+    pub id: u64,
+    pub node_id: String,
+    /// A branch name pattern, e.g. `release/*`.
+    pub name: String,
+}
+
+/// A public key used to encrypt secrets.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PublicKey {
+    pub key_id: String,
+    pub key: String,
+}
+
+/// An organization's Actions secret. The secret's value is never returned.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct OrganizationSecret {
+    pub name: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+    /// One of `all`, `private` or `selected`.
+    pub visibility: String,
+    /// Only present when `visibility` is `selected`.
+    pub selected_repositories_url: Option<Url>,
+}
+
+/// A secret, such as an API token, that was found in a repository.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct SecretScanningAlert {
+    pub number: u64,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub url: Url,
+    pub html_url: Url,
+    pub state: SecretScanningAlertState,
+    pub resolution: Option<SecretScanningResolution>,
+    pub resolved_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub resolved_by: Option<User>,
+    /// The type of secret, e.g. `github_personal_access_token`.
+    pub secret_type: String,
+    pub secret: String,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum SecretScanningAlertState {
+    Open,
+    Resolved,
+}
+
+/// Why a secret scanning alert was resolved.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum SecretScanningResolution {
+    FalsePositive,
+    WontFix,
+    Revoked,
+    UsedInTests,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Release {
+    pub url: Url,
+    pub html_url: Url,
+    pub assets_url: Url,
+    pub upload_url: String,
+    pub tarball_url: Option<Url>,
+    pub zipball_url: Option<Url>,
+    pub id: u64,
+    pub node_id: String,
+    pub tag_name: String,
+    pub target_commitish: String,
+    pub name: Option<String>,
+    pub body: Option<String>,
+    pub draft: bool,
+    pub prerelease: bool,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// When the release was published, `None` for drafts.
+    pub published_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub author: User,
+    pub assets: Vec<ReleaseAsset>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ReleaseAsset {
+    pub url: Url,
+    pub browser_download_url: Url,
+    pub id: u64,
+    pub node_id: String,
+    pub name: String,
+    pub label: Option<String>,
+    pub state: String,
+    pub content_type: String,
+    pub size: u64,
+    pub download_count: u64,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+    pub uploader: Option<User>,
+}
+
+/// A repository starred by a user, along with when it was starred.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct StarredRepo {
+    pub starred_at: chrono::DateTime<chrono::Utc>,
+    pub repo: Repository,
+}
+
+/// The weekly commit counts of a repository over the last 52 weeks, oldest
+/// week first.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Participation {
+    /// Commits by everyone, including the owner.
+    pub all: Vec<u64>,
+    /// Commits by the repository's owner.
+    pub owner: Vec<u64>,
+}
+
+/// The number of lines added and deleted in a repository during a week.
+/// GitHub sends these as `[week, additions, deletions]` arrays.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "(i64, i64, i64)", into = "(i64, i64, i64)")]
+#[non_exhaustive]
+pub struct CodeFrequency {
+    /// The start of the week.
+    pub week: chrono::DateTime<chrono::Utc>,
+    pub additions: i64,
+    /// The number of deleted lines, as a negative number.
+    pub deletions: i64,
+}
+
+impl std::convert::TryFrom<(i64, i64, i64)> for CodeFrequency {
+    type Error = String;
+
+    fn try_from((week, additions, deletions): (i64, i64, i64)) -> Result<Self, Self::Error> {
+        use chrono::TimeZone;
+
+        let week = chrono::Utc
+            .timestamp_opt(week, 0)
+            .single()
+            .ok_or_else(|| format!("invalid week timestamp: {}", week))?;
+
+        Ok(Self {
+            week,
+            additions,
+            deletions,
+        })
+    }
+}
+
+impl From<CodeFrequency> for (i64, i64, i64) {
+    fn from(frequency: CodeFrequency) -> Self {
+        (
+            frequency.week.timestamp(),
+            frequency.additions,
+            frequency.deletions,
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Permissions {
+    pub admin: bool,
+    pub push: bool,
+    pub pull: bool,
+    /// Not included in every response.
+    pub maintain: Option<bool>,
+    /// Not included in every response.
+    pub triage: Option<bool>,
+}
+
+/// A user with access to a repository, along with what they can do in it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Collaborator {
+    #[serde(flatten)]
+    pub user: User,
+    pub permissions: Permissions,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct CheckRuns {
+    pub total_count: i32,
+    pub check_runs: Vec<CheckRun>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct CheckRun {
+    pub id: Option<i64>,
+    pub head_sha: Option<String>,
+    pub node_id: Option<String>,
+    pub external_id: Option<String>,
+    pub url: Option<String>,
+    pub html_url: Option<String>,
+    pub details_url: Option<String>,
+    pub status: Option<CheckStatus>,
+    pub conclusion: Option<String>,
+    pub started_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub name: Option<String>,
+    pub output: Option<CheckRunOutput>,
+    pub check_suite: Option<CheckSuiteReference>,
+    pub app: Option<App>,
+    pub pull_requests: Option<Vec<CheckPullRequest>>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum CheckStatus {
+    Queued,
+    Completed,
+    InProgress,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct App {
+    pub id: u64,
+    pub slug: Option<String>,
+    pub node_id: String,
+    pub owner: Option<User>,
+    pub name: String,
+    pub description: Option<String>,
+    pub external_url: Option<Url>,
+    pub html_url: Url,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct CheckSuite {
+    pub id: u64,
+    pub node_id: String,
+    pub head_branch: Option<String>,
+    pub head_sha: String,
+    pub status: Option<CheckStatus>,
+    /// Only present once `status` is `completed`.
+    pub conclusion: Option<String>,
+    pub url: Url,
+    pub before: Option<String>,
+    pub after: Option<String>,
+    pub pull_requests: Option<Vec<CheckPullRequest>>,
+    pub app: Option<App>,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct CheckRunOutput {
+    pub title: Option<String>,
+    pub summary: Option<String>,
+    pub text: Option<String>,
+    pub annotations_count: u64,
+    pub annotations_url: Url,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct CheckSuiteReference {
+    pub id: u64,
+}
+
+/// A pull request associated with a check, this only contains the pull
+/// request's identifiers and can be fetched in full with
+/// `PullRequestHandler::get`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct CheckPullRequest {
+    pub id: u64,
+    pub number: u64,
+    pub url: Url,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct CombinedStatus {
+    pub state: StatusState,
+    pub sha: String,
+    pub total_count: i64,
+    pub statuses: Vec<Status>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Status {
+    pub id: Option<i64>,
+    pub node_id: Option<String>,
+    pub avatar_url: Option<String>,
+    pub url: Option<String>,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub state: StatusState,
+    pub creator: Option<User>,
+    /// The label used to differentiate this status from the statuses of
+    /// other systems, e.g. `continuous-integration/travis-ci`.
+    pub context: String,
+    pub description: Option<String>,
+    pub target_url: Option<String>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum StatusState {
+    Failure,
+    Pending,
+    Success,
+    /// Only used by individual statuses, the combined state is `failure` if
+    /// any status is `error`.
+    Error,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum ReviewState {
+    Approved,
+    Pending,
+    ChangesRequested,
+    Commented,
+    Dismissed,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Links {
+    #[serde(rename = "self")]
+    pub self_link: Option<SelfLink>,
+    pub html_link: Option<HtmlLink>,
+    pub issue_link: Option<IssueLink>,
+    pub comments_link: Option<CommentsLink>,
+    pub review_comments_link: Option<ReviewCommentsLink>,
+    pub review_comment_link: Option<ReviewCommentLink>,
     pub commits_link: Option<CommitsLink>,
     pub statuses_link: Option<StatusesLink>,
 }
@@ -726,7 +1951,586 @@ pub struct InstallationPermissions {
 #[non_exhaustive]
 pub struct InstallationToken {
     pub token: String,
-    pub expires_at: Option<String>,
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
     pub permissions: Permissions,
     pub repositories: Option<Vec<Repository>>,
 }
+
+/// The rate limit status of the authenticated user, see
+/// [`Octocrab::ratelimit`](../struct.Octocrab.html#method.ratelimit).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct RateLimit {
+    pub resources: RateLimitResources,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct RateLimitResources {
+    /// The limit for the REST API, excluding search.
+    pub core: Rate,
+    /// The limit for the search API.
+    pub search: Rate,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Rate {
+    pub limit: u64,
+    pub remaining: u64,
+    /// When the current window resets.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub reset: chrono::DateTime<chrono::Utc>,
+    pub used: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REPOSITORY: &str = include_str!("../tests/resources/repository.json");
+
+    #[test]
+    fn construct_issue_from_default() {
+        let label = Label {
+            name: "Bug".into(),
+            ..Label::default()
+        };
+        let issue = Issue {
+            number: 42.into(),
+            labels: vec![label],
+            ..Issue::default()
+        };
+
+        assert!(issue.is_open());
+        assert!(issue.has_label("bug"));
+        assert_eq!(issue.created_at.timestamp(), 0);
+        assert_eq!(issue.html_url.as_str(), "https://api.github.com/");
+
+        // Default fixtures survive a round trip through GitHub's format.
+        let json = serde_json::to_string(&issue).unwrap();
+        assert_eq!(serde_json::from_str::<Issue>(&json).unwrap(), issue);
+    }
+
+    /// Deserializes `json` as a `T`, and checks that serializing it again
+    /// produces a value that deserializes to the same `T`.
+    fn assert_round_trip<T>(json: &str)
+    where
+        T: serde::de::DeserializeOwned + Serialize + PartialEq + std::fmt::Debug,
+    {
+        let model: T = serde_json::from_str(json).unwrap();
+        let serialized = serde_json::to_string(&model).unwrap();
+        assert_eq!(serde_json::from_str::<T>(&serialized).unwrap(), model);
+    }
+
+    #[test]
+    fn round_trip() {
+        let event: serde_json::Value = serde_json::from_str(include_str!(
+            "../tests/resources/cross_referenced_event.json"
+        ))
+        .unwrap();
+
+        assert_round_trip::<Issue>(&event["source"]["issue"].to_string());
+        assert_round_trip::<PullRequest>(include_str!("../tests/resources/pull_request.json"));
+        assert_round_trip::<Repository>(REPOSITORY);
+        assert_round_trip::<Comment>(include_str!("../tests/resources/issue_comment.json"));
+        assert_round_trip::<Milestone>(include_str!("../tests/resources/milestone.json"));
+    }
+
+    #[test]
+    fn deserialize_comment_urls() {
+        let comment: Comment =
+            serde_json::from_str(include_str!("../tests/resources/issue_comment.json")).unwrap();
+
+        assert_eq!(
+            comment.url.as_str(),
+            "https://api.github.com/repos/octocat/Hello-World/issues/comments/1"
+        );
+        assert_eq!(
+            comment.html_url.as_str(),
+            "https://github.com/octocat/Hello-World/issues/1347#issuecomment-1"
+        );
+        assert_eq!(
+            comment.issue_url.unwrap().path(),
+            "/repos/octocat/Hello-World/issues/1347"
+        );
+    }
+
+    #[test]
+    fn deserialize_pull_request_urls() {
+        let pull_request: PullRequest =
+            serde_json::from_str(include_str!("../tests/resources/pull_request.json")).unwrap();
+
+        assert_eq!(
+            pull_request.issue_url.path(),
+            "/repos/octocat/Hello-World/issues/1347"
+        );
+        assert_eq!(
+            pull_request.comments_url.path(),
+            "/repos/octocat/Hello-World/issues/1347/comments"
+        );
+        assert_eq!(
+            pull_request.review_comments_url.path(),
+            "/repos/octocat/Hello-World/pulls/1347/comments"
+        );
+        assert_eq!(
+            pull_request.commits_url.path(),
+            "/repos/octocat/Hello-World/pulls/1347/commits"
+        );
+        assert_eq!(
+            pull_request.statuses_url.path(),
+            "/repos/octocat/Hello-World/statuses/6dcb09b5b57875f334f61aebed695e2e4193db5e"
+        );
+    }
+
+    #[test]
+    fn deserialize_hook_delivery() {
+        let delivery: HookDelivery =
+            serde_json::from_str(include_str!("../tests/resources/hook_delivery.json")).unwrap();
+
+        assert_eq!(delivery.id, 12345678);
+        assert_eq!(delivery.status_code, 200);
+        assert_eq!(delivery.event, "issues");
+        assert_eq!(delivery.action.as_deref(), Some("opened"));
+        assert_eq!(
+            delivery.request.unwrap().payload.unwrap()["issue"]["body"],
+            "foo"
+        );
+        assert_eq!(delivery.response.unwrap().payload.as_deref(), Some("ok"));
+    }
+
+    #[test]
+    fn deserialize_milestone() {
+        let milestone: Milestone =
+            serde_json::from_str(include_str!("../tests/resources/milestone.json")).unwrap();
+
+        assert_eq!(milestone.number, 1);
+        assert_eq!(milestone.title, "v1.0");
+        assert_eq!(milestone.state.as_deref(), Some("open"));
+        assert_eq!(milestone.open_issues, Some(4));
+        assert_eq!(milestone.closed_issues, Some(8));
+        assert_eq!(milestone.creator.unwrap().login, "octocat");
+        assert_eq!(
+            milestone.closed_at.map(|date| date.to_rfc3339()).as_deref(),
+            Some("2013-02-12T13:22:01+00:00")
+        );
+    }
+
+    #[test]
+    fn display_issue() {
+        let event: serde_json::Value = serde_json::from_str(include_str!(
+            "../tests/resources/cross_referenced_event.json"
+        ))
+        .unwrap();
+        let issue: Issue = serde_json::from_value(event["source"]["issue"].clone()).unwrap();
+
+        assert_eq!(issue.to_string(), "#42 Fix the Hello-World bug");
+    }
+
+    #[test]
+    fn deserialize_timestamps() {
+        let event: serde_json::Value = serde_json::from_str(include_str!(
+            "../tests/resources/cross_referenced_event.json"
+        ))
+        .unwrap();
+        let issue: Issue = serde_json::from_value(event["source"]["issue"].clone()).unwrap();
+        let pull_request: PullRequest =
+            serde_json::from_str(include_str!("../tests/resources/pull_request.json")).unwrap();
+
+        assert_eq!(issue.created_at.to_rfc3339(), "2011-04-22T13:33:48+00:00");
+        assert_eq!(issue.closed_at, None);
+        assert_eq!(
+            pull_request.merged_at.map(|date| date.timestamp()),
+            Some(1296068472)
+        );
+    }
+
+    #[test]
+    fn deserialize_import_status() {
+        let import = include_str!("../tests/resources/import.json");
+
+        for (status, expected) in &[
+            ("detecting", ImportStatus::Detecting),
+            ("importing", ImportStatus::Importing),
+            ("complete", ImportStatus::Complete),
+            ("error", ImportStatus::Error),
+        ] {
+            let mut value: serde_json::Value = serde_json::from_str(import).unwrap();
+            value["status"] = serde_json::json!(status);
+            let import: Import = serde_json::from_value(value).unwrap();
+
+            assert_eq!(import.status, *expected);
+        }
+    }
+
+    #[test]
+    fn deserialize_participation() {
+        let participation: Participation =
+            serde_json::from_str(include_str!("../tests/resources/participation.json")).unwrap();
+
+        assert_eq!(participation.all.len(), 52);
+        assert_eq!(participation.owner.len(), 52);
+        assert_eq!(participation.all.iter().sum::<u64>(), 628);
+        assert_eq!(participation.owner.iter().sum::<u64>(), 219);
+    }
+
+    #[test]
+    fn deserialize_reaction_rollup() {
+        let comment: Comment =
+            serde_json::from_str(include_str!("../tests/resources/issue_comment.json")).unwrap();
+        let reactions = comment.reactions.unwrap();
+
+        assert_eq!(reactions.total_count, 15);
+        assert_eq!(reactions.plus_one, 5);
+        assert_eq!(reactions.minus_one, 1);
+        assert_eq!(reactions.laugh, 2);
+        assert_eq!(reactions.hooray, 1);
+        assert_eq!(reactions.confused, 1);
+        assert_eq!(reactions.heart, 3);
+        assert_eq!(reactions.rocket, 1);
+        assert_eq!(reactions.eyes, 1);
+    }
+
+    #[test]
+    fn sort_issues_by_number() {
+        let event: IssueEvent = serde_json::from_str(include_str!(
+            "../tests/resources/cross_referenced_event.json"
+        ))
+        .unwrap();
+        let issue = event.source.unwrap().issue;
+        let with_number = |number: u64, id: u64| Issue {
+            number: number.into(),
+            id: id.into(),
+            ..issue.clone()
+        };
+        let mut issues = [
+            with_number(3, 30),
+            with_number(1, 11),
+            with_number(2, 20),
+            with_number(1, 10),
+        ];
+
+        issues.sort_by_key(Issue::sort_key);
+
+        let keys: Vec<_> = issues.iter().map(Issue::sort_key).collect();
+        assert_eq!(keys, [(1, 10), (1, 11), (2, 20), (3, 30)]);
+    }
+
+    #[test]
+    fn issue_predicates() {
+        let event: IssueEvent = serde_json::from_str(include_str!(
+            "../tests/resources/cross_referenced_event.json"
+        ))
+        .unwrap();
+        let open = event.source.unwrap().issue;
+        let label: Label = serde_json::from_value(serde_json::json!({
+            "id": 208045946,
+            "node_id": "MDU6TGFiZWwyMDgwNDU5NDY=",
+            "url": "https://api.github.com/repos/octocat/Hello-World/labels/bug",
+            "name": "Bug",
+            "description": "Something isn't working",
+            "color": "f29513",
+            "default": true
+        }))
+        .unwrap();
+        let closed = Issue {
+            state: String::from("closed"),
+            labels: vec![label],
+            ..open.clone()
+        };
+
+        assert!(open.is_open());
+        assert!(!open.is_closed());
+        assert!(!open.has_label("bug"));
+        assert!(closed.is_closed());
+        assert!(!closed.is_open());
+        assert!(closed.has_label("bug"));
+        assert!(closed.has_label("BUG"));
+        assert!(!closed.has_label("enhancement"));
+
+        let issues = [open, closed];
+        let closed_bugs = issues
+            .iter()
+            .filter(|issue| issue.is_closed() && issue.has_label("bug"))
+            .count();
+        assert_eq!(closed_bugs, 1);
+    }
+
+    #[test]
+    fn pull_request_predicates() {
+        let pull_request: PullRequest =
+            serde_json::from_str(include_str!("../tests/resources/pull_request.json")).unwrap();
+        let closed_draft = PullRequest {
+            state: IssueState::Closed,
+            draft: true,
+            ..pull_request.clone()
+        };
+
+        assert!(pull_request.is_open());
+        assert!(!pull_request.is_draft());
+        assert!(!closed_draft.is_open());
+        assert!(closed_draft.is_draft());
+    }
+
+    #[test]
+    fn sort_pull_requests_by_number() {
+        let pull_request: PullRequest =
+            serde_json::from_str(include_str!("../tests/resources/pull_request.json")).unwrap();
+        let with_number = |number: u64, id: u64| PullRequest {
+            number: number.into(),
+            id: id.into(),
+            ..pull_request.clone()
+        };
+        let mut pull_requests = [
+            with_number(42, 420),
+            with_number(7, 71),
+            with_number(1347, 13470),
+            with_number(7, 70),
+        ];
+
+        pull_requests.sort_by_key(PullRequest::sort_key);
+
+        let keys: Vec<_> = pull_requests.iter().map(PullRequest::sort_key).collect();
+        assert_eq!(keys, [(7, 70), (7, 71), (42, 420), (1347, 13470)]);
+    }
+
+    #[test]
+    fn deserialize_cross_referenced_event() {
+        let event: IssueEvent = serde_json::from_str(include_str!(
+            "../tests/resources/cross_referenced_event.json"
+        ))
+        .unwrap();
+
+        assert_eq!(event.event, Some(Event::CrossReferenced));
+        let source = event.source.unwrap();
+        assert_eq!(source.r#type, "issue");
+        assert_eq!(source.issue.number, 42);
+        assert_eq!(
+            source.issue.repository_url.as_str(),
+            "https://api.github.com/repos/octocat/Spoon-Knife"
+        );
+        assert!(source.issue.pull_request.is_some());
+    }
+
+    #[test]
+    fn pull_request_detail_fields() {
+        let pull_request: PullRequest =
+            serde_json::from_str(include_str!("../tests/resources/pull_request.json")).unwrap();
+
+        assert!(pull_request.is_detail_loaded());
+        assert_eq!(pull_request.additions_or_zero(), 100);
+        assert_eq!(pull_request.deletions_or_zero(), 3);
+        assert_eq!(pull_request.changed_files_or_zero(), 5);
+        assert_eq!(pull_request.commits_or_zero(), 3);
+    }
+
+    #[test]
+    fn listed_pull_request_detail_fields() {
+        let mut value: serde_json::Value =
+            serde_json::from_str(include_str!("../tests/resources/pull_request.json")).unwrap();
+        for field in &[
+            "additions",
+            "deletions",
+            "changed_files",
+            "commits",
+            "comments",
+            "review_comments",
+            "merged",
+            "mergeable_state",
+        ] {
+            value.as_object_mut().unwrap().remove(*field);
+        }
+
+        let pull_request: PullRequest = serde_json::from_value(value).unwrap();
+
+        assert!(!pull_request.is_detail_loaded());
+        assert_eq!(pull_request.additions_or_zero(), 0);
+        assert_eq!(pull_request.deletions_or_zero(), 0);
+        assert_eq!(pull_request.changed_files_or_zero(), 0);
+        assert_eq!(pull_request.commits_or_zero(), 0);
+    }
+
+    #[test]
+    fn deserialize_pull_request_from_deleted_fork() {
+        let mut value: serde_json::Value =
+            serde_json::from_str(include_str!("../tests/resources/pull_request.json")).unwrap();
+        value["head"]["label"] = serde_json::json!("unknown repository");
+        value["head"]["user"] = serde_json::Value::Null;
+        value["head"]["repo"] = serde_json::Value::Null;
+
+        let pull_request: PullRequest = serde_json::from_value(value).unwrap();
+
+        assert_eq!(pull_request.head.user, None);
+        assert_eq!(pull_request.head.repo, None);
+        assert!(pull_request.base.user.is_some());
+        assert!(pull_request.base.repo.is_some());
+    }
+
+    #[test]
+    fn deserialize_combined_status() {
+        let status: CombinedStatus =
+            serde_json::from_str(include_str!("../tests/resources/combined_status.json")).unwrap();
+
+        assert_eq!(status.state, StatusState::Failure);
+        assert_eq!(status.total_count, 2);
+        assert_eq!(status.statuses.len(), 2);
+        assert_eq!(status.statuses[0].context, "continuous-integration/jenkins");
+        assert_eq!(status.statuses[0].state, StatusState::Success);
+        assert_eq!(status.statuses[1].context, "security/brakeman");
+        assert_eq!(status.statuses[1].state, StatusState::Error);
+    }
+
+    #[test]
+    fn deserialize_tag_protection() {
+        let protections: Vec<TagProtection> =
+            serde_json::from_str(include_str!("../tests/resources/tag_protection.json")).unwrap();
+
+        assert_eq!(protections.len(), 1);
+        assert_eq!(protections[0].id, 2);
+        assert_eq!(protections[0].pattern, "v1.*");
+        assert_eq!(
+            protections[0].created_at.map(|date| date.to_rfc3339()),
+            Some("2022-05-17T21:42:44+00:00".to_owned())
+        );
+        assert_eq!(protections[0].enabled, Some(true));
+    }
+
+    #[test]
+    fn deserialize_deployment() {
+        let deployment: Deployment =
+            serde_json::from_str(include_str!("../tests/resources/deployment.json")).unwrap();
+
+        assert_eq!(deployment.id, 1);
+        assert_eq!(deployment.sha, "a84d88e7554fc1fa21bcbc4efae3c782a70d2b9d");
+        assert_eq!(deployment.r#ref, "topic-branch");
+        assert_eq!(deployment.task, "deploy");
+        assert_eq!(deployment.environment, "production");
+        assert_eq!(
+            deployment.description.as_deref(),
+            Some("Deploy request from hubot")
+        );
+        assert_eq!(deployment.creator.unwrap().login, "octocat");
+        assert_eq!(
+            deployment.statuses_url.as_str(),
+            "https://api.github.com/repos/octocat/example/deployments/1/statuses"
+        );
+    }
+
+    #[test]
+    fn deserialize_single_repository() {
+        let repo: Repository = serde_json::from_str(REPOSITORY).unwrap();
+
+        assert!(!repo.fork);
+        assert_eq!(repo.subscribers_count, Some(42));
+        assert_eq!(repo.network_count, Some(108));
+    }
+
+    #[test]
+    fn repository_timestamps_and_staleness() {
+        let mut repo: Repository = serde_json::from_str(REPOSITORY).unwrap();
+        let day = chrono::Duration::days(1);
+
+        assert_eq!(
+            repo.pushed_at.unwrap().to_rfc3339(),
+            "2011-01-26T19:06:43+00:00"
+        );
+        assert!(repo.is_stale(day));
+
+        repo.pushed_at = Some(chrono::Utc::now());
+        assert!(!repo.is_stale(day));
+    }
+
+    #[test]
+    fn empty_repository_staleness() {
+        let mut value: serde_json::Value = serde_json::from_str(REPOSITORY).unwrap();
+        value["pushed_at"] = serde_json::Value::Null;
+        let mut repo: Repository = serde_json::from_value(value).unwrap();
+        let day = chrono::Duration::days(1);
+
+        assert_eq!(repo.pushed_at, None);
+        assert!(repo.is_stale(day));
+
+        repo.created_at = chrono::Utc::now();
+        assert!(!repo.is_stale(day));
+    }
+
+    #[test]
+    fn deserialize_listed_repository() {
+        let mut value: serde_json::Value = serde_json::from_str(REPOSITORY).unwrap();
+        let object = value.as_object_mut().unwrap();
+        object.remove("subscribers_count");
+        object.remove("network_count");
+
+        let repo: Repository = serde_json::from_value(value).unwrap();
+
+        assert_eq!(repo.subscribers_count, None);
+        assert_eq!(repo.network_count, None);
+    }
+
+    #[test]
+    fn deserialize_recursive_tree() {
+        let tree: GitTree = serde_json::from_value(serde_json::json!({
+            "sha": "fc6274d15fa3ae2ab983129fb037999f264ba9a7",
+            "url": "https://api.github.com/repos/octocat/Hello-World/trees/fc6274d15fa3ae2ab983129fb037999f264ba9a7",
+            "tree": [
+                {
+                    "path": "README.md",
+                    "mode": "100644",
+                    "type": "blob",
+                    "size": 30,
+                    "sha": "44b4fc6d56897b048c772eb4087f854f46256132",
+                    "url": "https://api.github.com/repos/octocat/Hello-World/git/blobs/44b4fc6d56897b048c772eb4087f854f46256132"
+                },
+                {
+                    "path": "src",
+                    "mode": "040000",
+                    "type": "tree",
+                    "sha": "f484d249c660418515fb01c2b9662073663c242e",
+                    "url": "https://api.github.com/repos/octocat/Hello-World/git/trees/f484d249c660418515fb01c2b9662073663c242e"
+                },
+                {
+                    "path": "src/lib.rs",
+                    "mode": "100644",
+                    "type": "blob",
+                    "size": 75,
+                    "sha": "7c258a9869f33c1e1e1f74fbb32f07c86cb5a75b",
+                    "url": "https://api.github.com/repos/octocat/Hello-World/git/blobs/7c258a9869f33c1e1e1f74fbb32f07c86cb5a75b"
+                },
+                {
+                    "path": "vendor/lib",
+                    "mode": "160000",
+                    "type": "commit",
+                    "sha": "d8fe77a9b6e8fd4394edbc6a2de3b60748d13840"
+                }
+            ],
+            "truncated": true
+        }))
+        .unwrap();
+
+        assert!(tree.truncated);
+        assert_eq!(tree.tree.len(), 4);
+        assert_eq!(tree.tree[3].r#type, GitTreeEntryType::Commit);
+        assert_eq!(tree.tree[3].url, None);
+        assert_eq!(
+            tree.blob_paths("").collect::<Vec<_>>(),
+            ["README.md", "src/lib.rs"]
+        );
+        assert_eq!(tree.blob_paths("src/").collect::<Vec<_>>(), ["src/lib.rs"]);
+    }
+
+    #[test]
+    fn code_frequency_out_of_range_week() {
+        let frequency: CodeFrequency =
+            serde_json::from_value(serde_json::json!([1302998400, 1124, -435])).unwrap();
+        assert_eq!(
+            frequency.week,
+            "2011-04-17T00:00:00Z"
+                .parse::<chrono::DateTime<chrono::Utc>>()
+                .unwrap()
+        );
+
+        let error = serde_json::from_value::<CodeFrequency>(serde_json::json!([i64::MAX, 1, -1]))
+            .unwrap_err();
+        assert!(error.to_string().contains("invalid week timestamp"));
+    }
+}
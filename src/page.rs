@@ -3,6 +3,14 @@ use snafu::ResultExt;
 use url::Url;
 
 /// A Page of GitHub results, with links to the next and previous page.
+///
+/// Endpoints that wrap their results in an object (e.g. `{ "total_count": 1,
+/// "check_runs": [...] }`) are also supported, in which case `total_count` is
+/// set and `items` is the list of results. Following pages fetched with
+/// [`Page::next`](#method.next) or [`Octocrab::all_pages`] are unwrapped the
+/// same way.
+///
+/// [`Octocrab::all_pages`]: ./struct.Octocrab.html#method.all_pages
 /// ```no_run
 ///# async fn run() -> octocrab::Result<()> {
 /// let octocrab = octocrab::instance();
@@ -19,14 +27,268 @@ use url::Url;
 #[derive(Clone, Debug)]
 pub struct Page<T> {
     pub items: Vec<T>,
+    /// The total number of results across all pages, only present for
+    /// endpoints that wrap their results in an object.
+    pub total_count: Option<u64>,
+    /// Whether GitHub timed out before finding every result, only present
+    /// for search results.
+    pub incomplete_results: Option<bool>,
     pub next: Option<Url>,
     pub prev: Option<Url>,
+    pub first: Option<Url>,
+    pub last: Option<Url>,
+    /// The `ETag` of the response, which can be persisted and passed to
+    /// e.g. [`ListIssuesBuilder::etag`] to only fetch the page again if it
+    /// has changed.
+    ///
+    /// [`ListIssuesBuilder::etag`]: ./issues/struct.ListIssuesBuilder.html#method.etag
+    pub etag: Option<String>,
+    /// Whether GitHub responded with `304 Not Modified` to a conditional
+    /// request, in which case the page is empty and the results from the
+    /// previous request are still current.
+    pub not_modified: bool,
+    /// The key of the object the results are wrapped in, if it isn't
+    /// `items`, so that following pages can be unwrapped the same way.
+    pub(crate) envelope_key: Option<&'static str>,
+}
+
+impl<T: serde::de::DeserializeOwned> Page<T> {
+    /// Gets the next page of results, following the `next` link GitHub
+    /// returned. Returns `None` if this is the last page.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::instance();
+    /// let mut page = octocrab.issues("owner", "repo").list().send().await?;
+    ///
+    /// loop {
+    ///     for issue in &page.items {
+    ///         println!("{}", issue.title);
+    ///     }
+    ///
+    ///     page = match page.next(&octocrab).await? {
+    ///         Some(next) => next,
+    ///         None => break,
+    ///     };
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn next(&self, crab: &crate::Octocrab) -> crate::Result<Option<Page<T>>> {
+        crab.get_page_in(&self.next, self.envelope_key).await
+    }
+
+    /// Deserializes a page from `response`, whose results are either a JSON
+    /// array, or wrapped in an object under `envelope_key` (`items` if
+    /// `None`, as used by search results). An object without that key is a
+    /// `Json` error.
+    pub(crate) async fn from_response_in(
+        response: reqwest::Response,
+        envelope_key: Option<&'static str>,
+    ) -> crate::Result<Self> {
+        let etag = response
+            .headers()
+            .get(reqwest::header::ETAG)
+            .and_then(|etag| etag.to_str().ok())
+            .map(String::from);
+
+        if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+            return Ok(Self {
+                etag,
+                not_modified: true,
+                envelope_key,
+                ..Self::default()
+            });
+        }
+
+        let links = get_links(&response)?;
+        let (items, total_count, incomplete_results) =
+            match crate::FromResponse::from_response(response).await? {
+                serde_json::Value::Object(mut envelope) => {
+                    let total_count = envelope
+                        .get("total_count")
+                        .and_then(serde_json::Value::as_u64);
+                    let incomplete_results = envelope
+                        .get("incomplete_results")
+                        .and_then(serde_json::Value::as_bool);
+                    // Without the key, deserializing the envelope itself fails.
+                    let items = match envelope.remove(envelope_key.unwrap_or("items")) {
+                        Some(items) => items,
+                        None => serde_json::Value::Object(envelope),
+                    };
+
+                    (items, total_count, incomplete_results)
+                }
+                items => (items, None, None),
+            };
+
+        Ok(Self {
+            items: serde::Deserialize::deserialize(&items).with_context(|| crate::error::Json {
+                json: items.clone(),
+            })?,
+            total_count,
+            incomplete_results,
+            next: links.next,
+            prev: links.prev,
+            first: links.first,
+            last: links.last,
+            etag,
+            not_modified: false,
+            envelope_key,
+        })
+    }
 }
 
 impl<T> Page<T> {
     /// Returns the current set of items, replacing it with an empty Vec.
     pub fn take_items(&mut self) -> Vec<T> {
-        std::mem::replace(&mut self.items, Vec::new())
+        std::mem::take(&mut self.items)
+    }
+
+    /// Returns a cursor pointing at the next page, if there is one. The
+    /// cursor can be saved and later used to resume listing from the next
+    /// page, e.g. with [`ListIssuesBuilder::resume_from`].
+    ///
+    /// [`ListIssuesBuilder::resume_from`]: ./issues/struct.ListIssuesBuilder.html#method.resume_from
+    pub fn next_cursor(&self) -> Option<PaginationCursor> {
+        self.next.as_ref().and_then(PaginationCursor::from_url)
+    }
+
+    /// The total number of pages, from the `page` query parameter of the
+    /// `last` link. `None` on the last page, and when all of the results fit
+    /// on a single page, as GitHub doesn't return a `last` link then.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::instance();
+    /// let page = octocrab.issues("owner", "repo").list().page(3u32).send().await?;
+    ///
+    /// if let Some(total_pages) = page.total_pages() {
+    ///     println!("page 3 of {}", total_pages);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn total_pages(&self) -> Option<u32> {
+        PaginationCursor::from_url(self.last.as_ref()?).map(|cursor| cursor.page)
+    }
+
+    /// The number of this page, derived from the `next` or `prev` link.
+    /// `None` if GitHub didn't return either, e.g. because all of the
+    /// results fit on a single page.
+    pub fn page_number(&self) -> Option<u32> {
+        let page = |url: &Option<Url>| PaginationCursor::from_url(url.as_ref()?);
+
+        match (page(&self.next), page(&self.prev)) {
+            (Some(next), _) => next.page.checked_sub(1),
+            (None, Some(prev)) => prev.page.checked_add(1),
+            (None, None) => None,
+        }
+    }
+
+    /// The number of the last page. Unlike [`total_pages`](#method.total_pages)
+    /// this is also known on the last page itself, where it's the number of
+    /// this page.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::instance();
+    /// let page = octocrab.issues("owner", "repo").list().page(3u32).send().await?;
+    ///
+    /// if let (Some(number), Some(last)) = (page.page_number(), page.last_page()) {
+    ///     println!("page {} of {}", number, last);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn last_page(&self) -> Option<u32> {
+        match self.next {
+            Some(_) => self.total_pages(),
+            None => self.page_number(),
+        }
+    }
+
+    /// The URLs of every page after this one, derived from the `next` and
+    /// `last` links. `None` if GitHub didn't return a `last` link, or if the
+    /// links don't paginate with a `page` query parameter.
+    #[cfg(feature = "stream")]
+    pub(crate) fn remaining_page_urls(&self) -> Option<Vec<Url>> {
+        let next = self.next.as_ref()?;
+        let first = PaginationCursor::from_url(next)?.page;
+        let last = PaginationCursor::from_url(self.last.as_ref()?)?.page;
+        let query: Vec<(String, String)> = next
+            .query_pairs()
+            .filter(|(key, _)| key != "page")
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect();
+
+        Some(
+            (first..=last)
+                .map(|page| {
+                    let mut url = next.clone();
+                    url.query_pairs_mut()
+                        .clear()
+                        .extend_pairs(&query)
+                        .append_pair("page", &page.to_string());
+                    url
+                })
+                .collect(),
+        )
+    }
+}
+
+/// A serializable position in a list of results, that allows you to persist
+/// the progress of a long crawl and resume it later.
+///
+/// The serialized form of the cursor is `{ "page": 2, "per_page": 100 }`,
+/// this format is stable and will keep working with future versions of
+/// `octocrab`. Since GitHub paginates by offset, resuming from a cursor can
+/// skip or repeat results if items were added or removed in the meantime.
+/// ```no_run
+/// # async fn run() -> octocrab::Result<()> {
+/// let octocrab = octocrab::instance();
+/// let page = octocrab.issues("owner", "repo").list().send().await?;
+/// let checkpoint = serde_json::to_string(&page.next_cursor()).unwrap();
+///
+/// // Later on.
+/// if let Some(cursor) = serde_json::from_str(&checkpoint).unwrap() {
+///     let page = octocrab
+///         .issues("owner", "repo")
+///         .list()
+///         .resume_from(cursor)
+///         .send()
+///         .await?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct PaginationCursor {
+    /// The page number to resume from.
+    pub page: u32,
+    /// The number of results per page, `None` uses GitHub's default.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub per_page: Option<u8>,
+}
+
+impl PaginationCursor {
+    /// Creates a cursor from a page URL, such as [`Page::next`]. Returns
+    /// `None` if the URL doesn't have a `page` query parameter.
+    ///
+    /// [`Page::next`]: ./struct.Page.html#structfield.next
+    pub fn from_url(url: &Url) -> Option<Self> {
+        let mut page = None;
+        let mut per_page = None;
+
+        for (key, value) in url.query_pairs() {
+            match &*key {
+                "page" => page = value.parse().ok(),
+                "per_page" => per_page = value.parse().ok(),
+                _ => {}
+            }
+        }
+
+        Some(Self {
+            page: page?,
+            per_page,
+        })
     }
 }
 
@@ -34,8 +296,15 @@ impl<T> Default for Page<T> {
     fn default() -> Self {
         Self {
             items: Vec::new(),
+            total_count: None,
+            incomplete_results: None,
             next: None,
             prev: None,
+            first: None,
+            last: None,
+            etag: None,
+            not_modified: false,
+            envelope_key: None,
         }
     }
 }
@@ -52,33 +321,364 @@ impl<T> IntoIterator for Page<T> {
 #[async_trait::async_trait]
 impl<T: serde::de::DeserializeOwned> crate::FromResponse for Page<T> {
     async fn from_response(response: reqwest::Response) -> crate::Result<Self> {
-        let (prev, next) = get_links(&response)?;
-
-        Ok(Self {
-            items: crate::FromResponse::from_response(response).await?,
-            next,
-            prev,
-        })
+        Self::from_response_in(response, None).await
     }
 }
 
-fn get_links(response: &reqwest::Response) -> crate::Result<(Option<Url>, Option<Url>)> {
-    let mut prev = None;
-    let mut next = None;
+#[derive(Default)]
+struct Links {
+    next: Option<Url>,
+    prev: Option<Url>,
+    first: Option<Url>,
+    last: Option<Url>,
+}
+
+/// Clamps a builder's `per_page` to the `1..=100` range GitHub accepts, as
+/// GitHub silently caps larger values instead of rejecting them.
+pub(crate) fn clamp_per_page(per_page: impl std::convert::TryInto<i64>) -> u8 {
+    per_page.try_into().unwrap_or(i64::MAX).clamp(1, 100) as u8
+}
+
+/// Parses the `Link` header of `response`, GitHub omits the header entirely
+/// when all of the results fit on a single page.
+fn get_links(response: &reqwest::Response) -> crate::Result<Links> {
+    let mut links = Links::default();
 
     if let Ok(link_header) = response.headers().decode::<hyperx::header::Link>() {
         for value in link_header.values() {
             if let Some(relations) = value.rel() {
+                let url = || Url::parse(value.link()).context(crate::error::Url);
+
                 if relations.contains(&hyperx::header::RelationType::Next) {
-                    next = Some(Url::parse(value.link()).context(crate::error::Url)?);
+                    links.next = Some(url()?);
                 }
 
                 if relations.contains(&hyperx::header::RelationType::Prev) {
-                    prev = Some(Url::parse(value.link()).context(crate::error::Url)?);
+                    links.prev = Some(url()?);
+                }
+
+                if relations.contains(&hyperx::header::RelationType::First) {
+                    links.first = Some(url()?);
+                }
+
+                if relations.contains(&hyperx::header::RelationType::Last) {
+                    links.last = Some(url()?);
                 }
             }
         }
     }
 
-    Ok((prev, next))
+    Ok(links)
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::{
+        matchers::{method, path, query_param},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[tokio::test]
+    async fn all_pages() {
+        let server = MockServer::start().await;
+        let page_url = |page| format!("{}/items?page={}", server.uri(), page);
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .and(query_param("page", "2"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header(
+                        "link",
+                        &*format!(
+                            r#"<{}>; rel="prev", <{}>; rel="first""#,
+                            page_url(1),
+                            page_url(1)
+                        ),
+                    )
+                    .set_body_json(serde_json::json!([3])),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header(
+                        "link",
+                        &*format!(
+                            r#"<{}>; rel="next", <{}>; rel="last""#,
+                            page_url(2),
+                            page_url(2)
+                        ),
+                    )
+                    .set_body_json(serde_json::json!([1, 2])),
+            )
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let first: crate::Page<u32> = octocrab.get("/items", None::<&()>).await.unwrap();
+        assert_eq!(
+            first.last.as_ref().map(|url| url.as_str()),
+            Some(&*page_url(2))
+        );
+
+        let last = first.next(&octocrab).await.unwrap().unwrap();
+        assert_eq!(last.items, [3]);
+        assert!(last.next.is_none());
+        assert_eq!(
+            last.first.as_ref().map(|url| url.as_str()),
+            Some(&*page_url(1))
+        );
+        assert!(last.next(&octocrab).await.unwrap().is_none());
+
+        assert_eq!(octocrab.all_pages(first).await.unwrap(), [1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn total_pages() {
+        let server = MockServer::start().await;
+        let page_url = |page| format!("{}/items?per_page=2&page={}", server.uri(), page);
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .and(query_param("page", "3"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header(
+                        "link",
+                        &*format!(
+                            r#"<{}>; rel="first", <{}>; rel="prev", <{}>; rel="next", <{}>; rel="last""#,
+                            page_url(1),
+                            page_url(2),
+                            page_url(4),
+                            page_url(47)
+                        ),
+                    )
+                    .set_body_json(serde_json::json!([5, 6])),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .and(query_param("page", "47"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header(
+                        "link",
+                        &*format!(
+                            r#"<{}>; rel="first", <{}>; rel="prev""#,
+                            page_url(1),
+                            page_url(46)
+                        ),
+                    )
+                    .set_body_json(serde_json::json!([93])),
+            )
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let page: crate::Page<u32> = octocrab.get("/items", Some(&[("page", 3)])).await.unwrap();
+        assert_eq!(page.total_pages(), Some(47));
+        assert_eq!(page.page_number(), Some(3));
+        assert_eq!(page.last_page(), Some(47));
+        assert_eq!(page.next_cursor().map(|cursor| cursor.page), Some(4));
+
+        let last: crate::Page<u32> = octocrab.get("/items", Some(&[("page", 47)])).await.unwrap();
+        assert_eq!(last.total_pages(), None);
+        assert_eq!(last.page_number(), Some(47));
+        assert_eq!(last.last_page(), Some(47));
+
+        let single = crate::Page::<u32>::default();
+        assert_eq!(single.total_pages(), None);
+        assert_eq!(single.page_number(), None);
+        assert_eq!(single.last_page(), None);
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn into_stream() {
+        use futures_util::StreamExt;
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .and(query_param("page", "3"))
+            .respond_with(ResponseTemplate::new(502))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .and(query_param("page", "2"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header(
+                        "link",
+                        &*format!(r#"<{}/items?page=3>; rel="next""#, server.uri()),
+                    )
+                    .set_body_json(serde_json::json!([3])),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let page = crate::Page {
+            items: vec![1, 2],
+            next: Some(format!("{}/items?page=2", server.uri()).parse().unwrap()),
+            ..crate::Page::default()
+        };
+        let mut stream = Box::pin(octocrab.into_stream::<u32>(page));
+
+        assert_eq!(stream.next().await.unwrap().unwrap(), 1);
+        assert_eq!(stream.next().await.unwrap().unwrap(), 2);
+        // The second page is only requested once the first has been drained.
+        assert!(server.received_requests().await.unwrap().is_empty());
+        assert_eq!(stream.next().await.unwrap().unwrap(), 3);
+        assert_eq!(
+            stream
+                .next()
+                .await
+                .unwrap()
+                .unwrap_err()
+                .as_github()
+                .unwrap()
+                .status_code,
+            502
+        );
+        assert!(stream.next().await.is_none());
+    }
+
+    #[cfg(feature = "stream")]
+    #[tokio::test]
+    async fn into_stream_with_prefetch() {
+        use futures_util::TryStreamExt;
+
+        let server = MockServer::start().await;
+        let page_url = |page: u32| format!("{}/items?per_page=1&page={}", server.uri(), page);
+        for (page, delay) in [(2, 300), (3, 100), (4, 0)] {
+            Mock::given(method("GET"))
+                .and(path("/items"))
+                .and(query_param("page", page.to_string()))
+                .and(query_param("per_page", "1"))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_delay(std::time::Duration::from_millis(delay))
+                        .set_body_json(serde_json::json!([page])),
+                )
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let octocrab = crate::setup(&server);
+        let page = crate::Page {
+            items: vec![1],
+            next: Some(page_url(2).parse().unwrap()),
+            last: Some(page_url(4).parse().unwrap()),
+            ..crate::Page::default()
+        };
+        let items: Vec<u32> = octocrab
+            .into_stream_with_prefetch(page, 3)
+            .try_collect()
+            .await
+            .unwrap();
+
+        // Later pages respond first, but the items keep their order.
+        assert_eq!(items, [1, 2, 3, 4]);
+    }
+
+    #[tokio::test]
+    async fn missing_link_header() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/items"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([1])))
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let page: crate::Page<u32> = octocrab.get("/items", None::<&()>).await.unwrap();
+
+        assert!(page.next.is_none() && page.prev.is_none());
+        assert!(page.first.is_none() && page.last.is_none());
+        assert!(page.next(&octocrab).await.unwrap().is_none());
+        assert_eq!(octocrab.all_pages(page).await.unwrap(), [1]);
+    }
+
+    #[tokio::test]
+    async fn enveloped_pages() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/runs"))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 3,
+                "check_runs": [3],
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/runs"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header(
+                        "link",
+                        &*format!(r#"<{}/runs?page=2>; rel="next""#, server.uri()),
+                    )
+                    .set_body_json(serde_json::json!({
+                        "total_count": 3,
+                        "check_runs": [1, 2],
+                    })),
+            )
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let first: crate::Page<u32> = octocrab
+            .get_enveloped_page(
+                "/runs",
+                None::<&()>,
+                reqwest::header::HeaderMap::new(),
+                "check_runs",
+            )
+            .await
+            .unwrap();
+        assert_eq!(first.items, [1, 2]);
+        assert_eq!(first.total_count, Some(3));
+
+        let last = first.next(&octocrab).await.unwrap().unwrap();
+        assert_eq!(last.items, [3]);
+
+        assert_eq!(octocrab.all_pages(first).await.unwrap(), [1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn missing_envelope_key() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/runs"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "total_count": 1,
+                "check_suites": [1],
+            })))
+            .mount(&server)
+            .await;
+
+        let octocrab = crate::setup(&server);
+        let result = octocrab
+            .get_enveloped_page::<u32, _, _>(
+                "/runs",
+                None::<&()>,
+                reqwest::header::HeaderMap::new(),
+                "check_runs",
+            )
+            .await;
+        assert!(result.unwrap_err().as_json().is_some());
+
+        let result = octocrab
+            .get::<crate::Page<u32>, _, _>("/runs", None::<&()>)
+            .await;
+        assert!(result.unwrap_err().as_json().is_some());
+    }
 }
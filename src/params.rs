@@ -1,7 +1,7 @@
 //! # Common GitHub Parameter Types
 
-/// The status of a issue or pull request.
-#[derive(Debug, Clone, Copy, serde::Serialize)]
+/// The status of a issue or pull request. `All` is only valid as a filter.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "lowercase")]
 #[non_exhaustive]
 pub enum State {
@@ -10,9 +10,8 @@ pub enum State {
     Closed,
 }
 
-/// What to sort results by. Can be either `created`, `updated`, `popularity`
-/// (comment count) or `long-running` (age, filtering by pulls updated in the
-/// last month).
+/// The direction to sort results in, either ascending (`asc`) or
+/// descending (`desc`).
 #[derive(Debug, Clone, Copy, serde::Serialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
@@ -23,34 +22,78 @@ pub enum Direction {
     Descending,
 }
 
-/// The reason for locking an issue.
-#[derive(Debug, Clone, Copy, serde::Serialize)]
-#[non_exhaustive]
-pub enum LockReason {
-    #[serde(rename = "off-topic")]
-    OffTopic,
-    #[serde(rename = "too heated")]
-    TooHeated,
-    #[serde(rename = "resolved")]
-    Resolved,
-    #[serde(rename = "spam")]
-    Spam,
+#[doc(hidden)]
+pub use self::issues::LockReason;
+
+pub mod checks {
+    //! Parameter types for the checks API.
+
+    /// The status of a check run.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum Status {
+        Queued,
+        InProgress,
+        Completed,
+    }
+
+    /// Which check runs to return when a check suite has been run more than
+    /// once.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum Filter {
+        /// Only the most recent check runs, the default.
+        Latest,
+        /// Every check run.
+        All,
+    }
 }
 
 pub mod issues {
     //! Parameter types for the issues API.
 
-    /// What to sort the results by. Can be either `created`, `updated`,
-    /// or `comments`.
+    /// What to sort the results by. The issues endpoints only accept
+    /// `created`, `updated`, or `comments`, unlike pull requests there is no
+    /// `popularity` or `long-running` sort for issues.
     #[derive(Debug, Clone, Copy, serde::Serialize)]
     #[serde(rename_all = "snake_case")]
     #[non_exhaustive]
     pub enum Sort {
+        /// Sort by when the issue was created.
         Created,
+        /// Sort by when the issue was last updated.
         Updated,
+        /// Sort by the number of comments.
         Comments,
     }
 
+    /// What to sort milestones by.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum MilestoneSort {
+        /// Sort by the due date, the default.
+        DueOn,
+        /// Sort by the fraction of issues that are closed.
+        Completeness,
+    }
+
+    /// The reason for locking an issue or pull request's conversation.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[non_exhaustive]
+    pub enum LockReason {
+        #[serde(rename = "off-topic")]
+        OffTopic,
+        #[serde(rename = "too heated")]
+        TooHeated,
+        #[serde(rename = "resolved")]
+        Resolved,
+        #[serde(rename = "spam")]
+        Spam,
+    }
+
     /// A generic filter type that allows you to filter either by exact match,
     /// any match, or no matches.
     #[derive(Debug, Clone, Copy)]
@@ -74,6 +117,23 @@ pub mod issues {
         }
     }
 
+    impl<T> Filter<T> {
+        /// Only matches `value` exactly.
+        pub fn exact(value: T) -> Self {
+            Self::Matches(value)
+        }
+
+        /// Matches anything, as long as it's set.
+        pub fn any() -> Self {
+            Self::Any
+        }
+
+        /// Only matches when it isn't set.
+        pub fn none() -> Self {
+            Self::None
+        }
+    }
+
     impl<T: serde::Serialize> From<T> for Filter<T> {
         fn from(value: T) -> Self {
             Self::Matches(value)
@@ -84,6 +144,22 @@ pub mod issues {
     mod tests {
         use super::*;
 
+        #[test]
+        fn serialize_sort() {
+            assert_eq!(
+                r#""created""#,
+                serde_json::to_string(&Sort::Created).unwrap()
+            );
+            assert_eq!(
+                r#""updated""#,
+                serde_json::to_string(&Sort::Updated).unwrap()
+            );
+            assert_eq!(
+                r#""comments""#,
+                serde_json::to_string(&Sort::Comments).unwrap()
+            );
+        }
+
         #[test]
         fn serialize() {
             assert_eq!(
@@ -100,6 +176,22 @@ pub mod issues {
                 serde_json::to_string(&Filter::<()>::None).unwrap()
             );
         }
+
+        #[test]
+        fn serialize_milestone() {
+            assert_eq!(
+                serde_json::json!(5),
+                serde_json::to_value(Filter::<u64>::exact(5)).unwrap()
+            );
+            assert_eq!(
+                serde_json::json!("*"),
+                serde_json::to_value(Filter::<u64>::any()).unwrap()
+            );
+            assert_eq!(
+                serde_json::json!("none"),
+                serde_json::to_value(Filter::<u64>::none()).unwrap()
+            );
+        }
     }
 }
 
@@ -116,6 +208,88 @@ pub mod orgs {
         Member,
         Admin,
     }
+
+    /// The default permission level members have for an organization's
+    /// repositories.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum DefaultRepositoryPermission {
+        /// Members can pull, but not push to or administer the repositories.
+        Read,
+        /// Members can pull and push, but not administer the repositories.
+        Write,
+        /// Members can pull, push, and administer the repositories.
+        Admin,
+        /// Members have no permissions to the repositories.
+        None,
+    }
+
+    /// Who to invite to an organization, either by their email address or
+    /// their GitHub user id.
+    #[derive(Debug, Clone, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum Invitee {
+        Email(String),
+        InviteeId(u64),
+    }
+
+    impl From<String> for Invitee {
+        fn from(email: String) -> Self {
+            Self::Email(email)
+        }
+    }
+
+    impl From<&str> for Invitee {
+        fn from(email: &str) -> Self {
+            Self::Email(email.to_owned())
+        }
+    }
+
+    impl From<u64> for Invitee {
+        fn from(id: u64) -> Self {
+            Self::InviteeId(id)
+        }
+    }
+
+    /// The role an invited user will have in the organization.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum InvitationRole {
+        /// Organization owner.
+        Admin,
+        /// A non-owner member.
+        DirectMember,
+        /// A non-member who can manage the organization's billing.
+        BillingManager,
+    }
+
+    /// Which repositories of the organization can access a secret.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum SecretVisibility {
+        /// Every repository.
+        All,
+        /// Only private and internal repositories.
+        Private,
+        /// Only the repositories that were explicitly selected.
+        Selected,
+    }
+
+    /// Which outside collaborators to list.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum OutsideCollaboratorFilter {
+        /// Only outside collaborators without two-factor authentication
+        /// enabled.
+        #[serde(rename = "2fa_disabled")]
+        TwoFactorDisabled,
+        All,
+    }
 }
 
 pub mod pulls {
@@ -131,8 +305,129 @@ pub mod pulls {
         Created,
         Updated,
         Popularity,
+        #[serde(rename = "long-running")]
         LongRunning,
     }
+
+    /// How to merge a pull request. Can be either `merge` (a merge commit),
+    /// `squash` or `rebase`.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum MergeMethod {
+        Merge,
+        Squash,
+        Rebase,
+    }
+
+    /// The action to take when submitting a review. Can be either `APPROVE`,
+    /// `REQUEST_CHANGES` or `COMMENT`. A `Pending` review is left unsubmitted,
+    /// and can be submitted later.
+    #[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[non_exhaustive]
+    pub enum ReviewEvent {
+        Approve,
+        RequestChanges,
+        Comment,
+        Pending,
+    }
+
+    /// An inline comment to submit as part of a review. The comment is placed
+    /// either at a `position` in the diff, or at a `line` of the file.
+    #[derive(Debug, Clone, serde::Serialize)]
+    pub struct ReviewComment {
+        path: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        position: Option<u64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        line: Option<u64>,
+        body: String,
+    }
+
+    impl ReviewComment {
+        /// A comment on `path`, `position` lines down from the first `@@` hunk
+        /// header of the file's diff.
+        pub fn at_position(
+            path: impl Into<String>,
+            position: u64,
+            body: impl Into<String>,
+        ) -> Self {
+            Self {
+                path: path.into(),
+                position: Some(position),
+                line: None,
+                body: body.into(),
+            }
+        }
+
+        /// A comment on `line` of the file at `path`.
+        pub fn at_line(path: impl Into<String>, line: u64, body: impl Into<String>) -> Self {
+            Self {
+                path: path.into(),
+                position: None,
+                line: Some(line),
+                body: body.into(),
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn serialize_sort() {
+            assert_eq!(
+                r#""created""#,
+                serde_json::to_string(&Sort::Created).unwrap()
+            );
+            assert_eq!(
+                r#""updated""#,
+                serde_json::to_string(&Sort::Updated).unwrap()
+            );
+            assert_eq!(
+                r#""popularity""#,
+                serde_json::to_string(&Sort::Popularity).unwrap()
+            );
+            assert_eq!(
+                r#""long-running""#,
+                serde_json::to_string(&Sort::LongRunning).unwrap()
+            );
+        }
+
+        #[test]
+        fn serialize_review_event() {
+            assert_eq!(
+                r#""APPROVE""#,
+                serde_json::to_string(&ReviewEvent::Approve).unwrap()
+            );
+            assert_eq!(
+                r#""REQUEST_CHANGES""#,
+                serde_json::to_string(&ReviewEvent::RequestChanges).unwrap()
+            );
+            assert_eq!(
+                r#""COMMENT""#,
+                serde_json::to_string(&ReviewEvent::Comment).unwrap()
+            );
+            assert_eq!(
+                r#""PENDING""#,
+                serde_json::to_string(&ReviewEvent::Pending).unwrap()
+            );
+        }
+
+        #[test]
+        fn serialize_review_comment() {
+            assert_eq!(
+                serde_json::to_value(ReviewComment::at_position("src/lib.rs", 4, "Nit")).unwrap(),
+                serde_json::json!({ "path": "src/lib.rs", "position": 4, "body": "Nit" })
+            );
+            assert_eq!(
+                serde_json::to_value(ReviewComment::at_line("src/lib.rs", 12, "Typo")).unwrap(),
+                serde_json::json!({ "path": "src/lib.rs", "line": 12, "body": "Typo" })
+            );
+        }
+    }
 }
 
 pub mod repos {
@@ -168,4 +463,90 @@ pub mod repos {
         Pushed,
         FullName,
     }
+
+    /// How to sort starred repositories.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum StarredSort {
+        /// By when the repository was starred.
+        Created,
+        /// By when the repository was last pushed to.
+        Updated,
+    }
+
+    /// The media type used to deliver a webhook's payloads.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum HookContentType {
+        /// `application/json`.
+        Json,
+        /// `application/x-www-form-urlencoded`, the default.
+        Form,
+    }
+
+    /// The version control system of a repository being imported.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum Vcs {
+        Subversion,
+        Git,
+        Mercurial,
+        /// Team Foundation Version Control.
+        Tfvc,
+    }
+
+    /// Which collaborators of a repository to list.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum Affiliation {
+        /// Collaborators who aren't members of the repository's organization.
+        Outside,
+        /// Collaborators with permissions on the repository itself, rather
+        /// than through an organization or a team.
+        Direct,
+        /// Every collaborator, the default.
+        All,
+    }
+
+    /// A level of access to a repository, from least to most privileged.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum Permission {
+        Pull,
+        Triage,
+        Push,
+        Maintain,
+        Admin,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn state_round_trip() {
+        for (state, json) in [
+            (State::All, r#""all""#),
+            (State::Open, r#""open""#),
+            (State::Closed, r#""closed""#),
+        ] {
+            assert_eq!(json, serde_json::to_string(&state).unwrap());
+            assert_eq!(state, serde_json::from_str::<State>(json).unwrap());
+        }
+    }
+
+    #[tokio::test]
+    async fn state_all_filter() {
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.pulls("rust-lang", "rust");
+        let list = handler.list().state(State::All);
+
+        assert_eq!(serde_json::to_value(list).unwrap()["state"], "all");
+    }
 }
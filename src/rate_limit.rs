@@ -0,0 +1,63 @@
+//! Tracks the rate limit state reported by GitHub's responses.
+
+use std::sync::Mutex;
+
+use chrono::{DateTime, TimeZone, Utc};
+use reqwest::header::HeaderMap;
+
+/// The `X-RateLimit-Remaining` and `X-RateLimit-Reset` headers of the most
+/// recent response that had them, and that counted against the `core` rate
+/// limit. Other resources such as `search` and `graphql` have their own
+/// limits, which would otherwise overwrite the `core` values.
+#[derive(Debug, Default)]
+pub(crate) struct RateLimitTracker {
+    last: Mutex<Option<Observed>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Observed {
+    remaining: u64,
+    reset: DateTime<Utc>,
+}
+
+impl RateLimitTracker {
+    pub(crate) fn record(&self, headers: &HeaderMap) {
+        let resource = headers
+            .get("x-ratelimit-resource")
+            .and_then(|value| value.to_str().ok());
+        if resource.is_some() && resource != Some("core") {
+            return;
+        }
+
+        if let Some(observed) = Observed::from_headers(headers) {
+            *self.last.lock().unwrap() = Some(observed);
+        }
+    }
+
+    pub(crate) fn limited_until(&self) -> Option<DateTime<Utc>> {
+        self.last
+            .lock()
+            .unwrap()
+            .filter(|observed| observed.remaining == 0)
+            .map(|observed| observed.reset)
+    }
+
+    pub(crate) fn remaining(&self) -> Option<u64> {
+        self.last.lock().unwrap().map(|observed| observed.remaining)
+    }
+}
+
+impl Observed {
+    fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        Some(Self {
+            remaining: header(headers, "x-ratelimit-remaining")?,
+            reset: Utc
+                .timestamp_opt(header(headers, "x-ratelimit-reset")?, 0)
+                .single()?,
+        })
+    }
+}
+
+pub(crate) fn header<T: std::str::FromStr>(headers: &HeaderMap, name: &str) -> Option<T> {
+    headers.get(name)?.to_str().ok()?.parse().ok()
+}
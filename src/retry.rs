@@ -0,0 +1,296 @@
+//! Opt-in retrying of requests that were rejected by GitHub's rate limits, or
+//! while GitHub was unavailable.
+
+use std::time::Duration;
+
+use chrono::{TimeZone, Utc};
+use reqwest::{header::HeaderMap, Method, StatusCode};
+
+use crate::rate_limit::header;
+
+/// How `Octocrab` retries requests that were rejected because of a rate
+/// limit or because GitHub was unavailable, see [`OctocrabBuilder::retry`].
+///
+/// A request is retried if GitHub responds with `429 Too Many Requests`,
+/// or with `403 Forbidden` and either a `Retry-After` header (used by the
+/// secondary rate limits) or `X-RateLimit-Remaining: 0`. `GET` requests are
+/// also retried if GitHub responds with `503 Service Unavailable` (e.g.
+/// during maintenance), other requests aren't as that could repeat their
+/// side effects. Before retrying, the client
+/// waits for the duration of `Retry-After`, or otherwise until the time in
+/// `X-RateLimit-Reset`. A `503` without either header is retried after
+/// 2<sup>retries</sup> seconds.
+/// ```
+/// use std::time::Duration;
+///
+/// # fn main() -> octocrab::Result<()> {
+/// let octocrab = octocrab::Octocrab::builder()
+///     .retry(octocrab::RetryPolicy::new(3).max_wait(Duration::from_secs(300)))
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`OctocrabBuilder::retry`]: ./struct.OctocrabBuilder.html#method.retry
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    max_wait: Duration,
+}
+
+impl RetryPolicy {
+    /// Retry a request up to `max_retries` times.
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            max_wait: Duration::from_secs(60),
+        }
+    }
+
+    /// The maximum total time to wait across the retries of a request. If
+    /// GitHub asks the client to wait longer than this, the error is returned
+    /// straight away instead. Default: 60 seconds.
+    pub fn max_wait(mut self, max_wait: Duration) -> Self {
+        self.max_wait = max_wait;
+        self
+    }
+
+    /// How long to wait before retrying a `method` request whose response
+    /// had `status` and `headers`, given the number of retries so far and the
+    /// time already spent waiting for them. `None` if the response shouldn't
+    /// be retried.
+    pub(crate) fn wait(
+        &self,
+        retries: u32,
+        waited: Duration,
+        method: &Method,
+        status: StatusCode,
+        headers: &HeaderMap,
+    ) -> Option<Duration> {
+        if retries >= self.max_retries {
+            return None;
+        }
+
+        let retry_after = header::<u64>(headers, "retry-after").map(Duration::from_secs);
+        let exhausted = header::<u64>(headers, "x-ratelimit-remaining") == Some(0);
+        let limited = status == StatusCode::TOO_MANY_REQUESTS
+            || (status == StatusCode::FORBIDDEN && (retry_after.is_some() || exhausted));
+        let unavailable = status == StatusCode::SERVICE_UNAVAILABLE && method == Method::GET;
+
+        if !limited && !unavailable {
+            return None;
+        }
+
+        let wait = retry_after
+            .or_else(|| {
+                let reset = Utc
+                    .timestamp_opt(header(headers, "x-ratelimit-reset")?, 0)
+                    .single()?;
+                Some((reset - Utc::now()).to_std().unwrap_or_default())
+            })
+            .or_else(|| Some(Duration::from_secs(1 << retries.min(16))).filter(|_| unavailable))
+            .unwrap_or_default();
+
+        Some(wait).filter(|wait| waited + *wait <= self.max_wait)
+    }
+}
+
+/// How `Octocrab` retries `GET` requests that failed because of a server
+/// error or a network error, see [`OctocrabBuilder::retry_on_server_error`].
+///
+/// [`OctocrabBuilder::retry_on_server_error`]: ./struct.OctocrabBuilder.html#method.retry_on_server_error
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ServerErrorRetry {
+    max_retries: u32,
+    base_delay: Duration,
+}
+
+impl ServerErrorRetry {
+    pub(crate) fn new(max_retries: u32, base_delay: Duration) -> Self {
+        Self {
+            max_retries,
+            base_delay,
+        }
+    }
+
+    /// Whether to retry a `method` request that produced `result`, given the
+    /// number of retries so far.
+    pub(crate) fn should_retry(
+        &self,
+        retries: u32,
+        method: &Method,
+        result: &crate::Result<reqwest::Response>,
+    ) -> bool {
+        retries < self.max_retries
+            && method == Method::GET
+            && match result {
+                Ok(response) => response.status().is_server_error(),
+                Err(crate::Error::Http { source, .. }) => {
+                    (source.is_connect() || source.is_request()) && !source.is_timeout()
+                }
+                Err(_) => false,
+            }
+    }
+
+    /// How long to wait before retrying for the `retries + 1`th time:
+    /// `base_delay * 2^retries`, of which a random half is skipped so that
+    /// concurrent clients don't retry in lockstep.
+    pub(crate) fn delay(&self, retries: u32) -> Duration {
+        let backoff = self.base_delay * 2u32.pow(retries.min(16));
+
+        backoff / 2 + (backoff / 2).mul_f64(random_fraction())
+    }
+}
+
+/// A random number in `0.0..1.0`, random enough to spread out retries.
+fn random_fraction() -> f64 {
+    use std::hash::{BuildHasher, Hasher};
+
+    // Every `RandomState` is seeded with different keys.
+    let hash = std::collections::hash_map::RandomState::new()
+        .build_hasher()
+        .finish();
+
+    (hash >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn server_error_delay() {
+        let retry = ServerErrorRetry::new(5, Duration::from_millis(100));
+
+        for retries in 0..5 {
+            let backoff = Duration::from_millis(100 * 2u64.pow(retries));
+            let delay = retry.delay(retries);
+            assert!(delay >= backoff / 2 && delay <= backoff, "{:?}", delay);
+        }
+    }
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        pairs
+            .iter()
+            .map(|(name, value)| (*name, value.parse().unwrap()))
+            .map(|(name, value)| (reqwest::header::HeaderName::from_static(name), value))
+            .collect()
+    }
+
+    #[test]
+    fn waits_for_retry_after() {
+        let policy = RetryPolicy::new(2);
+        let headers = headers(&[("retry-after", "30")]);
+
+        for status in &[StatusCode::TOO_MANY_REQUESTS, StatusCode::FORBIDDEN] {
+            assert_eq!(
+                policy.wait(0, Duration::default(), &Method::GET, *status, &headers),
+                Some(Duration::from_secs(30))
+            );
+        }
+        assert_eq!(
+            policy.wait(
+                2,
+                Duration::default(),
+                &Method::GET,
+                StatusCode::FORBIDDEN,
+                &headers
+            ),
+            None
+        );
+        assert_eq!(
+            policy.wait(
+                1,
+                Duration::from_secs(40),
+                &Method::GET,
+                StatusCode::FORBIDDEN,
+                &headers
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn waits_until_reset() {
+        let policy = RetryPolicy::new(1).max_wait(Duration::from_secs(3600));
+        let reset = (Utc::now() + chrono::Duration::seconds(600)).timestamp();
+        let headers = headers(&[
+            ("x-ratelimit-remaining", "0"),
+            ("x-ratelimit-reset", &reset.to_string()),
+        ]);
+
+        let wait = policy
+            .wait(
+                0,
+                Duration::default(),
+                &Method::POST,
+                StatusCode::FORBIDDEN,
+                &headers,
+            )
+            .unwrap();
+        assert!(wait > Duration::from_secs(590) && wait <= Duration::from_secs(600));
+    }
+
+    #[test]
+    fn backs_off_while_unavailable() {
+        let policy = RetryPolicy::new(3);
+        let status = StatusCode::SERVICE_UNAVAILABLE;
+
+        assert_eq!(
+            policy.wait(
+                0,
+                Duration::default(),
+                &Method::GET,
+                status,
+                &headers(&[("retry-after", "5")])
+            ),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(
+            policy.wait(
+                2,
+                Duration::default(),
+                &Method::GET,
+                status,
+                &HeaderMap::new()
+            ),
+            Some(Duration::from_secs(4))
+        );
+        assert_eq!(
+            policy.wait(
+                0,
+                Duration::default(),
+                &Method::POST,
+                status,
+                &HeaderMap::new()
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn ignores_other_errors() {
+        let policy = RetryPolicy::new(1);
+
+        assert_eq!(
+            policy.wait(
+                0,
+                Duration::default(),
+                &Method::GET,
+                StatusCode::FORBIDDEN,
+                &headers(&[("x-ratelimit-remaining", "4999")])
+            ),
+            None
+        );
+        assert_eq!(
+            policy.wait(
+                0,
+                Duration::default(),
+                &Method::GET,
+                StatusCode::NOT_FOUND,
+                &HeaderMap::new()
+            ),
+            None
+        );
+    }
+}